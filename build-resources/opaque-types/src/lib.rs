@@ -2,10 +2,12 @@
 #![allow(dead_code)]
 #![allow(deprecated)]
 use core::ffi::c_void;
-#[cfg(all(feature = "shared-memory", feature = "unstable"))]
-use std::sync::Arc;
+#[cfg(feature = "unstable")]
+use std::collections::HashMap;
+#[cfg(feature = "unstable")]
+use std::{sync::atomic::AtomicBool, time::Duration};
 use std::{
-    sync::{Condvar, Mutex, MutexGuard},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
     thread::JoinHandle,
 };
 
@@ -23,7 +25,7 @@ use zenoh::{
     liveliness::LivelinessToken,
     matching::MatchingListener,
     pubsub::{Publisher, Subscriber},
-    query::{Querier, Query, Queryable, Reply, ReplyError},
+    query::{Querier, Queryable, Reply, ReplyError},
     sample::Sample,
     scouting::Hello,
     session::{Session, ZenohId},
@@ -33,10 +35,12 @@ use zenoh::{
 use zenoh::{
     cancellation::CancellationToken,
     internal::builders::close::NolocalJoinHandle,
-    sample::SourceInfo,
+    qos::{CongestionControl, Priority, Reliability},
+    query::Parameters,
+    sample::{Locality, SourceInfo},
     session::{
         EntityGlobalId, Link, LinkEvent, LinkEventsListener, Transport, TransportEvent,
-        TransportEventsListener,
+        TransportEventsListener, WeakSession,
     },
 };
 
@@ -89,6 +93,26 @@ get_opaque_type_data!(Vec<CSlice>, z_owned_string_array_t);
 /// A loaned string array.
 get_opaque_type_data!(Vec<CSlice>, z_loaned_string_array_t);
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An array of maybe-owned slices.
+get_opaque_type_data!(Vec<CSlice>, z_owned_slice_array_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned slice array.
+get_opaque_type_data!(Vec<CSlice>, z_loaned_slice_array_t);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A map of maybe-owned slices to maybe-owned slices.
+///
+/// Keys are compared by content, the iteration order is unspecified.
+get_opaque_type_data!(HashMap<CSlice, CSlice>, z_owned_slice_map_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned slice map.
+get_opaque_type_data!(HashMap<CSlice, CSlice>, z_loaned_slice_map_t);
+
 /// An owned Zenoh sample.
 ///
 /// This is a read only type that can only be constructed by cloning a `z_loaned_sample_t`.
@@ -118,31 +142,95 @@ get_opaque_type_data!(Option<Reply>, z_owned_reply_t);
 /// A loaned reply.
 get_opaque_type_data!(Reply, z_loaned_reply_t);
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An array of owned replies.
+get_opaque_type_data!(Vec<Reply>, z_owned_reply_array_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned reply array.
+get_opaque_type_data!(Vec<Reply>, z_loaned_reply_array_t);
+
 /// A Zenoh reply error - a combination of reply error payload and its encoding.
 get_opaque_type_data!(ReplyError, z_owned_reply_err_t);
 /// A loaned Zenoh reply error.
 get_opaque_type_data!(ReplyError, z_loaned_reply_err_t);
 
+pub struct CQuery {
+    _shared: Arc<u8>,
+    _value: Option<(ZBytes, Encoding)>,
+    _attachment: Option<ZBytes>,
+}
+
 /// An owned Zenoh query received by a queryable.
 ///
 /// Queries are atomically reference-counted, letting you extract them from the callback that handed them to you by cloning.
-get_opaque_type_data!(Option<Query>, z_owned_query_t);
+get_opaque_type_data!(Option<CQuery>, z_owned_query_t);
 /// A loaned Zenoh query.
-get_opaque_type_data!(Query, z_loaned_query_t);
+get_opaque_type_data!(CQuery, z_loaned_query_t);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Owned selector parameters: a list of ``key=value`` pairs separated by ``;``, similar to a url's query segment.
+///
+/// The value of a parameter may hold several elements separated by ``|``.
+get_opaque_type_data!(Parameters<'static>, z_owned_parameters_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Loaned selector parameters.
+get_opaque_type_data!(Parameters<'static>, z_loaned_parameters_t);
+
+#[cfg(feature = "unstable")]
+pub struct CReplyStream {
+    _query: CQuery,
+    _key_expr: KeyExpr<'static>,
+    _encoding: Encoding,
+    _chunk_size: usize,
+    _is_express: bool,
+    _stream_id: u64,
+    _next_index: u64,
+    _pending: ZBytesWriter,
+    _pending_len: usize,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned reply stream, sending a large reply to a query as an ordered series of chunks.
+///
+/// Dropping a reply stream which was not finished aborts it.
+get_opaque_type_data!(Option<CReplyStream>, z_owned_reply_stream_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned reply stream.
+get_opaque_type_data!(CReplyStream, z_loaned_reply_stream_t);
+
+pub struct CQueryable {
+    _queryable: Queryable<()>,
+    #[cfg(feature = "unstable")]
+    _paused: Arc<AtomicBool>,
+}
 
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#queryable"> queryable </a>.
 ///
 /// Responds to queries sent via `z_get()` with intersecting key expression.
-get_opaque_type_data!(Option<Queryable<()>>, z_owned_queryable_t);
+get_opaque_type_data!(Option<CQueryable>, z_owned_queryable_t);
 /// A loaned Zenoh queryable.
-get_opaque_type_data!(Queryable<()>, z_loaned_queryable_t);
+get_opaque_type_data!(CQueryable, z_loaned_queryable_t);
+
+pub struct CQuerier {
+    _querier: Querier<'static>,
+    #[cfg(feature = "unstable")]
+    _stats: Arc<u8>,
+    #[cfg(feature = "unstable")]
+    _timeout: Duration,
+}
 
 /// An owned Zenoh querier.
 ///
 /// Sends queries to matching queryables.
-get_opaque_type_data!(Option<Querier>, z_owned_querier_t);
+get_opaque_type_data!(Option<CQuerier>, z_owned_querier_t);
 /// A loaned Zenoh queryable.
-get_opaque_type_data!(Querier, z_loaned_querier_t);
+get_opaque_type_data!(CQuerier, z_loaned_querier_t);
 
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -265,10 +353,39 @@ get_opaque_type_data!(ZenohId, z_id_t);
 /// It consists of a time generated by a Hybrid Logical Clock (HLC) in NPT64 format and a unique zenoh identifier.
 get_opaque_type_data!(Timestamp, z_timestamp_t);
 
+pub struct CPublisher {
+    _publisher: Publisher<'static>,
+    #[cfg(feature = "unstable")]
+    _rate_limiter: Option<Box<u8>>,
+}
+
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#publisher"> publisher </a>.
-get_opaque_type_data!(Option<Publisher<'static>>, z_owned_publisher_t);
+get_opaque_type_data!(Option<CPublisher>, z_owned_publisher_t);
 /// A loaned Zenoh publisher.
-get_opaque_type_data!(Publisher<'static>, z_loaned_publisher_t);
+get_opaque_type_data!(CPublisher, z_loaned_publisher_t);
+
+#[cfg(feature = "unstable")]
+pub struct CPrefixPublisher {
+    _session: WeakSession,
+    _prefix: KeyExpr<'static>,
+    _encoding: Encoding,
+    _congestion_control: CongestionControl,
+    _priority: Priority,
+    _is_express: bool,
+    _reliability: Reliability,
+    _allowed_destination: Locality,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh prefix publisher.
+///
+/// Publishes on any key expression starting with the prefix it was declared on, reusing the prefix declaration.
+get_opaque_type_data!(Option<CPrefixPublisher>, z_owned_prefix_publisher_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned Zenoh prefix publisher.
+get_opaque_type_data!(CPrefixPublisher, z_loaned_prefix_publisher_t);
 
 /// @brief An owned Zenoh matching listener.
 ///
@@ -276,22 +393,79 @@ get_opaque_type_data!(Publisher<'static>, z_loaned_publisher_t);
 /// Dropping the corresponding publisher, also drops matching listener.
 get_opaque_type_data!(Option<MatchingListener<()>>, z_owned_matching_listener_t);
 
+pub struct CSubscriber {
+    _subscriber: Subscriber<()>,
+    #[cfg(feature = "unstable")]
+    _paused: Arc<AtomicBool>,
+}
+
 /// An owned Zenoh <a href="https://zenoh.io/docs/manual/abstractions/#subscriber"> subscriber </a>.
 ///
 /// Receives data from publication on intersecting key expressions.
 /// Destroying the subscriber cancels the subscription.
-get_opaque_type_data!(Option<Subscriber<()>>, z_owned_subscriber_t);
+get_opaque_type_data!(Option<CSubscriber>, z_owned_subscriber_t);
 /// A loaned Zenoh subscriber.
-get_opaque_type_data!(Subscriber<()>, z_loaned_subscriber_t);
+get_opaque_type_data!(CSubscriber, z_loaned_subscriber_t);
+
+pub struct CLivelinessToken {
+    _token: LivelinessToken,
+    #[cfg(feature = "unstable")]
+    _payload_queryable: Option<Queryable<()>>,
+}
 
 /// @brief A liveliness token that can be used to provide the network with information about connectivity to its
 /// declarer: when constructed, a PUT sample will be received by liveliness subscribers on intersecting key
 /// expressions.
 ///
 /// A DELETE on the token's key expression will be received by subscribers if the token is destroyed, or if connectivity between the subscriber and the token's creator is lost.
-get_opaque_type_data!(Option<LivelinessToken>, z_owned_liveliness_token_t);
+get_opaque_type_data!(Option<CLivelinessToken>, z_owned_liveliness_token_t);
 /// @brief A loaned liveliness token.
-get_opaque_type_data!(LivelinessToken, z_loaned_liveliness_token_t);
+get_opaque_type_data!(CLivelinessToken, z_loaned_liveliness_token_t);
+
+#[cfg(feature = "unstable")]
+pub struct CLivelinessTracker {
+    _subscriber: Subscriber<()>,
+    _members: Arc<u8>,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned liveliness tracker.
+///
+/// Keeps the set of the alive liveliness tokens intersecting a key expression.
+get_opaque_type_data!(Option<CLivelinessTracker>, z_owned_liveliness_tracker_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned liveliness tracker.
+get_opaque_type_data!(CLivelinessTracker, z_loaned_liveliness_tracker_t);
+
+#[cfg(feature = "unstable")]
+pub struct CLivelinessContention {
+    _own: (ZenohId, u64),
+    _subscriber: Subscriber<()>,
+    _token: LivelinessToken,
+    _worker: Arc<u8>,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned liveliness election candidate.
+///
+/// Takes part in a leader election based on liveliness tokens.
+get_opaque_type_data!(Option<CLivelinessContention>, z_owned_liveliness_election_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned liveliness election candidate.
+get_opaque_type_data!(CLivelinessContention, z_loaned_liveliness_election_t);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned distributed lock based on liveliness tokens.
+get_opaque_type_data!(Option<CLivelinessContention>, z_owned_liveliness_lock_t);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A loaned distributed lock based on liveliness tokens.
+get_opaque_type_data!(CLivelinessContention, z_loaned_liveliness_lock_t);
 
 /// @brief A Transport structure returned by Zenoh connectivity API.
 ///
@@ -603,19 +777,19 @@ get_opaque_type_data!(RingChannelHandler<Sample>, z_loaned_ring_handler_sample_t
 
 /// An owned Zenoh fifo query handler.
 get_opaque_type_data!(
-    Option<FifoChannelHandler<Query>>,
+    Option<FifoChannelHandler<CQuery>>,
     z_owned_fifo_handler_query_t
 );
 /// An loaned Zenoh fifo query handler.
-get_opaque_type_data!(FifoChannelHandler<Query>, z_loaned_fifo_handler_query_t);
+get_opaque_type_data!(FifoChannelHandler<CQuery>, z_loaned_fifo_handler_query_t);
 
 /// An owned Zenoh ring query handler.
 get_opaque_type_data!(
-    Option<RingChannelHandler<Query>>,
+    Option<RingChannelHandler<CQuery>>,
     z_owned_ring_handler_query_t
 );
 /// An loaned Zenoh ring query handler.
-get_opaque_type_data!(RingChannelHandler<Query>, z_loaned_ring_handler_query_t);
+get_opaque_type_data!(RingChannelHandler<CQuery>, z_loaned_ring_handler_query_t);
 
 /// An owned Zenoh fifo reply handler.
 get_opaque_type_data!(
@@ -633,6 +807,73 @@ get_opaque_type_data!(
 /// An loaned Zenoh ring reply handler.
 get_opaque_type_data!(RingChannelHandler<Reply>, z_loaned_ring_handler_reply_t);
 
+#[cfg(feature = "unstable")]
+pub struct CMatchingStatus {
+    _matching: bool,
+    _local: bool,
+    _remote: bool,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh fifo matching status handler.
+get_opaque_type_data!(
+    Option<FifoChannelHandler<CMatchingStatus>>,
+    z_owned_fifo_handler_matching_status_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh fifo matching status handler.
+get_opaque_type_data!(
+    FifoChannelHandler<CMatchingStatus>,
+    z_loaned_fifo_handler_matching_status_t
+);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh ring matching status handler.
+get_opaque_type_data!(
+    Option<RingChannelHandler<CMatchingStatus>>,
+    z_owned_ring_handler_matching_status_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh ring matching status handler.
+get_opaque_type_data!(
+    RingChannelHandler<CMatchingStatus>,
+    z_loaned_ring_handler_matching_status_t
+);
+
+#[cfg(feature = "unstable")]
+pub struct CMiss {
+    _source: EntityGlobalId,
+    _nb: u32,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh fifo sample miss handler.
+get_opaque_type_data!(
+    Option<FifoChannelHandler<CMiss>>,
+    ze_owned_fifo_handler_miss_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh fifo sample miss handler.
+get_opaque_type_data!(FifoChannelHandler<CMiss>, ze_loaned_fifo_handler_miss_t);
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An owned Zenoh ring sample miss handler.
+get_opaque_type_data!(
+    Option<RingChannelHandler<CMiss>>,
+    ze_owned_ring_handler_miss_t
+);
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief An loaned Zenoh ring sample miss handler.
+get_opaque_type_data!(RingChannelHandler<CMiss>, ze_loaned_ring_handler_miss_t);
+
 #[cfg(feature = "unstable")]
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief A source info.
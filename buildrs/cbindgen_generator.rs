@@ -765,17 +765,19 @@ fn find_closure_constructors(path_in: &str) -> Vec<FunctionSignature> {
 
 fn find_recv_functions(path_in: &str) -> Vec<FunctionSignature> {
     let bindings = std::fs::read_to_string(path_in).unwrap();
-    let re = Regex::new(r"(\w+)\s+z_(\w+)_handler_(\w+)_recv\(const\s+struct\s+(\w+)\s+\*(\w+),\s+struct\s+(\w+)\s+\*(\w+)\);").unwrap();
+    let re = Regex::new(r"(\w+)\s+(ze?)_(\w+)_handler_(\w+)_recv\(const\s+struct\s+(\w+)\s+\*(\w+),\s+struct\s+(\w+)\s+\*(\w+)\);").unwrap();
     let mut res = Vec::<FunctionSignature>::new();
 
-    for (_, [return_type, handler_type, value_type, arg1_type, arg1_name, arg2_type, arg2_name]) in
-        re.captures_iter(&bindings).map(|c| c.extract())
+    for (
+        _,
+        [return_type, prefix, handler_type, value_type, arg1_type, arg1_name, arg2_type, arg2_name],
+    ) in re.captures_iter(&bindings).map(|c| c.extract())
     {
         let (_, _, semantic, _) = split_type_name(arg1_type);
         let f = FunctionSignature::new(
             semantic,
             return_type,
-            "z_".to_string() + handler_type + "_handler_" + value_type + "_recv",
+            prefix.to_string() + "_" + handler_type + "_handler_" + value_type + "_recv",
             vec![
                 FuncArg::new(&("const ".to_string() + arg1_type + "*"), arg1_name),
                 FuncArg::new(&(arg2_type.to_string() + "*"), arg2_name),
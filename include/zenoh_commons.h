@@ -16,6 +16,27 @@
 #define ALIGN(n)
 #define ZENOHC_API
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Payload compression algorithm.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_compression_algorithm_t {
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * LZ4 block compression, optimized for speed.
+   */
+  Z_COMPRESSION_ALGORITHM_LZ4 = 0, 
+  Z_COMPRESSION_ALGORITHM_DEFAULT = Z_COMPRESSION_ALGORITHM_LZ4,
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * Zstandard compression, optimized for compression ratio.
+   */
+  Z_COMPRESSION_ALGORITHM_ZSTD = 1,
+#endif
+} z_compression_algorithm_t;
+#endif
 /**
  * The locality of samples to be received by subscribers or targeted by publishers.
  */
@@ -50,6 +71,86 @@ typedef enum z_locality_t {
 * @warning This API is deprecated. Please use `z_locality_t`.
 */
 typedef z_locality_t zc_locality_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The handling of queries received by an overloaded queryable.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_queryable_overload_policy_t {
+  /**
+   * The query is answered with an error reply carrying the ``"queryable overloaded"`` string.
+   */
+  Z_QUERYABLE_OVERLOAD_POLICY_REPLY_ERR = 0, 
+  Z_QUERYABLE_OVERLOAD_POLICY_DEFAULT = Z_QUERYABLE_OVERLOAD_POLICY_REPLY_ERR,
+  /**
+   * The query is dropped without reply.
+   */
+  Z_QUERYABLE_OVERLOAD_POLICY_DROP = 1,
+} z_queryable_overload_policy_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The kinds of samples delivered to a subscriber callback.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_sample_kind_filter_t {
+  /**
+   * Both `PUT` and `DELETE` samples are delivered.
+   */
+  Z_SAMPLE_KIND_FILTER_ANY = 0, 
+  Z_SAMPLE_KIND_FILTER_DEFAULT = Z_SAMPLE_KIND_FILTER_ANY,
+  /**
+   * Only `PUT` samples are delivered.
+   */
+  Z_SAMPLE_KIND_FILTER_PUT = 1,
+  /**
+   * Only `DELETE` samples are delivered.
+   */
+  Z_SAMPLE_KIND_FILTER_DELETE = 2,
+} z_sample_kind_filter_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The way a subscriber filters samples by their source.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_source_filter_mode_t {
+  /**
+   * Samples are not filtered by their source.
+   */
+  Z_SOURCE_FILTER_MODE_NONE = 0, 
+  Z_SOURCE_FILTER_MODE_DEFAULT = Z_SOURCE_FILTER_MODE_NONE,
+  /**
+   * Only samples whose source matches one of the listed ids or zids are delivered. Samples without source info are dropped.
+   */
+  Z_SOURCE_FILTER_MODE_ALLOW = 1,
+  /**
+   * Samples whose source matches one of the listed ids or zids are dropped. Samples without source info are delivered.
+   */
+  Z_SOURCE_FILTER_MODE_DENY = 2,
+} z_source_filter_mode_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The way a subscriber coalesces samples before calling its callback.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_subscriber_coalescing_mode_t {
+  /**
+   * Every sample is delivered as soon as it is received.
+   */
+  Z_SUBSCRIBER_COALESCING_MODE_NONE = 0, 
+  Z_SUBSCRIBER_COALESCING_MODE_DEFAULT = Z_SUBSCRIBER_COALESCING_MODE_NONE,
+  /**
+   * Samples are delivered every `interval_ms`, at most one per key expression: the latest one received during the interval.
+   */
+  Z_SUBSCRIBER_COALESCING_MODE_DOWNSAMPLE = 1,
+  /**
+   * Samples are delivered as fast as the callback processes them, keeping only the latest pending sample per key expression.
+   */
+  Z_SUBSCRIBER_COALESCING_MODE_LATEST = 2,
+} z_subscriber_coalescing_mode_t;
+#endif
 typedef enum z_congestion_control_t {
   /**
    * Messages are not dropped in case of congestion.
@@ -120,6 +221,24 @@ typedef enum z_reliability_t {
   Z_RELIABILITY_DEFAULT = Z_RELIABILITY_RELIABLE,
 } z_reliability_t;
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The action taken by a publisher when a message exceeds its rate limit.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_rate_limit_action_t {
+  /**
+   * The message is dropped and the operation returns `Z_EUNAVAILABLE`.
+   */
+  Z_RATE_LIMIT_ACTION_DROP = 0, 
+  Z_RATE_LIMIT_ACTION_DEFAULT = Z_RATE_LIMIT_ACTION_DROP,
+  /**
+   * The operation blocks until the message fits into the rate limit. If this would take longer than the configured timeout,
+   * the message is dropped immediately and the operation returns `Z_EUNAVAILABLE`.
+   */
+  Z_RATE_LIMIT_ACTION_BLOCK = 1,
+} z_rate_limit_action_t;
+#endif
 /**
  * The Queryables that should be target of a `z_get()`.
  */
@@ -243,6 +362,32 @@ typedef enum z_shm_provider_state {
   Z_SHM_PROVIDER_STATE_ERROR,
 } z_shm_provider_state;
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The kind of a time range bound.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef enum z_time_bound_kind_t {
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The range is not bounded in this direction.
+   */
+  Z_TIME_BOUND_KIND_UNBOUNDED = 0,
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The bound belongs to the range.
+   */
+  Z_TIME_BOUND_KIND_INCLUSIVE = 1,
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * The bound does not belong to the range.
+   */
+  Z_TIME_BOUND_KIND_EXCLUSIVE = 2,
+#endif
+} z_time_bound_kind_t;
+#endif
 typedef enum z_what_t {
   Z_WHAT_ROUTER = 1,
   Z_WHAT_PEER = 2,
@@ -319,9 +464,57 @@ typedef struct z_moved_precomputed_layout_t {
 typedef struct z_moved_precomputed_layout_t z_moved_alloc_layout_t;
 #endif
 typedef int8_t z_result_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to `z_bytes_compress()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_bytes_compress_options_t {
+  /**
+   * Compression algorithm to use.
+   */
+  enum z_compression_algorithm_t algorithm;
+  /**
+   * Compression level, `0` selects the algorithm default. Ignored by `Z_COMPRESSION_ALGORITHM_LZ4`.
+   */
+  int32_t level;
+} z_bytes_compress_options_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to `z_bytes_decompress()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_bytes_decompress_options_t {
+  /**
+   * Maximum size of the decompressed data in bytes, decompression fails if the data would exceed it.
+   * Protects against payloads that expand to an excessive size. Defaults to 64 MiB.
+   */
+  size_t max_size;
+} z_bytes_decompress_options_t;
+#endif
 typedef struct z_moved_bytes_t {
   struct z_owned_bytes_t _this;
 } z_moved_bytes_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A scatter/gather buffer descriptor.
+ *
+ * Its layout matches the POSIX `struct iovec`, so arrays of `z_iovec_t` can be passed to `readv()`/`writev()`/`sendmsg()`
+ * (and vice versa) by a simple cast.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_iovec_t {
+  /**
+   * Pointer to the start of the fragment.
+   */
+  void *iov_base;
+  /**
+   * Length of the fragment in bytes.
+   */
+  size_t iov_len;
+} z_iovec_t;
+#endif
 typedef struct z_moved_shm_t {
   struct z_owned_shm_t _this;
 } z_moved_shm_t;
@@ -397,6 +590,40 @@ typedef struct z_owned_closure_hello_t {
 typedef struct z_moved_closure_hello_t {
   struct z_owned_closure_hello_t _this;
 } z_moved_closure_hello_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A struct that indicates whether a liveliness election candidate is the leader.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_leadership_status_t {
+  /**
+   * True if the candidate gained leadership, false if it lost it.
+   */
+  bool is_leader;
+} z_leadership_status_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A leadership status-processing closure.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_owned_closure_leadership_status_t {
+  void *_context;
+  void (*_call)(const struct z_leadership_status_t *leadership_status, void *context);
+  void (*_drop)(void *context);
+} z_owned_closure_leadership_status_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_moved_closure_leadership_status_t {
+  struct z_owned_closure_leadership_status_t _this;
+} z_moved_closure_leadership_status_t;
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief A link-processing closure.
@@ -516,6 +743,46 @@ typedef struct z_owned_closure_reply_t {
   void (*_call)(struct z_loaned_reply_t *reply, void *context);
   void (*_drop)(void *context);
 } z_owned_closure_reply_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A reply-consolidation closure, resolving conflicts between replies on the same key expression.
+ *
+ * It is called with the reply currently kept for a key expression and a newly received candidate reply on the same key expression.
+ * Setting `replace` to ``true`` keeps the candidate instead of the current reply. The closure may also modify `kept`, e.g. to merge
+ * the candidate into it.
+ *
+ * A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_owned_closure_reply_consolidation_t {
+  void *_context;
+  void (*_call)(struct z_loaned_reply_t *kept,
+                struct z_loaned_reply_t *candidate,
+                bool *replace,
+                void *context);
+  void (*_drop)(void *context);
+} z_owned_closure_reply_consolidation_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Loaned closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_loaned_closure_reply_consolidation_t {
+  size_t _0;
+  size_t _1;
+  size_t _2;
+} z_loaned_closure_reply_consolidation_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Moved closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_moved_closure_reply_consolidation_t {
+  struct z_owned_closure_reply_consolidation_t _this;
+} z_moved_closure_reply_consolidation_t;
+#endif
 /**
  * Moved closure.
  */
@@ -662,7 +929,88 @@ typedef struct z_queryable_options_t {
    * that have the compatible allowed_destination.
    */
   enum z_locality_t allowed_origin;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The maximum number of queries in flight, 0 meaning unlimited. A query is in flight from its reception until it is
+   * finalized, either explicitly with `z_query_finalize()` or when it and all its clones are dropped.
+   * Queries received while the limit is reached are handled according to `overload_policy` and not passed to the callback.
+   */
+  size_t max_in_flight;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The handling of queries received while `max_in_flight` queries are in flight.
+   */
+  enum z_queryable_overload_policy_t overload_policy;
+#endif
 } z_queryable_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Filters applied to samples before the subscriber callback is called.
+ *
+ * The arrays are copied upon subscriber declaration, so they do not need to outlive it.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_subscriber_filter_options_t {
+  /**
+   * The kinds of samples to deliver.
+   */
+  enum z_sample_kind_filter_t kind;
+  /**
+   * An array of accepted encodings, NULL or empty to accept any encoding.
+   * An encoding without schema accepts samples with the same encoding and any schema.
+   */
+  const struct z_loaned_encoding_t *const *encodings;
+  /**
+   * The number of elements in `encodings`.
+   */
+  size_t encodings_len;
+  /**
+   * The way the sample source is matched against `source_ids` and `source_zids`.
+   */
+  enum z_source_filter_mode_t source_mode;
+  /**
+   * An array of source entity ids to match.
+   */
+  const struct z_entity_global_id_t *source_ids;
+  /**
+   * The number of elements in `source_ids`.
+   */
+  size_t source_ids_len;
+  /**
+   * An array of source zenoh ids to match, any entity of the listed zids matches.
+   */
+  const struct z_id_t *source_zids;
+  /**
+   * The number of elements in `source_zids`.
+   */
+  size_t source_zids_len;
+} z_subscriber_filter_options_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sample coalescing settings of a subscriber.
+ *
+ * When coalescing is enabled, the subscriber callback is called from the zenoh runtime, only while samples are pending.
+ * Pending samples are discarded when the subscriber is undeclared.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_subscriber_coalescing_options_t {
+  /**
+   * The coalescing mode.
+   */
+  enum z_subscriber_coalescing_mode_t mode;
+  /**
+   * The delivery interval in milliseconds, used by `Z_SUBSCRIBER_COALESCING_MODE_DOWNSAMPLE`.
+   * ``0`` behaves as `Z_SUBSCRIBER_COALESCING_MODE_LATEST`.
+   */
+  uint64_t interval_ms;
+} z_subscriber_coalescing_options_t;
+#endif
 /**
  * Options passed to the `z_declare_subscriber()` function.
  */
@@ -672,6 +1020,22 @@ typedef struct z_subscriber_options_t {
    * that have the compatible allowed_destination.
    */
   enum z_locality_t allowed_origin;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The filters applied to samples before the subscriber callback is called. All samples are delivered by default.
+   */
+  struct z_subscriber_filter_options_t filter;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The coalescing applied to samples which passed the filters. Disabled by default.
+   */
+  struct z_subscriber_coalescing_options_t coalescing;
+#endif
 } z_subscriber_options_t;
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -689,6 +1053,67 @@ typedef struct z_transport_events_listener_options_t {
 typedef struct z_moved_encoding_t {
   struct z_owned_encoding_t _this;
 } z_moved_encoding_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to the `z_declare_prefix_publisher()` function.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_prefix_publisher_options_t {
+  /**
+   * Default encoding for messages put by this prefix publisher.
+   */
+  struct z_moved_encoding_t *encoding;
+  /**
+   * The congestion control to apply when routing messages from this prefix publisher.
+   */
+  enum z_congestion_control_t congestion_control;
+  /**
+   * The priority of messages from this prefix publisher.
+   */
+  enum z_priority_t priority;
+  /**
+   * If set to ``true``, messages will not be batched. This usually has a positive impact on latency but negative impact on throughput.
+   */
+  bool is_express;
+  /**
+   * The prefix publisher reliability.
+   */
+  enum z_reliability_t reliability;
+  /**
+   * The allowed destination for this prefix publisher.
+   */
+  enum z_locality_t allowed_destination;
+} z_prefix_publisher_options_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The rate limit applied by a publisher to its puts and deletes.
+ *
+ * Limits are enforced with token buckets allowing bursts of up to one second worth of traffic.
+ * A message larger than `max_bytes_per_sec` is let through once the bytes bucket is full.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_publisher_rate_limit_options_t {
+  /**
+   * The maximum number of messages per second, ``0`` means unlimited.
+   */
+  uint64_t max_messages_per_sec;
+  /**
+   * The maximum number of payload bytes per second, ``0`` means unlimited.
+   */
+  uint64_t max_bytes_per_sec;
+  /**
+   * The action taken when a message exceeds the rate limit.
+   */
+  enum z_rate_limit_action_t action;
+  /**
+   * The maximum time in milliseconds an operation can block when `action` is `Z_RATE_LIMIT_ACTION_BLOCK`.
+   * ``0`` means no timeout: the operation blocks until the message fits into the rate limit, which takes at most one second,
+   * unless other threads keep consuming the rate limit of the same publisher concurrently.
+   */
+  uint64_t block_timeout_ms;
+} z_publisher_rate_limit_options_t;
+#endif
 /**
  * Options passed to the `z_declare_publisher()` function.
  */
@@ -721,6 +1146,14 @@ typedef struct z_publisher_options_t {
    * The allowed destination for this publisher.
    */
   enum z_locality_t allowed_destination;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * The rate limit applied to puts and deletes of this publisher. Not limited by default. Ignored by advanced publishers.
+   */
+  struct z_publisher_rate_limit_options_t rate_limit;
+#endif
 } z_publisher_options_t;
 /**
  * The replies consolidation strategy to apply on replies to a `z_get()`.
@@ -798,6 +1231,9 @@ typedef struct z_delete_options_t {
    */
   enum z_locality_t allowed_destination;
 } z_delete_options_t;
+typedef struct z_moved_fifo_handler_matching_status_t {
+  struct z_owned_fifo_handler_matching_status_t _this;
+} z_moved_fifo_handler_matching_status_t;
 typedef struct z_moved_fifo_handler_query_t {
   struct z_owned_fifo_handler_query_t _this;
 } z_moved_fifo_handler_query_t;
@@ -871,9 +1307,41 @@ typedef struct z_get_options_t {
    */
   struct z_moved_cancellation_token_t *cancellation_token;
 #endif
-} z_get_options_t;
-typedef struct z_moved_hello_t {
-  struct z_owned_hello_t _this;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, replies sent by reply streams (see `z_reply_stream_new()`) are reassembled, and each stream is handed over
+   * to the callback as a single reply carrying the whole payload once its last chunk is received. Other replies are handed over as is.
+   * Streams which are incomplete when the query ends are discarded, and aborted streams are handed over as their
+   * end-of-stream marker. Setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`.
+   */
+  bool reassemble_chunks;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * An optional closure resolving conflicts between replies on the same key expression, replacing the built-in consolidation
+   * strategies: setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`.
+   * Successful replies are held until the query ends, and the reply kept for each key expression is then handed over to the callback,
+   * in the order of reception of the first reply on each key expression. Error replies are handed over to the callback as soon as they are received.
+   */
+  struct z_moved_closure_reply_consolidation_t *consolidation_hook;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, the query is interrupted as soon as its first successful reply is handed over to the callback, and no later reply reaches it.
+   * Error replies received before are handed over as usual. Setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`,
+   * and can not be combined with `consolidation_hook`. The query can still be interrupted earlier with `cancellation_token`.
+   */
+  bool first_reply_only;
+#endif
+} z_get_options_t;
+typedef struct z_moved_hello_t {
+  struct z_owned_hello_t _this;
 } z_moved_hello_t;
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -909,13 +1377,58 @@ typedef struct z_liveliness_subscriber_options_t {
    * If true, subscriber will receive the state change notifications for liveliness tokens that were declared before its declaration.
    */
   bool history;
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If true, the payload of the tokens (see `z_liveliness_token_options_t`) is fetched, and handed over as the payload of the PUT samples
+   * notifying their declaration. The samples are then delayed until the payload is fetched, but remain in order for each key expression.
+   * The samples still waiting for a payload when the subscriber is undeclared are dropped.
+   */
+  bool fetch_payload;
+#endif
 } z_liveliness_subscriber_options_t;
 /**
  * @brief The options for `z_liveliness_declare_token()`.
+ *
+ * Liveliness tokens can not be restricted to local or remote subscribers, as zenoh declares them to the whole network.
  */
 typedef struct z_liveliness_token_options_t {
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * An optional payload describing the token, such as an application version or capabilities. It is copied, and handed over
+   * to the liveliness subscribers and `z_liveliness_get()` callers which set `fetch_payload` in the sample notifying the token declaration.
+   * Only the payloads of tokens declared on that very key expression are handed over; if several sessions declare a token on it,
+   * the payload of one of them is.
+   */
+  const struct z_loaned_bytes_t *payload;
+#endif
+#if !defined(Z_FEATURE_UNSTABLE_API)
   uint8_t _dummy;
+#endif
 } z_liveliness_token_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The options for `z_liveliness_declare_tracker()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_liveliness_tracker_options_t {
+  /**
+   * An optional closure called with a PUT sample when a token joins the tracked set, and with a DELETE sample when it leaves it.
+   * The set is updated before the closure is called.
+   */
+  struct z_moved_closure_sample_t *callback;
+  /**
+   * The timeout for the liveliness query retrieving the initially alive tokens, in milliseconds. 0 means default query timeout from zenoh configuration.
+   */
+  uint64_t timeout_ms;
+} z_liveliness_tracker_options_t;
+#endif
+typedef struct z_moved_liveliness_election_t {
+  struct z_owned_liveliness_election_t _this;
+} z_moved_liveliness_election_t;
 /**
  * @brief The options for `z_liveliness_get()`
  */
@@ -932,10 +1445,52 @@ typedef struct z_liveliness_get_options_t {
    */
   struct z_moved_cancellation_token_t *cancellation_token;
 #endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If true, the payload of the tokens (see `z_liveliness_token_options_t`) is fetched, and handed over as the payload of the replies.
+   * The replies are then delayed until the payload of their token is fetched.
+   */
+  bool fetch_payload;
+#endif
 } z_liveliness_get_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief The options for `z_liveliness_lock_acquire()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_liveliness_lock_options_t {
+  /**
+   * The maximum time to wait for the lock, in milliseconds. 0 means waiting indefinitely.
+   */
+  uint64_t timeout_ms;
+  /**
+   * The time in milliseconds the lock must remain held once acquired, before `z_liveliness_lock_acquire()` returns.
+   * It should exceed the time needed for liveliness tokens to propagate in the network.
+   */
+  uint64_t settle_ms;
+} z_liveliness_lock_options_t;
+#endif
+typedef struct z_moved_liveliness_lock_t {
+  struct z_owned_liveliness_lock_t _this;
+} z_moved_liveliness_lock_t;
 typedef struct z_moved_liveliness_token_t {
   struct z_owned_liveliness_token_t _this;
 } z_moved_liveliness_token_t;
+typedef struct z_moved_liveliness_tracker_t {
+  struct z_owned_liveliness_tracker_t _this;
+} z_moved_liveliness_tracker_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Function called for each alive token by `z_liveliness_tracker_iterate()`.
+ *
+ * Should return ``true`` to stop the iteration, ``false`` to continue.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef bool (*z_liveliness_tracker_iter_body_t)(const struct z_loaned_keyexpr_t *key_expr,
+                                                 void *context);
+#endif
 typedef struct z_moved_matching_listener_t {
   struct z_owned_matching_listener_t _this;
 } z_moved_matching_listener_t;
@@ -951,6 +1506,52 @@ typedef struct z_moved_mutex_t {
 typedef struct z_open_options_t {
   uint8_t _dummy;
 } z_open_options_t;
+typedef struct z_moved_parameters_t {
+  struct z_owned_parameters_t _this;
+} z_moved_parameters_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Function called for each parameter by `z_parameters_iterate()`.
+ *
+ * Should return ``true`` to stop the iteration, ``false`` to continue.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef bool (*z_parameters_iter_body_t)(const struct z_loaned_string_t *key,
+                                         const struct z_loaned_string_t *value,
+                                         void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A bound of a time range.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_time_bound_t {
+  /**
+   * The kind of the bound.
+   */
+  enum z_time_bound_kind_t kind;
+  /**
+   * The time of the bound in milliseconds since the UNIX epoch, ignored for unbounded bounds.
+   */
+  uint64_t time_ms;
+} z_time_bound_t;
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief A time range, as carried by the ``_time`` selector parameter.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_time_range_t {
+  /**
+   * The start of the range.
+   */
+  struct z_time_bound_t start;
+  /**
+   * The end of the range.
+   */
+  struct z_time_bound_t end;
+} z_time_range_t;
+#endif
 /**
  * Represents the set of options that can be applied to the delete operation by a previously declared publisher,
  * whenever issued via `z_publisher_delete()`.
@@ -961,9 +1562,9 @@ typedef struct z_publisher_delete_options_t {
    */
   const struct z_timestamp_t *timestamp;
 } z_publisher_delete_options_t;
-typedef struct z_moved_publisher_t {
-  struct z_owned_publisher_t _this;
-} z_moved_publisher_t;
+typedef struct z_moved_prefix_publisher_t {
+  struct z_owned_prefix_publisher_t _this;
+} z_moved_prefix_publisher_t;
 /**
  * Options passed to the `z_publisher_put()` function.
  */
@@ -989,6 +1590,9 @@ typedef struct z_publisher_put_options_t {
    */
   struct z_moved_bytes_t *attachment;
 } z_publisher_put_options_t;
+typedef struct z_moved_publisher_t {
+  struct z_owned_publisher_t _this;
+} z_moved_publisher_t;
 /**
  * Options passed to the `z_put()` function.
  */
@@ -1073,7 +1677,74 @@ typedef struct z_querier_get_options_t {
    */
   struct z_moved_cancellation_token_t *cancellation_token;
 #endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, the replies are held back until the query is complete and then handed over grouped by replier,
+   * in the order of the first reply of each replier (see `z_reply_replier_id()`).
+   */
+  bool group_by_replier;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, the query is interrupted as soon as its first successful reply is handed over to the callback, and no later reply reaches it.
+   * Error replies received before are handed over as usual. Can not be combined with `group_by_replier`.
+   * The querier should be declared with `Z_CONSOLIDATION_MODE_NONE` or `Z_CONSOLIDATION_MODE_MONOTONIC` consolidation,
+   * otherwise replies may only be handed over once all of them are received.
+   */
+  bool first_reply_only;
+#endif
+#if defined(Z_FEATURE_UNSTABLE_API)
+  /**
+   * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+   *
+   * If set to ``true``, replies sent by reply streams (see `z_reply_stream_new()`) are reassembled, and each stream is handed over
+   * to the callback as a single reply carrying the whole payload once its last chunk is received. Other replies are handed over as is.
+   * Streams which are incomplete when the query ends are discarded, and aborted streams are handed over as their
+   * end-of-stream marker. The querier should be declared with `Z_CONSOLIDATION_MODE_NONE` consolidation for all the chunks to be received.
+   */
+  bool reassemble_chunks;
+#endif
 } z_querier_get_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Statistics of the queries sent by a querier.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_querier_stats_t {
+  /**
+   * The number of queries successfully sent.
+   */
+  uint64_t queries_sent;
+  /**
+   * The number of successful replies received.
+   */
+  uint64_t replies;
+  /**
+   * The number of error replies received from queryables.
+   */
+  uint64_t errors;
+  /**
+   * The number of queries that timed out before all the replies were received.
+   */
+  uint64_t timeouts;
+  /**
+   * The median latency of the latest replies in microseconds, measured from the sending of the query.
+   */
+  uint64_t latency_p50_us;
+  /**
+   * The 90th percentile latency of the latest replies in microseconds.
+   */
+  uint64_t latency_p90_us;
+  /**
+   * The 99th percentile latency of the latest replies in microseconds.
+   */
+  uint64_t latency_p99_us;
+} z_querier_stats_t;
+#endif
 typedef struct z_moved_query_t {
   struct z_owned_query_t _this;
 } z_moved_query_t;
@@ -1117,6 +1788,26 @@ typedef struct z_query_reply_options_t {
    */
   struct z_moved_bytes_t *attachment;
 } z_query_reply_options_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Options passed to the `z_reply_stream_new()` and `z_query_reply_chunked()` functions.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_reply_stream_options_t {
+  /**
+   * The encoding of the streamed payload.
+   */
+  struct z_moved_encoding_t *encoding;
+  /**
+   * The maximum size of a chunk in bytes, 0 meaning the default of 64 KiB.
+   */
+  size_t chunk_size;
+  /**
+   * If set to ``true``, the chunks will not be batched. This usually has a positive impact on latency but negative impact on throughput.
+   */
+  bool is_express;
+} z_reply_stream_options_t;
+#endif
 /**
  * Represents the set of options that can be applied to a query delete reply,
  * sent via `z_query_reply_del()`.
@@ -1166,12 +1857,45 @@ typedef struct z_query_reply_err_options_t {
 typedef struct z_moved_queryable_t {
   struct z_owned_queryable_t _this;
 } z_moved_queryable_t;
+typedef struct z_moved_reply_array_t {
+  struct z_owned_reply_array_t _this;
+} z_moved_reply_array_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Information about a reply which is a chunk of a reply stream.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct z_reply_chunk_info_t {
+  /**
+   * The identifier of the stream the chunk belongs to, unique per stream.
+   */
+  uint64_t stream_id;
+  /**
+   * The position of the chunk in the stream, starting from 0.
+   */
+  uint64_t index;
+  /**
+   * ``true`` if this chunk is the end of the stream.
+   */
+  bool is_last;
+  /**
+   * ``true`` if the stream was aborted by the replier; in this case the chunk is the end of the stream and carries no data.
+   */
+  bool is_aborted;
+} z_reply_chunk_info_t;
+#endif
 typedef struct z_moved_reply_t {
   struct z_owned_reply_t _this;
 } z_moved_reply_t;
 typedef struct z_moved_reply_err_t {
   struct z_owned_reply_err_t _this;
 } z_moved_reply_err_t;
+typedef struct z_moved_reply_stream_t {
+  struct z_owned_reply_stream_t _this;
+} z_moved_reply_stream_t;
+typedef struct z_moved_ring_handler_matching_status_t {
+  struct z_owned_ring_handler_matching_status_t _this;
+} z_moved_ring_handler_matching_status_t;
 typedef struct z_moved_ring_handler_query_t {
   struct z_owned_ring_handler_query_t _this;
 } z_moved_ring_handler_query_t;
@@ -1212,6 +1936,23 @@ typedef struct z_moved_shm_client_storage_t {
 typedef struct z_moved_shm_provider_t {
   struct z_owned_shm_provider_t _this;
 } z_moved_shm_provider_t;
+typedef struct z_moved_slice_array_t {
+  struct z_owned_slice_array_t _this;
+} z_moved_slice_array_t;
+typedef struct z_moved_slice_map_t {
+  struct z_owned_slice_map_t _this;
+} z_moved_slice_map_t;
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Function called for each key-value pair of the map by `z_slice_map_iterate()`.
+ *
+ * Should return ``true`` to stop the iteration, ``false`` to continue.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef bool (*z_slice_map_iter_body_t)(const struct z_loaned_slice_t *key,
+                                        const struct z_loaned_slice_t *value,
+                                        void *context);
+#endif
 typedef struct z_moved_string_array_t {
   struct z_owned_string_array_t _this;
 } z_moved_string_array_t;
@@ -1595,12 +2336,18 @@ typedef struct ze_querying_subscriber_options_t {
   uint64_t query_timeout_ms;
 } ze_querying_subscriber_options_t;
 #endif
+typedef struct ze_moved_fifo_handler_miss_t {
+  struct ze_owned_fifo_handler_miss_t _this;
+} ze_moved_fifo_handler_miss_t;
 typedef struct ze_moved_publication_cache_t {
   struct ze_owned_publication_cache_t _this;
 } ze_moved_publication_cache_t;
 typedef struct ze_moved_querying_subscriber_t {
   struct ze_owned_querying_subscriber_t _this;
 } ze_moved_querying_subscriber_t;
+typedef struct ze_moved_ring_handler_miss_t {
+  struct ze_owned_ring_handler_miss_t _this;
+} ze_moved_ring_handler_miss_t;
 typedef struct ze_moved_sample_miss_listener_t {
   struct ze_owned_sample_miss_listener_t _this;
 } ze_moved_sample_miss_listener_t;
@@ -1722,6 +2469,37 @@ z_result_t z_bytes_as_mut_loaned_shm(struct z_loaned_bytes_t *this_,
  * Constructs an owned shallow copy of data in provided uninitialized memory location.
  */
 ZENOHC_API void z_bytes_clone(struct z_owned_bytes_t *dst, const struct z_loaned_bytes_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Compresses payload data.
+ *
+ * The resulting encoding keeps the original encoding and replaces its schema with the algorithm tag (`lz4` or `zstd`),
+ * followed by `:` and the original schema if there was one. E.g. `text/plain;utf-8` compressed with lz4 becomes `text/plain;lz4:utf-8`.
+ * Use `z_bytes_decompress()` to restore both the data and the original encoding.
+ *
+ * @param dst: An uninitialized memory location where compressed payload will be constructed.
+ * @param dst_encoding: An uninitialized memory location where encoding of the compressed payload will be constructed.
+ * @param this_: Data to compress.
+ * @param encoding: Encoding of the data to compress. If NULL, `z_encoding_zenoh_bytes()` is assumed.
+ * @param options: Compression options. If NULL, default options are used.
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_compress(struct z_owned_bytes_t *dst,
+                            struct z_owned_encoding_t *dst_encoding,
+                            const struct z_loaned_bytes_t *this_,
+                            const struct z_loaned_encoding_t *encoding,
+                            const struct z_bytes_compress_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_bytes_compress_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_bytes_compress_options_default(struct z_bytes_compress_options_t *this_);
+#endif
 /**
  * Converts a data from buffer into `z_owned_bytes_t` by copying.
  * @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
@@ -1752,6 +2530,34 @@ ZENOHC_API z_result_t z_bytes_copy_from_str(struct z_owned_bytes_t *this_, const
 ZENOHC_API
 void z_bytes_copy_from_string(struct z_owned_bytes_t *this_,
                               const struct z_loaned_string_t *str);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Decompresses payload data previously compressed with `z_bytes_compress()`.
+ *
+ * @param dst: An uninitialized memory location where decompressed payload will be constructed.
+ * @param dst_encoding: An uninitialized memory location where the original encoding of the payload will be constructed.
+ * @param this_: Data to decompress.
+ * @param encoding: Encoding of the compressed data, as produced by `z_bytes_compress()`.
+ * @param options: Decompression options. If NULL, default options are used.
+ * @return 0 in case of success, negative error code otherwise. `Z_EINVAL` is returned if encoding does not correspond to
+ * a compressed payload, if data is corrupted or if decompressed data would exceed the maximum size.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_decompress(struct z_owned_bytes_t *dst,
+                              struct z_owned_encoding_t *dst_encoding,
+                              const struct z_loaned_bytes_t *this_,
+                              const struct z_loaned_encoding_t *encoding,
+                              const struct z_bytes_decompress_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_bytes_decompress_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_bytes_decompress_options_default(struct z_bytes_decompress_options_t *this_);
+#endif
 /**
  * Drops `this_`, resetting it to gravestone value. If there are any shallow copies
  * created by `z_bytes_clone()`, they would still stay valid.
@@ -1776,6 +2582,27 @@ z_result_t z_bytes_from_buf(struct z_owned_bytes_t *this_,
                             size_t len,
                             void (*deleter)(void *data, void *context),
                             void *context);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs `z_owned_bytes_t` out of an array of fragments without copying them.
+ *
+ * The resulting payload is the concatenation of all fragments in the array order.
+ * @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
+ * @param iov: A pointer to the array of fragments. `this_` will take ownership of each fragment buffer.
+ * @param iovcnt: Number of elements in `iov`.
+ * @param deleter: A thread-safe function, that will be called once on each fragment `iov_base` when `this_` is dropped.
+ * Can be `NULL` if fragments are located in static memory and do not require a drop.
+ * @param context: An optional context to be passed to `deleter`.
+ * @return 0 in case of success, negative error code otherwise. In case of failure no ownership is taken and `deleter` is never called.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_from_iovec(struct z_owned_bytes_t *this_,
+                              const struct z_iovec_t *iov,
+                              size_t iovcnt,
+                              void (*deleter)(void *data, void *context),
+                              void *context);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Converts from an immutable SHM buffer consuming it.
@@ -1884,6 +2711,24 @@ ZENOHC_API const struct z_loaned_bytes_t *z_bytes_loan(const struct z_owned_byte
  * Muatably borrows data.
  */
 ZENOHC_API struct z_loaned_bytes_t *z_bytes_loan_mut(struct z_owned_bytes_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Searches for the first occurrence of `pattern` in the remaining data, without advancing the reader.
+ *
+ * The occurrences spanning several underlying data fragments are also found.
+ * @param this_: Data reader to search in.
+ * @param pattern: A pointer to the byte sequence to search for.
+ * @param len: Length of the `pattern`.
+ * @param offset: A location where the offset of the found occurrence, relative to the current reader position, will be written.
+ * @return ``true`` if `pattern` was found, ``false`` otherwise (in this case `offset` stays unchanged).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_bytes_reader_find(const struct z_bytes_reader_t *this_,
+                         const uint8_t *pattern,
+                         size_t len,
+                         size_t *offset);
+#endif
 /**
  * Reads data into specified destination.
  *
@@ -1896,6 +2741,55 @@ ZENOHC_API
 size_t z_bytes_reader_read(struct z_bytes_reader_t *this_,
                            uint8_t *dst,
                            size_t len);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Reads `len` bytes from the current reader position into a new `z_owned_bytes_t` without copying.
+ *
+ * @param this_: Data reader to read from.
+ * @param len: Number of bytes to read.
+ * @param dst: An uninitialized memory location where the read data will be constructed.
+ * @return 0 in case of success, `Z_EINVAL` if less than `len` bytes remain in the reader (in this case the reader position is not changed).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_reader_read_bytes(struct z_bytes_reader_t *this_,
+                                     size_t len,
+                                     struct z_owned_bytes_t *dst);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Reads exactly `len` bytes into specified destination.
+ *
+ * @param this_: Data reader to read from.
+ * @param dst: Buffer where the read data is written.
+ * @param len: Number of bytes to read.
+ * @return 0 in case of success, `Z_EINVAL` if less than `len` bytes remain in the reader (in this case the reader position is not changed).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_reader_read_exact(struct z_bytes_reader_t *this_,
+                                     uint8_t *dst,
+                                     size_t len);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Reads data until the first occurrence of `delimiter` into a new `z_owned_bytes_t` without copying.
+ *
+ * The delimiter itself is consumed but not included into the read data.
+ * @param this_: Data reader to read from.
+ * @param delimiter: A pointer to the delimiter byte sequence.
+ * @param len: Length of the `delimiter`, should be greater than 0.
+ * @param dst: An uninitialized memory location where the read data will be constructed.
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if the delimiter was not found (in this case the reader position is not changed),
+ * `Z_EINVAL` if the delimiter is empty.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_bytes_reader_read_until(struct z_bytes_reader_t *this_,
+                                     const uint8_t *delimiter,
+                                     size_t len,
+                                     struct z_owned_bytes_t *dst);
+#endif
 /**
  * Gets the number of bytes that can still be read.
  */
@@ -1924,6 +2818,25 @@ ZENOHC_API int64_t z_bytes_reader_tell(struct z_bytes_reader_t *this_);
 ZENOHC_API
 bool z_bytes_slice_iterator_next(struct z_bytes_slice_iterator_t *this_,
                                  struct z_view_slice_t *slice);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Exports the fragments of the data as an array of `z_iovec_t` without copying.
+ *
+ * The filled descriptors point into the memory of `this_` and remain valid as long as `this_` is neither modified nor dropped.
+ * The memory they point to must not be written.
+ * To know the required array size, the function can first be called with `iovcnt` equal to 0.
+ *
+ * @param this_: Data to export.
+ * @param iov: A pointer to the array where fragment descriptors will be written. Can be `NULL` if `iovcnt` is 0.
+ * @param iovcnt: Capacity of `iov`.
+ * @return total number of fragments in `this_`. If it is greater than `iovcnt`, only the first `iovcnt` fragments were written.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_bytes_to_iovec(const struct z_loaned_bytes_t *this_,
+                        struct z_iovec_t *iov,
+                        size_t iovcnt);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Converts data into an owned SHM buffer by copying it's shared reference.
@@ -2163,7 +3076,6 @@ ZENOHC_API
 struct z_loaned_closure_hello_t *z_closure_hello_loan_mut(struct z_owned_closure_hello_t *closure);
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs closure.
  *
  * Closures are not guaranteed not to be called concurrently.
  *
@@ -2172,23 +3084,73 @@ struct z_loaned_closure_hello_t *z_closure_hello_loan_mut(struct z_owned_closure
  *   - `drop` will only be called **once**, and **after every** `call` has ended.
  *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
  *
+ * @brief Constructs closure.
  * @param this_: uninitialized memory location where new closure will be constructed.
  * @param call: a closure body.
  * @param drop: an optional function to be called once on closure drop.
  * @param context: closure context.
  */
-#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void z_closure_link(struct z_owned_closure_link_t *this_,
-                    void (*call)(struct z_loaned_link_t *link, void *context),
-                    void (*drop)(void *context),
-                    void *context);
+void z_closure_leadership_status(struct z_owned_closure_leadership_status_t *this_,
+                                 void (*call)(const struct z_leadership_status_t *leadership_status,
+                                              void *context),
+                                 void (*drop)(void *context),
+                                 void *context);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * Calls the closure. Calling an uninitialized closure is a no-op.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
  */
-#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_closure_leadership_status_call(const struct z_loaned_closure_leadership_status_t *closure,
+                                      const struct z_leadership_status_t *leadership_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Dropping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_closure_leadership_status_drop(struct z_moved_closure_leadership_status_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_closure_leadership_status_t *z_closure_leadership_status_loan(const struct z_owned_closure_leadership_status_t *closure);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs closure.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ *
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+void z_closure_link(struct z_owned_closure_link_t *this_,
+                    void (*call)(struct z_loaned_link_t *link, void *context),
+                    void (*drop)(void *context),
+                    void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * Calls the closure. Calling an uninitialized closure is a no-op.
+ */
+#if (defined(Z_FEATURE_UNSTABLE_API) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
 void z_closure_link_call(const struct z_loaned_closure_link_t *closure,
                          struct z_loaned_link_t *link);
@@ -2372,6 +3334,60 @@ void z_closure_reply(struct z_owned_closure_reply_t *this_,
 ZENOHC_API
 void z_closure_reply_call(const struct z_loaned_closure_reply_t *closure,
                           struct z_loaned_reply_t *reply);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ *
+ * Closures are not guaranteed not to be called concurrently.
+ *
+ * It is guaranteed that:
+ *   - `call` will never be called once `drop` has started.
+ *   - `drop` will only be called **once**, and **after every** `call` has ended.
+ *   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+ *
+ * @brief Constructs closure.
+ * @param this_: uninitialized memory location where new closure will be constructed.
+ * @param call: a closure body.
+ * @param drop: an optional function to be called once on closure drop.
+ * @param context: closure context.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_closure_reply_consolidation(struct z_owned_closure_reply_consolidation_t *this_,
+                                   void (*call)(struct z_loaned_reply_t *kept,
+                                                struct z_loaned_reply_t *candidate,
+                                                bool *replace,
+                                                void *context),
+                                   void (*drop)(void *context),
+                                   void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Calls the closure. Calling an uninitialized closure is a no-op.
+ *
+ * @return ``true`` if the candidate should replace the kept reply, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_closure_reply_consolidation_call(const struct z_loaned_closure_reply_consolidation_t *closure,
+                                        struct z_loaned_reply_t *kept,
+                                        struct z_loaned_reply_t *candidate);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the closure, resetting it to its gravestone state. Dropping an uninitialized closure is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_closure_reply_consolidation_drop(struct z_moved_closure_reply_consolidation_t *closure_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows closure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_closure_reply_consolidation_t *z_closure_reply_consolidation_loan(const struct z_owned_closure_reply_consolidation_t *closure);
+#endif
 /**
  * Drops the closure, resetting it to its gravestone state. Dropping an uninitialized closure is a no-op.
  */
@@ -2726,6 +3742,28 @@ z_result_t z_declare_link_events_listener(const struct z_loaned_session_t *sessi
                                           struct z_moved_closure_link_event_t *callback,
                                           struct z_link_events_listener_options_t *options);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs and declares a prefix publisher for the given key expression.
+ *
+ * Unlike `z_owned_publisher_t`, a prefix publisher is not bound to a single key expression: each put or delete
+ * takes a suffix which is joined to the prefix. The prefix is declared once on the network, so that messages only carry
+ * its numerical id along with the suffix, and no new declaration is made per suffix.
+ *
+ * @param session: The Zenoh session.
+ * @param publisher: An uninitialized location in memory where prefix publisher will be constructed.
+ * @param prefix: The key expression prefix to publish on. Should not contain wildcards.
+ * @param options: Additional options for the prefix publisher.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_declare_prefix_publisher(const struct z_loaned_session_t *session,
+                                      struct z_owned_prefix_publisher_t *publisher,
+                                      const struct z_loaned_keyexpr_t *prefix,
+                                      struct z_prefix_publisher_options_t *options);
+#endif
 /**
  * Constructs and declares a publisher for the given key expression.
  *
@@ -3043,6 +4081,19 @@ ZENOHC_API const struct z_loaned_encoding_t *z_encoding_image_png(void);
  *  Constant alias for string: `"image/webp"`.
  */
 ZENOHC_API const struct z_loaned_encoding_t *z_encoding_image_webp(void);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Checks if encoding corresponds to a payload compressed with `z_bytes_compress()`.
+ *
+ * @param this_: Encoding to check.
+ * @param algorithm: A memory location where compression algorithm will be written, if encoding corresponds to a compressed payload. Can be NULL.
+ * @return ``true`` if encoding corresponds to a compressed payload, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_encoding_is_compressed(const struct z_loaned_encoding_t *this_,
+                              enum z_compression_algorithm_t *algorithm);
+#endif
 /**
  * Borrows encoding.
  */
@@ -3245,6 +4296,16 @@ uint32_t z_entity_global_id_eid(const struct z_entity_global_id_t *this_);
 ZENOHC_API
 struct z_id_t z_entity_global_id_zid(const struct z_entity_global_id_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_fifo_channel_matching_status_new(struct z_owned_closure_matching_status_t *callback,
+                                        struct z_owned_fifo_handler_matching_status_t *handler,
+                                        size_t capacity);
+#endif
 /**
  * Constructs send and recieve ends of the fifo channel
  */
@@ -3266,6 +4327,45 @@ ZENOHC_API
 void z_fifo_channel_sample_new(struct z_owned_closure_sample_t *callback,
                                struct z_owned_fifo_handler_sample_t *handler,
                                size_t capacity);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_fifo_handler_matching_status_drop(struct z_moved_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_fifo_handler_matching_status_t *z_fifo_handler_matching_status_loan(const struct z_owned_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the fifo buffer. If there are no more pending matching statuses will block until next matching status is received, or until
+ * the channel is dropped (normally when the matching listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_fifo_handler_matching_status_recv(const struct z_loaned_fifo_handler_matching_status_t *this_,
+                                               struct z_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the fifo buffer.
+ * If there are no more pending matching statuses will return immediately (with matching status zero-initialized).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized),
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the matching status will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_fifo_handler_matching_status_try_recv(const struct z_loaned_fifo_handler_matching_status_t *this_,
+                                                   struct z_matching_status_t *matching_status);
+#endif
 /**
  * Drops the handler and resets it to a gravestone state.
  */
@@ -3361,10 +4461,56 @@ z_result_t z_get(const struct z_loaned_session_t *session,
                  const char *parameters,
                  struct z_moved_closure_reply_t *callback,
                  struct z_get_options_t *options);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Queries data from the matching queryables in the system, blocking until all the replies are received.
+ *
+ * The call returns once the query is finished, either because all the queryables have replied or because the query timed out,
+ * or as soon as `max_replies` replies have been received. In the latter case the query is cancelled and the remaining replies are discarded.
+ *
+ * @param session: The zenoh session.
+ * @param key_expr: The key expression matching resources to query.
+ * @param parameters: The query's parameters null-terminated string, similar to a url's query segment.
+ * @param replies: An uninitialized location in memory where the array of received replies will be constructed, in their order of reception.
+ * @param max_replies: The number of replies after which to stop waiting, 0 meaning no limit.
+ * @param options: Additional options for the get. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, a negative error value upon failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_get_collect(const struct z_loaned_session_t *session,
+                         const struct z_loaned_keyexpr_t *key_expr,
+                         const char *parameters,
+                         struct z_owned_reply_array_t *replies,
+                         size_t max_replies,
+                         struct z_get_options_t *options);
+#endif
 /**
  * Constructs default `z_get_options_t`
  */
 ZENOHC_API void z_get_options_default(struct z_get_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Query data from the matching queryables in the system.
+ * Replies are provided through a callback function.
+ *
+ * @param session: The zenoh session.
+ * @param key_expr: The key expression matching resources to query.
+ * @param parameters: The query's selector parameters.
+ * @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
+ * @param options: Additional options for the get. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, a negative error value upon failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_get_with_parameters(const struct z_loaned_session_t *session,
+                                 const struct z_loaned_keyexpr_t *key_expr,
+                                 const struct z_loaned_parameters_t *parameters,
+                                 struct z_moved_closure_reply_t *callback,
+                                 struct z_get_options_t *options);
+#endif
 /**
  * Query data from the matching queryables in the system.
  * Replies are provided through a callback function.
@@ -3574,6 +4720,22 @@ ZENOHC_API bool z_internal_closure_hello_check(const struct z_owned_closure_hell
  * Constructs a closure in a gravestone state.
  */
 ZENOHC_API void z_internal_closure_hello_null(struct z_owned_closure_hello_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_closure_leadership_status_check(const struct z_owned_closure_leadership_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a null value of 'z_owned_closure_leadership_status_t' type
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_closure_leadership_status_null(struct z_owned_closure_leadership_status_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
@@ -3630,6 +4792,22 @@ ZENOHC_API void z_internal_closure_query_null(struct z_owned_closure_query_t *th
  * Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
  */
 ZENOHC_API bool z_internal_closure_reply_check(const struct z_owned_closure_reply_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_closure_reply_consolidation_check(const struct z_owned_closure_reply_consolidation_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a closure in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_closure_reply_consolidation_null(struct z_owned_closure_reply_consolidation_t *this_);
+#endif
 /**
  * Constructs a closure int its gravestone state.
  */
@@ -3716,6 +4894,22 @@ ZENOHC_API bool z_internal_encoding_check(const struct z_owned_encoding_t *this_
  * Constructs a default `z_owned_encoding_t`.
  */
 ZENOHC_API void z_internal_encoding_null(struct z_owned_encoding_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_fifo_handler_matching_status_check(const struct z_owned_fifo_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_fifo_handler_matching_status_null(struct z_owned_fifo_handler_matching_status_t *this_);
+#endif
 /**
  * Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
  */
@@ -3807,6 +5001,38 @@ void z_internal_link_events_listener_null(struct z_owned_link_events_listener_t
 ZENOHC_API
 void z_internal_link_null(struct z_owned_link_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if liveliness election candidate is valid, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_liveliness_election_check(const struct z_owned_liveliness_election_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs liveliness election candidate in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_liveliness_election_null(struct z_owned_liveliness_election_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if liveliness lock is valid, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_liveliness_lock_check(const struct z_owned_liveliness_lock_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs liveliness lock in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_liveliness_lock_null(struct z_owned_liveliness_lock_t *this_);
+#endif
 /**
  * @brief Returns ``true`` if liveliness token is valid, ``false`` otherwise.
  */
@@ -3815,6 +5041,22 @@ ZENOHC_API bool z_internal_liveliness_token_check(const struct z_owned_livelines
  * @brief Constructs liveliness token in its gravestone state.
  */
 ZENOHC_API void z_internal_liveliness_token_null(struct z_owned_liveliness_token_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if liveliness tracker is valid, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_liveliness_tracker_check(const struct z_owned_liveliness_tracker_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs liveliness tracker in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_liveliness_tracker_null(struct z_owned_liveliness_tracker_t *this_);
+#endif
 /**
  * @brief Checks the matching listener is for the gravestone state
  */
@@ -3849,19 +5091,19 @@ ZENOHC_API bool z_internal_mutex_check(const struct z_owned_mutex_t *this_);
 ZENOHC_API void z_internal_mutex_null(struct z_owned_mutex_t *this_);
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Returns ``true`` if `this` is valid.
+ * @return ``true`` if the parameters are not in their gravestone state, i.e. not empty, ``false`` otherwise.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-bool z_internal_precomputed_layout_check(const struct z_owned_precomputed_layout_t *this_);
+bool z_internal_parameters_check(const struct z_owned_parameters_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs Alloc Layout in its gravestone value.
+ * @brief Constructs selector parameters in their gravestone state.
  */
-#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+#if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API
-void z_internal_precomputed_layout_null(struct z_owned_precomputed_layout_t *this_);
+void z_internal_parameters_null(struct z_owned_parameters_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -3869,20 +5111,52 @@ void z_internal_precomputed_layout_null(struct z_owned_precomputed_layout_t *thi
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-bool z_internal_ptr_in_segment_check(const struct z_owned_ptr_in_segment_t *this_);
+bool z_internal_precomputed_layout_check(const struct z_owned_precomputed_layout_t *this_);
 #endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
- * @brief Constructs data pointer in SHM Segment in its gravestone value.
+ * @brief Constructs Alloc Layout in its gravestone value.
  */
 #if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
 ZENOHC_API
-void z_internal_ptr_in_segment_null(struct z_owned_ptr_in_segment_t *this_);
+void z_internal_precomputed_layout_null(struct z_owned_precomputed_layout_t *this_);
 #endif
 /**
- * Returns ``true`` if publisher is valid, ``false`` otherwise.
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if prefix publisher is valid, ``false`` otherwise.
  */
-ZENOHC_API bool z_internal_publisher_check(const struct z_owned_publisher_t *this_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_prefix_publisher_check(const struct z_owned_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a prefix publisher in a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_prefix_publisher_null(struct z_owned_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if `this` is valid.
+ */
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+bool z_internal_ptr_in_segment_check(const struct z_owned_ptr_in_segment_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs data pointer in SHM Segment in its gravestone value.
+ */
+#if (defined(Z_FEATURE_SHARED_MEMORY) && defined(Z_FEATURE_UNSTABLE_API))
+ZENOHC_API
+void z_internal_ptr_in_segment_null(struct z_owned_ptr_in_segment_t *this_);
+#endif
+/**
+ * Returns ``true`` if publisher is valid, ``false`` otherwise.
+ */
+ZENOHC_API bool z_internal_publisher_check(const struct z_owned_publisher_t *this_);
 /**
  * Constructs a publisher in a gravestone state.
  */
@@ -3911,6 +5185,22 @@ ZENOHC_API bool z_internal_queryable_check(const struct z_owned_queryable_t *thi
  * Constructs a queryable in its gravestone value.
  */
 ZENOHC_API void z_internal_queryable_null(struct z_owned_queryable_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the reply array is valid, ``false`` if it is in a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_reply_array_check(const struct z_owned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs reply array in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_reply_array_null(struct z_owned_reply_array_t *this_);
+#endif
 /**
  * Returns ``true`` if `reply` is valid, ``false`` otherwise.
  */
@@ -3927,6 +5217,38 @@ ZENOHC_API void z_internal_reply_err_null(struct z_owned_reply_err_t *this_);
  * Constructs the reply in its gravestone state.
  */
 ZENOHC_API void z_internal_reply_null(struct z_owned_reply_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if reply stream is valid, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_reply_stream_check(const struct z_owned_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a reply stream in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_reply_stream_null(struct z_owned_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_ring_handler_matching_status_check(const struct z_owned_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_ring_handler_matching_status_null(struct z_owned_ring_handler_matching_status_t *this_);
+#endif
 /**
  * Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
  */
@@ -4066,10 +5388,42 @@ bool z_internal_shm_provider_check(const struct z_owned_shm_provider_t *this_);
 ZENOHC_API
 void z_internal_shm_provider_null(struct z_owned_shm_provider_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the slice array is valid, ``false`` if it is in a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_slice_array_check(const struct z_owned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs slice array in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_slice_array_null(struct z_owned_slice_array_t *this_);
+#endif
 /**
  * @return ``true`` if slice is not empty, ``false`` otherwise.
  */
 ZENOHC_API bool z_internal_slice_check(const struct z_owned_slice_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the map is not in its gravestone state, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_internal_slice_map_check(const struct z_owned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs slice map in its gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_internal_slice_map_null(struct z_owned_slice_map_t *this_);
+#endif
 /**
  * Constructs an empty `z_owned_slice_t`.
  */
@@ -4580,6 +5934,32 @@ z_result_t z_liveliness_declare_background_subscriber(const struct z_loaned_sess
                                                       const struct z_loaned_keyexpr_t *key_expr,
                                                       struct z_moved_closure_sample_t *callback,
                                                       struct z_liveliness_subscriber_options_t *options);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs and declares a candidate to the leader election `name`.
+ *
+ * The leader is the candidate holding the election's leader token. Candidates are ordered by the zid of their session, and then by
+ * their order of declaration for the candidates of the same process. When no leader is alive, the smallest candidate takes over,
+ * and a candidate joining while a leader is alive does not take over even if it is smaller. If several candidates became leaders concurrently,
+ * all of them but the smallest one step down, so the leadership gained by a candidate may be shortly lost.
+ *
+ * Leadership is bound to the liveliness of the leader's session: if the leader node fails, its tokens expire with the session lease
+ * (`transport/link/tx/lease` in zenoh configuration), and the next candidate takes over.
+ *
+ * @param session: The Zenoh session.
+ * @param election: An uninitialized memory location where the election candidate will be constructed.
+ * @param name: The name of the election. Must not contain wildcards.
+ * @param callback: The callback called whenever the candidate gains or loses leadership.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_declare_election(const struct z_loaned_session_t *session,
+                                         struct z_owned_liveliness_election_t *election,
+                                         const struct z_loaned_keyexpr_t *name,
+                                         struct z_moved_closure_leadership_status_t *callback);
+#endif
 /**
  * @brief Declares a subscriber on liveliness tokens that intersect `key_expr`.
  *
@@ -4606,13 +5986,58 @@ z_result_t z_liveliness_declare_subscriber(const struct z_loaned_session_t *sess
  * @param session: A Zenos session to declare the liveliness token.
  * @param token: An uninitialized memory location where liveliness token will be constructed.
  * @param key_expr: A keyexpr to declare a liveliess token for.
- * @param _options: Liveliness token declaration properties.
+ * @param options: Liveliness token declaration properties.
  */
 ZENOHC_API
 z_result_t z_liveliness_declare_token(const struct z_loaned_session_t *session,
                                       struct z_owned_liveliness_token_t *token,
                                       const struct z_loaned_keyexpr_t *key_expr,
-                                      const struct z_liveliness_token_options_t *_options);
+                                      const struct z_liveliness_token_options_t *options);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs and declares a liveliness tracker, keeping the set of the alive liveliness tokens intersecting `key_expr`.
+ *
+ * The tracker subscribes to liveliness changes before querying the tokens which are already alive, and only returns once the query is complete,
+ * so that the set is consistent as soon as it is declared. Replies to the query on key expressions whose liveliness changed in the meantime are discarded.
+ *
+ * @param session: The Zenoh session.
+ * @param tracker: An uninitialized memory location where the tracker will be constructed.
+ * @param key_expr: The key expression of the tokens to track.
+ * @param options: Additional options for the tracker. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_declare_tracker(const struct z_loaned_session_t *session,
+                                        struct z_owned_liveliness_tracker_t *tracker,
+                                        const struct z_loaned_keyexpr_t *key_expr,
+                                        struct z_liveliness_tracker_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares liveliness election candidate, frees memory and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_election_drop(struct z_moved_liveliness_election_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the candidate is currently the leader of the election, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_liveliness_election_is_leader(const struct z_loaned_liveliness_election_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows liveliness election candidate.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_liveliness_election_t *z_liveliness_election_loan(const struct z_owned_liveliness_election_t *this_);
+#endif
 /**
  * @brief Queries liveliness tokens currently on the network with a key expression intersecting with `key_expr`.
  *
@@ -4630,6 +6055,72 @@ z_result_t z_liveliness_get(const struct z_loaned_session_t *session,
  * @brief Constructs default value `z_liveliness_get_options_t`.
  */
 ZENOHC_API void z_liveliness_get_options_default(struct z_liveliness_get_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Acquires the distributed lock `name`, blocking until it is held.
+ *
+ * The lock is an election whose leader holds the lock: it is granted to waiters in the order of the election candidates once released,
+ * and is released when the holder's session fails (after the session lease). When several contenders acquire the lock concurrently,
+ * all of them but one give it up as soon as they notice each other, so the lock is only reported as acquired once it remained held
+ * for `z_liveliness_lock_options_t::settle_ms`. Since the holders are only known through liveliness, this relies on the liveliness tokens
+ * propagating within this time: use `z_liveliness_lock_is_held()` to check that the lock is still held.
+ *
+ * @param session: The Zenoh session.
+ * @param lock: An uninitialized memory location where the lock will be constructed.
+ * @param name: The name of the lock. Must not contain wildcards.
+ * @param options: Additional options for the lock.
+ *
+ * @return 0 in case of success, ``Z_ETIMEDOUT`` if the lock could not be acquired before the timeout, other negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_lock_acquire(const struct z_loaned_session_t *session,
+                                     struct z_owned_liveliness_lock_t *lock,
+                                     const struct z_loaned_keyexpr_t *name,
+                                     const struct z_liveliness_lock_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Releases liveliness lock, frees memory and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_lock_drop(struct z_moved_liveliness_lock_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the lock is still held, ``false`` if it was lost to a concurrent holder.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_liveliness_lock_is_held(const struct z_loaned_liveliness_lock_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows liveliness lock.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_liveliness_lock_t *z_liveliness_lock_loan(const struct z_owned_liveliness_lock_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_liveliness_lock_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_lock_options_default(struct z_liveliness_lock_options_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Releases liveliness lock.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_lock_release(struct z_moved_liveliness_lock_t *this_);
+#endif
 /**
  * @brief Constucts default value for `z_liveliness_declare_subscriber_options_t`.
  */
@@ -4648,10 +6139,99 @@ const struct z_loaned_liveliness_token_t *z_liveliness_token_loan(const struct z
  * @brief Constructs default value for `z_liveliness_token_options_t`.
  */
 ZENOHC_API void z_liveliness_token_options_default(struct z_liveliness_token_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if a token on `key_expr` is alive, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_liveliness_tracker_contains(const struct z_loaned_liveliness_tracker_t *this_,
+                                   const struct z_loaned_keyexpr_t *key_expr);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares liveliness tracker, frees memory and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_tracker_drop(struct z_moved_liveliness_tracker_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Iterates over the key expressions of the alive tokens, in lexicographic order.
+ *
+ * The iteration runs over a snapshot of the set, so `body` may safely call other functions on the tracker.
+ *
+ * @param this_: The liveliness tracker.
+ * @param body: Function called for each key expression. Iteration stops as soon as it returns ``true``.
+ * @param context: An arbitrary pointer passed to `body` on each call.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_tracker_iterate(const struct z_loaned_liveliness_tracker_t *this_,
+                                  z_liveliness_tracker_iter_body_t body,
+                                  void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the number of alive tokens.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_liveliness_tracker_len(const struct z_loaned_liveliness_tracker_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows liveliness tracker.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_liveliness_tracker_t *z_liveliness_tracker_loan(const struct z_owned_liveliness_tracker_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_liveliness_tracker_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_tracker_options_default(struct z_liveliness_tracker_options_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an array with the key expressions of the alive tokens, in lexicographic order.
+ *
+ * @param this_: The liveliness tracker.
+ * @param members: An uninitialized memory location where the array will be constructed.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_liveliness_tracker_snapshot(const struct z_loaned_liveliness_tracker_t *this_,
+                                   struct z_owned_string_array_t *members);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares liveliness election candidate, giving up leadership if it holds it.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_undeclare_election(struct z_moved_liveliness_election_t *this_);
+#endif
 /**
  * @brief Destroys a liveliness token, notifying subscribers of its destruction.
  */
 ZENOHC_API z_result_t z_liveliness_undeclare_token(struct z_moved_liveliness_token_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares liveliness tracker.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_liveliness_undeclare_tracker(struct z_moved_liveliness_tracker_t *this_);
+#endif
 /**
  * @brief Returns default value of `z_locality_t`
  */
@@ -4772,6 +6352,182 @@ z_result_t z_open_with_custom_shm_clients(struct z_owned_session_t *this_,
                                           struct z_moved_config_t *config,
                                           const struct z_loaned_shm_client_storage_t *shm_clients);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the string representation of the parameters, as sent in a selector.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_as_str(const struct z_loaned_parameters_t *this_,
+                         struct z_view_string_t *str);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an owned copy of selector parameters.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_clone(struct z_owned_parameters_t *dst,
+                        const struct z_loaned_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Destroys the parameters, resetting them to their gravestone value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_drop(struct z_moved_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs selector parameters from a string, e.g. ``"a=1;b=2|3;_time=[now(-1h)..]"``.
+ *
+ * @param this_: An uninitialized location in memory where the parameters will be constructed.
+ * @param s: A null-terminated string.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_parameters_from_str(struct z_owned_parameters_t *this_,
+                                 const char *s);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs selector parameters from a substring.
+ *
+ * @param this_: An uninitialized location in memory where the parameters will be constructed.
+ * @param s: A string, which does not need to be null-terminated.
+ * @param len: The length of the substring.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_parameters_from_substr(struct z_owned_parameters_t *this_,
+                                    const char *s,
+                                    size_t len);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the value of a parameter.
+ *
+ * If the parameter is present several times, the first value is returned.
+ * A parameter without ``=`` has an empty value.
+ *
+ * @param this_: The parameters.
+ * @param key: A null-terminated string, the name of the parameter.
+ * @param value: An uninitialized memory location where the view on the value will be constructed.
+ * It is left unchanged if the parameter is not present.
+ *
+ * @return ``true`` if the parameter is present, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_parameters_get(const struct z_loaned_parameters_t *this_,
+                      const char *key,
+                      struct z_view_string_t *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sets the value of a parameter, replacing its previous value if any.
+ *
+ * Several elements can be given in the value by separating them with ``|``.
+ *
+ * @param this_: The parameters.
+ * @param key: A non-empty null-terminated string, which should contain neither ``;`` nor ``=``.
+ * @param value: A null-terminated string, which should not contain ``;``.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_parameters_insert(struct z_loaned_parameters_t *this_,
+                               const char *key,
+                               const char *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if there are no parameters, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_parameters_is_empty(const struct z_loaned_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Iterates over the parameters in their order of appearance.
+ *
+ * @param this_: Parameters to iterate over.
+ * @param body: Function called for each key-value pair. Iteration stops as soon as it returns ``true``.
+ * @param context: An arbitrary pointer passed to `body` on each call.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_iterate(const struct z_loaned_parameters_t *this_,
+                          z_parameters_iter_body_t body,
+                          void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows selector parameters.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_parameters_t *z_parameters_loan(const struct z_owned_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows selector parameters.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_parameters_t *z_parameters_loan_mut(struct z_owned_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs empty selector parameters.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_new(struct z_owned_parameters_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Removes all the occurences of a parameter.
+ *
+ * @return ``true`` if the parameter was present, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_parameters_remove(struct z_loaned_parameters_t *this_,
+                         const char *key);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sets the ``_time`` parameter to the given time range, or removes it if `time_range` is ``NULL``.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_parameters_set_time_range(struct z_loaned_parameters_t *this_,
+                                 const struct z_time_range_t *time_range);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Parses the time range carried by the ``_time`` parameter.
+ *
+ * Bounds relative to the current time, like ``now(-1h)``, are resolved at the time of the call.
+ *
+ * @param this_: The parameters.
+ * @param time_range: An uninitialized memory location where the time range will be written.
+ *
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if there is no ``_time`` parameter, `Z_EINVAL` if its value is not a valid time range.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_parameters_time_range(const struct z_loaned_parameters_t *this_,
+                                   struct z_time_range_t *time_range);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Creates a new POSIX SHM Client.
@@ -4872,6 +6628,84 @@ z_result_t z_precomputed_layout_threadsafe_alloc_gc_defrag_async(struct z_buf_al
                                                                  void (*result_callback)(void*,
                                                                                          struct z_buf_alloc_result_t*));
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sends a `DELETE` message onto the key expression obtained by joining the publisher's prefix with `suffix`.
+ *
+ * @param this_: The prefix publisher.
+ * @param suffix: A null-terminated string to join to the publisher's prefix.
+ * @param options: The publisher delete options.
+ *
+ * @return 0 in case of success, negative error code in case of failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_prefix_publisher_delete(const struct z_loaned_prefix_publisher_t *this_,
+                                     const char *suffix,
+                                     struct z_publisher_delete_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Frees memory and resets prefix publisher to its gravestone state.
+ * This is equivalent to calling `z_undeclare_prefix_publisher()` and discarding its return value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_prefix_publisher_drop(struct z_moved_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the key expression prefix of the prefix publisher.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_keyexpr_t *z_prefix_publisher_keyexpr(const struct z_loaned_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows prefix publisher.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_prefix_publisher_t *z_prefix_publisher_loan(const struct z_owned_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows prefix publisher.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_prefix_publisher_t *z_prefix_publisher_loan_mut(struct z_owned_prefix_publisher_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_prefix_publisher_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_prefix_publisher_options_default(struct z_prefix_publisher_options_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sends a `PUT` message onto the key expression obtained by joining the publisher's prefix with `suffix`,
+ * transfering the payload ownership.
+ *
+ * The payload and all owned options fields are consumed upon function return.
+ *
+ * @param this_: The prefix publisher.
+ * @param suffix: A null-terminated string to join to the publisher's prefix, e.g. ``"42/pose"``.
+ * @param payload: The data to publish. Will be consumed.
+ * @param options: The publisher put options. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error values in case of failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_prefix_publisher_put(const struct z_loaned_prefix_publisher_t *this_,
+                                  const char *suffix,
+                                  struct z_moved_bytes_t *payload,
+                                  struct z_publisher_put_options_t *options);
+#endif
 /**
  * Returns the default value of #z_priority_t.
  */
@@ -4939,7 +6773,8 @@ z_result_t z_publisher_declare_matching_listener(const struct z_loaned_publisher
 /**
  * Sends a `DELETE` message onto the publisher's key expression.
  *
- * @return 0 in case of success, negative error code in case of failure.
+ * @return 0 in case of success, negative error code in case of failure. `Z_EUNAVAILABLE` is returned if the message was dropped
+ * due to the publisher rate limit.
  */
 ZENOHC_API
 z_result_t z_publisher_delete(const struct z_loaned_publisher_t *publisher,
@@ -4997,7 +6832,8 @@ ZENOHC_API void z_publisher_options_default(struct z_publisher_options_t *this_)
  * @param payload: The data to publish. Will be consumed.
  * @param options: The publisher put options. All owned fields will be consumed.
  *
- * @return 0 in case of success, negative error values in case of failure.
+ * @return 0 in case of success, negative error values in case of failure. `Z_EUNAVAILABLE` is returned if the message was dropped
+ * due to the publisher rate limit.
  */
 ZENOHC_API
 z_result_t z_publisher_put(const struct z_loaned_publisher_t *this_,
@@ -5007,6 +6843,24 @@ z_result_t z_publisher_put(const struct z_loaned_publisher_t *this_,
  * Constructs the default value for `z_publisher_put_options_t`.
  */
 ZENOHC_API void z_publisher_put_options_default(struct z_publisher_put_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_publisher_rate_limit_options_t`, which does not limit the publisher.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_publisher_rate_limit_options_default(struct z_publisher_rate_limit_options_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the number of puts and deletes throttled by the publisher because they exceeded its rate limit.
+ *
+ * Both messages dropped and messages delayed by `Z_RATE_LIMIT_ACTION_BLOCK` are counted.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+uint64_t z_publisher_throttled_count(const struct z_loaned_publisher_t *publisher);
+#endif
 /**
  * Publishes data on specified key expression.
  *
@@ -5084,6 +6938,25 @@ z_result_t z_querier_get_matching_status(const struct z_loaned_querier_t *this_,
  * @brief Constructs the default value for `z_querier_get_options_t`.
  */
 ZENOHC_API void z_querier_get_options_default(struct z_querier_get_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Query data from the matching queryables in the system.
+ * Replies are provided through a callback function.
+ *
+ * @param querier: The querier to make query from.
+ * @param parameters: The query's selector parameters.
+ * @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
+ * @param options: Additional options for the get. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, a negative error value upon failure.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_querier_get_with_parameters(const struct z_loaned_querier_t *querier,
+                                         const struct z_loaned_parameters_t *parameters,
+                                         struct z_moved_closure_reply_t *callback,
+                                         struct z_querier_get_options_t *options);
+#endif
 /**
  * @brief Query data from the matching queryables in the system.
  * Replies are provided through a callback function.
@@ -5124,9 +6997,32 @@ ZENOHC_API const struct z_loaned_querier_t *z_querier_loan(const struct z_owned_
  */
 ZENOHC_API struct z_loaned_querier_t *z_querier_loan_mut(struct z_owned_querier_t *this_);
 /**
- * @brief Constructs the default value for `z_querier_options_t`.
+ * @brief Constructs the default value for `z_querier_options_t`.
+ */
+ZENOHC_API void z_querier_options_default(struct z_querier_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Resets the statistics of the querier.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_querier_reset_stats(const struct z_loaned_querier_t *querier);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the statistics of the queries sent by the querier since its declaration or the last call to `z_querier_reset_stats()`.
+ *
+ * Both successful and error replies count towards the latency percentiles, which are computed on the latest 1024 replies
+ * and are 0 if no reply was received.
+ *
+ * @param querier: The querier to get the statistics of.
+ * @param stats: An uninitialized location in memory where the statistics will be written.
  */
-ZENOHC_API void z_querier_options_default(struct z_querier_options_t *this_);
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_querier_stats(const struct z_loaned_querier_t *querier,
+                     struct z_querier_stats_t *stats);
+#endif
 /**
  * @brief Gets the accept replies setting of the query,
  * i.e. which replies are accepted by the query originator.
@@ -5196,6 +7092,19 @@ ZENOHC_API void z_query_drop(struct z_moved_query_t *this_);
  */
 ZENOHC_API
 const struct z_loaned_encoding_t *z_query_encoding(const struct z_loaned_query_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Finalizes the query, signaling to the querier that no more replies will be sent by this queryable.
+ *
+ * The query is finalized for all its clones at once, without waiting for them to be dropped. Replying to it afterwards fails,
+ * while its key expression, parameters, payload and attachment can still be accessed. Finalizing an already finalized query is a no-op.
+ * If the same query was also received by other queryables of the session, the querier receives the finalization once all of them
+ * have finalized or dropped it.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_query_finalize(const struct z_loaned_query_t *this_);
+#endif
 /**
  * Gets query key expression.
  */
@@ -5248,6 +7157,27 @@ z_result_t z_query_reply(const struct z_loaned_query_t *this_,
                          const struct z_loaned_keyexpr_t *key_expr,
                          struct z_moved_bytes_t *payload,
                          struct z_query_reply_options_t *options);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sends a payload as a streamed reply to a query.
+ *
+ * This is equivalent to creating a reply stream with `z_reply_stream_new()`, sending the whole payload with
+ * `z_reply_stream_send()` and finishing it with `z_reply_stream_finish()`.
+ *
+ * @param query: The query to reply to.
+ * @param key_expr: The key expression of the replies.
+ * @param payload: The payload to send. Will be consumed.
+ * @param options: The options of the stream. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_query_reply_chunked(const struct z_loaned_query_t *query,
+                                 const struct z_loaned_keyexpr_t *key_expr,
+                                 struct z_moved_bytes_t *payload,
+                                 struct z_reply_stream_options_t *options);
+#endif
 /**
  * Sends a delete reply to a query.
  *
@@ -5296,6 +7226,14 @@ ZENOHC_API void z_query_reply_err_options_default(struct z_query_reply_err_optio
  * Constructs the default value for `z_query_reply_options_t`.
  */
 ZENOHC_API void z_query_reply_options_default(struct z_query_reply_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the parsed selector parameters of the query.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_parameters_t *z_query_selector_parameters(const struct z_loaned_query_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Returns the query source_info. Will return NULL, if source info is not set.
@@ -5325,6 +7263,14 @@ ZENOHC_API void z_queryable_drop(struct z_moved_queryable_t *this_);
 ZENOHC_API
 struct z_entity_global_id_t z_queryable_id(const struct z_loaned_queryable_t *queryable);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the queryable is paused, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_queryable_is_paused(const struct z_loaned_queryable_t *this_);
+#endif
 /**
  * @brief Returns the key expression of the queryable.
  */
@@ -5336,6 +7282,27 @@ const struct z_loaned_queryable_t *z_queryable_loan(const struct z_owned_queryab
  * Constructs the default value for `z_query_reply_options_t`.
  */
 ZENOHC_API void z_queryable_options_default(struct z_queryable_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Pauses the queryable: queries received while it is paused are dropped without calling its callback,
+ * so that the querier receives no reply from this queryable.
+ *
+ * Pausing is local: the queryable stays declared on the network and keeps its id, so matching queries are still routed
+ * to this session. Zenoh can not suspend the routing of a declared queryable, undeclare it to stop receiving queries
+ * from the network. Pausing an already paused queryable is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_queryable_pause(const struct z_loaned_queryable_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Resumes the delivery of queries to the callback of a queryable paused with `z_queryable_pause()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_queryable_resume(const struct z_loaned_queryable_t *this_);
+#endif
 /**
  * Fills buffer with random data.
  */
@@ -5372,6 +7339,82 @@ void z_ref_shm_client_storage_global(struct z_owned_shm_client_storage_t *this_)
 ZENOHC_API
 enum z_reliability_t z_reliability_default(void);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a shallow copy of a reply array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_reply_array_clone(struct z_owned_reply_array_t *dst,
+                         const struct z_loaned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Destroys the reply array, resetting it to its gravestone value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_reply_array_drop(struct z_moved_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return the reply at the position of index in the reply array.
+ *
+ * Will return `NULL` if the index is out of bounds.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_reply_t *z_reply_array_get(const struct z_loaned_reply_array_t *this_,
+                                                 size_t index);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the array is empty, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_reply_array_is_empty(const struct z_loaned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return number of replies in the array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_reply_array_len(const struct z_loaned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows reply array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_reply_array_t *z_reply_array_loan(const struct z_owned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows reply array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_reply_array_t *z_reply_array_loan_mut(struct z_owned_reply_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Gets the chunk information of a reply sent by a reply stream.
+ *
+ * This allows to process chunks as they arrive, instead of having them reassembled by `z_get()` or `z_querier_get()`.
+ *
+ * @param this_: The reply.
+ * @param out_info: An uninitialized location in memory where the chunk information will be written.
+ *
+ * @return ``true`` if the reply is a chunk of a reply stream, ``false`` otherwise, in which case `out_info` is left untouched.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_reply_chunk_info(const struct z_loaned_reply_t *this_,
+                        struct z_reply_chunk_info_t *out_info);
+#endif
 /**
  * Constructs an owned shallow copy of reply in provided uninitialized memory location.
  */
@@ -5464,10 +7507,116 @@ ZENOHC_API
 bool z_reply_replier_id(const struct z_loaned_reply_t *this_,
                         struct z_entity_global_id_t *out_id);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Aborts a reply stream if it was not finished, and resets it to its gravestone state.
+ *
+ * The data that was not yet sent is discarded, and an end-of-stream marker flagged as aborted is sent.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_reply_stream_drop(struct z_moved_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Sends the remaining data of a reply stream along with the end-of-stream marker, and releases the query.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_reply_stream_finish(struct z_moved_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows reply stream.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_reply_stream_t *z_reply_stream_loan(const struct z_owned_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows reply stream.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_reply_stream_t *z_reply_stream_loan_mut(struct z_owned_reply_stream_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Starts a streamed reply to a query.
+ *
+ * A reply stream sends a large payload as an ordered series of chunks: each chunk is a regular reply on `key_expr`,
+ * whose encoding carries a schema reserved by zenoh-c, which identifies the stream, the position of the chunk in it and
+ * whether it is the last one (see `z_reply_chunk_info()`), followed by the schema of the stream encoding.
+ * Data is added with `z_reply_stream_send()` and the stream is completed with `z_reply_stream_finish()`.
+ * Dropping a stream without finishing it sends an aborted end-of-stream marker instead.
+ *
+ * When the query reassembles the stream, an error reply is also sent ahead of the chunks, which is handed over in place
+ * of the stream if one of its chunks is lost.
+ *
+ * Chunks are subject to the congestion control of the query: with `Z_CONGESTION_CONTROL_BLOCK`, which is the default for queries,
+ * sending blocks until the network can accept more data. Since all chunks are sent on the same key expression, the query should
+ * be made with `Z_CONSOLIDATION_MODE_NONE` for them to be received; this is done automatically by `z_get()` when
+ * `z_get_options_t::reassemble_chunks` is set. Queries made with `z_querier_get()` are reassembled when
+ * `z_querier_get_options_t::reassemble_chunks` is set.
+ *
+ * The stream keeps the query alive until it is finished or dropped.
+ *
+ * @param this_: An uninitialized location in memory where reply stream will be constructed.
+ * @param query: The query to reply to.
+ * @param key_expr: The key expression of the replies.
+ * @param options: The options of the stream. All owned fields will be consumed.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_reply_stream_new(struct z_owned_reply_stream_t *this_,
+                              const struct z_loaned_query_t *query,
+                              const struct z_loaned_keyexpr_t *key_expr,
+                              struct z_reply_stream_options_t *options);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_reply_stream_options_t`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_reply_stream_options_default(struct z_reply_stream_options_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Adds data to a reply stream.
+ *
+ * Data is sent in chunks of the stream's chunk size as soon as enough of it is available. The remaining data is sent
+ * by `z_reply_stream_finish()`.
+ *
+ * @param this_: The reply stream.
+ * @param data: The data to send. Will be consumed.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_reply_stream_send(struct z_loaned_reply_stream_t *this_,
+                               struct z_moved_bytes_t *data);
+#endif
 /**
  * Takes ownership of the mutably borrowed reply
  */
 ZENOHC_API void z_reply_take_from_loaned(struct z_owned_reply_t *dst, struct z_loaned_reply_t *src);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the ring channel
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_ring_channel_matching_status_new(struct z_owned_closure_matching_status_t *callback,
+                                        struct z_owned_ring_handler_matching_status_t *handler,
+                                        size_t capacity);
+#endif
 /**
  * Constructs send and recieve ends of the ring channel
  */
@@ -5489,6 +7638,44 @@ ZENOHC_API
 void z_ring_channel_sample_new(struct z_owned_closure_sample_t *callback,
                                struct z_owned_ring_handler_sample_t *handler,
                                size_t capacity);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_ring_handler_matching_status_drop(struct z_moved_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_ring_handler_matching_status_t *z_ring_handler_matching_status_loan(const struct z_owned_ring_handler_matching_status_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the ring buffer. If there are no more pending matching statuses will block until next matching status is received, or until
+ * the channel is dropped (normally when the matching listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_ring_handler_matching_status_recv(const struct z_loaned_ring_handler_matching_status_t *this_,
+                                               struct z_matching_status_t *matching_status);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns matching status from the ring buffer. If there are no more pending matching statuses will return immediately (with matching status zero-initialized).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized),
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the matching status will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_ring_handler_matching_status_try_recv(const struct z_loaned_ring_handler_matching_status_t *this_,
+                                                   struct z_matching_status_t *matching_status);
+#endif
 /**
  * Drops the handler and resets it to a gravestone state.
  */
@@ -6172,6 +8359,99 @@ ZENOHC_API z_result_t z_sleep_s(size_t time);
  * Puts current thread to sleep for specified amount of microseconds.
  */
 ZENOHC_API z_result_t z_sleep_us(size_t time);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an owned copy of a slice array.
+ *
+ * All elements of the copy are owned, including those aliased by the original array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_array_clone(struct z_owned_slice_array_t *dst,
+                         const struct z_loaned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Destroys the slice array, resetting it to its gravestone value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_array_drop(struct z_moved_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return the value at the position of index in the slice array.
+ *
+ * Will return `NULL` if the index is out of bounds.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_slice_t *z_slice_array_get(const struct z_loaned_slice_array_t *this_,
+                                                 size_t index);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the array is empty, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_slice_array_is_empty(const struct z_loaned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return number of elements in the array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_slice_array_len(const struct z_loaned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows slice array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_slice_array_t *z_slice_array_loan(const struct z_owned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows slice array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_slice_array_t *z_slice_array_loan_mut(struct z_owned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a new empty slice array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_array_new(struct z_owned_slice_array_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Appends specified value to the end of the slice array by alias.
+ *
+ * The value should outlive the array.
+ * @return the new length of the array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_slice_array_push_by_alias(struct z_loaned_slice_array_t *this_,
+                                   const struct z_loaned_slice_t *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Appends specified value to the end of the slice array by copying.
+ *
+ * @return the new length of the array.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_slice_array_push_by_copy(struct z_loaned_slice_array_t *this_,
+                                  const struct z_loaned_slice_t *value);
+#endif
 /**
  * Constructs an owned copy of a slice.
  */
@@ -6225,6 +8505,126 @@ ZENOHC_API size_t z_slice_len(const struct z_loaned_slice_t *this_);
  * Borrows slice.
  */
 ZENOHC_API const struct z_loaned_slice_t *z_slice_loan(const struct z_owned_slice_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs an owned copy of a slice map.
+ *
+ * All keys and values of the copy are owned, including those aliased by the original map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_map_clone(struct z_owned_slice_map_t *dst,
+                       const struct z_loaned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Destroys the map, resetting it to its gravestone value.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_map_drop(struct z_moved_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns the value associated with `key`.
+ *
+ * Will return `NULL` if the key is not present in the map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_slice_t *z_slice_map_get(const struct z_loaned_slice_map_t *this_,
+                                               const struct z_loaned_slice_t *key);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Associates a key with a value in the map by aliasing them.
+ *
+ * Both key and value should outlive the map.
+ * @return ``true`` if the key was already present in the map and its value has been replaced, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_slice_map_insert_by_alias(struct z_loaned_slice_map_t *this_,
+                                 const struct z_loaned_slice_t *key,
+                                 const struct z_loaned_slice_t *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Associates a key with a value in the map by copying them.
+ *
+ * @return ``true`` if the key was already present in the map and its value has been replaced, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_slice_map_insert_by_copy(struct z_loaned_slice_map_t *this_,
+                                const struct z_loaned_slice_t *key,
+                                const struct z_loaned_slice_t *value);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the map is empty, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_slice_map_is_empty(const struct z_loaned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Iterates over key-value pairs of the map in unspecified order.
+ *
+ * @param this_: Slice map to iterate over.
+ * @param body: Function called for each key-value pair. Iteration stops as soon as it returns ``true``.
+ * @param context: An arbitrary pointer passed to `body` on each call.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_map_iterate(const struct z_loaned_slice_map_t *this_,
+                         z_slice_map_iter_body_t body,
+                         void *context);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return number of key-value pairs in the map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+size_t z_slice_map_len(const struct z_loaned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows slice map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct z_loaned_slice_map_t *z_slice_map_loan(const struct z_owned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Mutably borrows slice map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+struct z_loaned_slice_map_t *z_slice_map_loan_mut(struct z_owned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a new empty slice map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_slice_map_new(struct z_owned_slice_map_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Removes the key and its associated value from the map.
+ *
+ * @return ``true`` if the key was present in the map, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_slice_map_remove(struct z_loaned_slice_map_t *this_,
+                        const struct z_loaned_slice_t *key);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Returns the source id of the source info.
@@ -6368,11 +8768,27 @@ ZENOHC_API size_t z_string_len(const struct z_loaned_string_t *this_);
  * Borrows string.
  */
 ZENOHC_API const struct z_loaned_string_t *z_string_loan(const struct z_owned_string_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_subscriber_coalescing_options_t`, which disables coalescing.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_subscriber_coalescing_options_default(struct z_subscriber_coalescing_options_t *this_);
+#endif
 /**
  * Undeclares subscriber callback and resets it to its gravestone state.
  * This is equivalent to calling `z_undeclare_subscriber()` and discarding its return value.
  */
 ZENOHC_API void z_subscriber_drop(struct z_moved_subscriber_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs the default value for `z_subscriber_filter_options_t`, which accepts all samples.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_subscriber_filter_options_default(struct z_subscriber_filter_options_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Returns the ID of the subscriber.
@@ -6381,6 +8797,14 @@ ZENOHC_API void z_subscriber_drop(struct z_moved_subscriber_t *this_);
 ZENOHC_API
 struct z_entity_global_id_t z_subscriber_id(const struct z_loaned_subscriber_t *subscriber);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if the subscriber is paused, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_subscriber_is_paused(const struct z_loaned_subscriber_t *this_);
+#endif
 /**
  * Returns the key expression of the subscriber.
  */
@@ -6395,6 +8819,27 @@ const struct z_loaned_subscriber_t *z_subscriber_loan(const struct z_owned_subsc
  * Constructs the default value for `z_subscriber_options_t`.
  */
 ZENOHC_API void z_subscriber_options_default(struct z_subscriber_options_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Pauses the subscriber: samples received while it is paused are dropped without calling its callback.
+ *
+ * Pausing is local: the subscriber stays declared on the network and keeps its id, so matching samples are still routed
+ * to this session. Zenoh can not suspend the routing of a declared subscriber, undeclare it to stop receiving samples
+ * from the network. Samples already queued for delivery by the subscriber coalescing are still delivered.
+ * Pausing an already paused subscriber is a no-op.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_subscriber_pause(const struct z_loaned_subscriber_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Resumes the delivery of samples to the callback of a subscriber paused with `z_subscriber_pause()`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void z_subscriber_resume(const struct z_loaned_subscriber_t *this_);
+#endif
 /**
  * Detaches the task and releases all allocated resources.
  */
@@ -6446,6 +8891,24 @@ ZENOHC_API struct z_time_t z_time_now(void);
 ZENOHC_API
 const char *z_time_now_as_str(const char *buf,
                               size_t len);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the time, in milliseconds since the UNIX epoch, belongs to the time range, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_time_range_contains(const struct z_time_range_t *this_,
+                           uint64_t time_ms);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @return ``true`` if the time of the timestamp belongs to the time range, ``false`` otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool z_time_range_contains_timestamp(const struct z_time_range_t *this_,
+                                     const struct z_timestamp_t *timestamp);
+#endif
 /**
  * @brief Returns id associated with this timestamp.
  */
@@ -6698,6 +9161,16 @@ z_result_t z_undeclare_link_events_listener(struct z_moved_link_events_listener_
  * @return 0 in case of success, negative error code otherwise.
  */
 ZENOHC_API z_result_t z_undeclare_matching_listener(struct z_moved_matching_listener_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Undeclares the given prefix publisher, along with the declaration of its key expression prefix.
+ *
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t z_undeclare_prefix_publisher(struct z_moved_prefix_publisher_t *this_);
+#endif
 /**
  * @brief Undeclares the given publisher.
  *
@@ -7442,6 +9915,17 @@ const struct ze_loaned_advanced_subscriber_t *ze_advanced_subscriber_loan(const
 ZENOHC_API
 void ze_advanced_subscriber_options_default(struct ze_advanced_subscriber_options_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Advanced subscribers can not be paused: samples dropped while paused would bypass their miss detection and
+ * recovery. Undeclare the advanced subscriber to stop receiving samples.
+ *
+ * @return `Z_EUNAVAILABLE`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_advanced_subscriber_pause(const struct ze_loaned_advanced_subscriber_t *subscriber);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Constructs the default value for `ze_advanced_subscriber_recovery_options_t`.
@@ -7450,6 +9934,33 @@ void ze_advanced_subscriber_options_default(struct ze_advanced_subscriber_option
 ZENOHC_API
 void ze_advanced_subscriber_recovery_options_default(struct ze_advanced_subscriber_recovery_options_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Advanced subscribers can not be paused, see `ze_advanced_subscriber_pause()`.
+ *
+ * @return `Z_EUNAVAILABLE`.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_advanced_subscriber_resume(const struct ze_loaned_advanced_subscriber_t *subscriber);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Extracts the value associated with a single key from an attachment serialized by `ze_serialize_attachment_map()`.
+ *
+ * Unlike `ze_deserialize_attachment_map()` only the entries preceding the key are parsed.
+ * @param this_: Attachment data.
+ * @param key: A null-terminated key to look for.
+ * @param value: An uninitialized location in memory where the value is to be constructed.
+ * @return 0 in case of success, `Z_EUNAVAILABLE` if the key is not present in the attachment,
+ * `Z_EDESERIALIZE` if the attachment is not a valid attachment map.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_attachment_get(const struct z_loaned_bytes_t *this_,
+                             const char *key,
+                             struct z_owned_slice_t *value);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  *
@@ -7629,6 +10140,18 @@ z_result_t ze_declare_querying_subscriber(const struct z_loaned_session_t *sessi
                                           struct z_moved_closure_sample_t *callback,
                                           struct ze_querying_subscriber_options_t *options);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Deserializes an attachment serialized by `ze_serialize_attachment_map()` into a map of string keys to byte values.
+ * @param this_: Attachment data.
+ * @param map: An uninitialized location in memory where the map is to be constructed.
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_deserialize_attachment_map(const struct z_loaned_bytes_t *this_,
+                                         struct z_owned_slice_map_t *map);
+#endif
 /**
  * @brief Deserializes into a bool.
  * @return 0 in case of success, negative error code otherwise.
@@ -7802,6 +10325,55 @@ struct ze_deserializer_t ze_deserializer_from_bytes(const struct z_loaned_bytes_
  * @return `true` if there is no more data to parse, `false` otherwise.
  */
 ZENOHC_API bool ze_deserializer_is_done(const struct ze_deserializer_t *this_);
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the fifo channel
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_fifo_channel_miss_new(struct ze_owned_closure_miss_t *callback,
+                              struct ze_owned_fifo_handler_miss_t *handler,
+                              size_t capacity);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_fifo_handler_miss_drop(struct ze_moved_fifo_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct ze_loaned_fifo_handler_miss_t *ze_fifo_handler_miss_loan(const struct ze_owned_fifo_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns miss from the fifo buffer. If there are no more pending misses will block until next miss is received, or until
+ * the channel is dropped (normally when the sample miss listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_fifo_handler_miss_recv(const struct ze_loaned_fifo_handler_miss_t *this_,
+                                     struct ze_miss_t *miss);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns miss from the fifo buffer.
+ * If there are no more pending misses will return immediately (with miss zero-initialized).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized),
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the miss will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_fifo_handler_miss_try_recv(const struct ze_loaned_fifo_handler_miss_t *this_,
+                                         struct ze_miss_t *miss);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * Returns ``true`` if advanced publisher is valid, ``false`` otherwise.
@@ -7847,6 +10419,22 @@ bool ze_internal_closure_miss_check(const struct ze_owned_closure_miss_t *this_)
 ZENOHC_API
 void ze_internal_closure_miss_null(struct ze_owned_closure_miss_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool ze_internal_fifo_handler_miss_check(const struct ze_owned_fifo_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_internal_fifo_handler_miss_null(struct ze_owned_fifo_handler_miss_t *this_);
+#endif
 /**
  * @warning This API is deprecated. Please use ze_advanced_publisher.
  * @brief Returns ``true`` if publication cache is valid, ``false`` otherwise.
@@ -7876,6 +10464,22 @@ bool ze_internal_querying_subscriber_check(const struct ze_owned_querying_subscr
 #if defined(Z_FEATURE_UNSTABLE_API)
 ZENOHC_API void ze_internal_querying_subscriber_null(struct ze_owned_querying_subscriber_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+bool ze_internal_ring_handler_miss_check(const struct ze_owned_ring_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs a handler in gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_internal_ring_handler_miss_null(struct ze_owned_ring_handler_miss_t *this_);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Checks the sample_miss listener is for the gravestone state
@@ -7968,6 +10572,54 @@ const struct ze_loaned_querying_subscriber_t *ze_querying_subscriber_loan(const
 ZENOHC_API
 void ze_querying_subscriber_options_default(struct ze_querying_subscriber_options_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Constructs send and recieve ends of the ring channel
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_ring_channel_miss_new(struct ze_owned_closure_miss_t *callback,
+                              struct ze_owned_ring_handler_miss_t *handler,
+                              size_t capacity);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Drops the handler and resets it to a gravestone state.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+void ze_ring_handler_miss_drop(struct ze_moved_ring_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Borrows handler.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+const struct ze_loaned_ring_handler_miss_t *ze_ring_handler_miss_loan(const struct ze_owned_ring_handler_miss_t *this_);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns miss from the ring buffer. If there are no more pending misses will block until next miss is received, or until
+ * the channel is dropped (normally when the sample miss listener is undeclared).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_ring_handler_miss_recv(const struct ze_loaned_ring_handler_miss_t *this_,
+                                     struct ze_miss_t *miss);
+#endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Returns miss from the ring buffer. If there are no more pending misses will return immediately (with miss zero-initialized).
+ * @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized),
+ * `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the miss will be zero-initialized).
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_ring_handler_miss_try_recv(const struct ze_loaned_ring_handler_miss_t *this_,
+                                         struct ze_miss_t *miss);
+#endif
 /**
  * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
  * @brief Undeclares the given sample miss listener, dropping and invalidating it.
@@ -7976,6 +10628,21 @@ void ze_querying_subscriber_options_default(struct ze_querying_subscriber_option
 ZENOHC_API
 void ze_sample_miss_listener_drop(struct ze_moved_sample_miss_listener_t *this_);
 #endif
+/**
+ * @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+ * @brief Serializes a map of string keys to byte values, to be used as sample, query or reply attachment.
+ *
+ * The map is serialized the same way as `HashMap<String, Vec<u8>>` is serialized by zenoh-ext in Rust, so it can be
+ * read by other zenoh bindings. All keys should be valid UTF-8.
+ * @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
+ * @param map: A map to serialize.
+ * @return 0 in case of success, negative error code otherwise.
+ */
+#if defined(Z_FEATURE_UNSTABLE_API)
+ZENOHC_API
+z_result_t ze_serialize_attachment_map(struct z_owned_bytes_t *this_,
+                                       const struct z_loaned_slice_map_t *map);
+#endif
 /**
  * @brief Serializes a bool.
  */
@@ -37,5 +37,6 @@
 #define Z_EBUSY_MUTEX -16
 #define Z_EINVAL_MUTEX -22
 #define Z_EAGAIN_MUTEX -11
+#define Z_ETIMEDOUT -110
 #define Z_EPOISON_MUTEX -22
 #define Z_EGENERIC INT8_MIN
@@ -9,10 +9,12 @@ static inline z_moved_bytes_writer_t* z_bytes_writer_move(z_owned_bytes_writer_t
 static inline z_moved_cancellation_token_t* z_cancellation_token_move(z_owned_cancellation_token_t* x) { return (z_moved_cancellation_token_t*)(x); }
 static inline z_moved_chunk_alloc_result_t* z_chunk_alloc_result_move(z_owned_chunk_alloc_result_t* x) { return (z_moved_chunk_alloc_result_t*)(x); }
 static inline z_moved_closure_hello_t* z_closure_hello_move(z_owned_closure_hello_t* x) { return (z_moved_closure_hello_t*)(x); }
+static inline z_moved_closure_leadership_status_t* z_closure_leadership_status_move(z_owned_closure_leadership_status_t* x) { return (z_moved_closure_leadership_status_t*)(x); }
 static inline z_moved_closure_link_t* z_closure_link_move(z_owned_closure_link_t* x) { return (z_moved_closure_link_t*)(x); }
 static inline z_moved_closure_link_event_t* z_closure_link_event_move(z_owned_closure_link_event_t* x) { return (z_moved_closure_link_event_t*)(x); }
 static inline z_moved_closure_matching_status_t* z_closure_matching_status_move(z_owned_closure_matching_status_t* x) { return (z_moved_closure_matching_status_t*)(x); }
 static inline z_moved_closure_query_t* z_closure_query_move(z_owned_closure_query_t* x) { return (z_moved_closure_query_t*)(x); }
+static inline z_moved_closure_reply_consolidation_t* z_closure_reply_consolidation_move(z_owned_closure_reply_consolidation_t* x) { return (z_moved_closure_reply_consolidation_t*)(x); }
 static inline z_moved_closure_reply_t* z_closure_reply_move(z_owned_closure_reply_t* x) { return (z_moved_closure_reply_t*)(x); }
 static inline z_moved_closure_sample_t* z_closure_sample_move(z_owned_closure_sample_t* x) { return (z_moved_closure_sample_t*)(x); }
 static inline z_moved_closure_transport_t* z_closure_transport_move(z_owned_closure_transport_t* x) { return (z_moved_closure_transport_t*)(x); }
@@ -21,6 +23,7 @@ static inline z_moved_closure_zid_t* z_closure_zid_move(z_owned_closure_zid_t* x
 static inline z_moved_condvar_t* z_condvar_move(z_owned_condvar_t* x) { return (z_moved_condvar_t*)(x); }
 static inline z_moved_config_t* z_config_move(z_owned_config_t* x) { return (z_moved_config_t*)(x); }
 static inline z_moved_encoding_t* z_encoding_move(z_owned_encoding_t* x) { return (z_moved_encoding_t*)(x); }
+static inline z_moved_fifo_handler_matching_status_t* z_fifo_handler_matching_status_move(z_owned_fifo_handler_matching_status_t* x) { return (z_moved_fifo_handler_matching_status_t*)(x); }
 static inline z_moved_fifo_handler_query_t* z_fifo_handler_query_move(z_owned_fifo_handler_query_t* x) { return (z_moved_fifo_handler_query_t*)(x); }
 static inline z_moved_fifo_handler_reply_t* z_fifo_handler_reply_move(z_owned_fifo_handler_reply_t* x) { return (z_moved_fifo_handler_reply_t*)(x); }
 static inline z_moved_fifo_handler_sample_t* z_fifo_handler_sample_move(z_owned_fifo_handler_sample_t* x) { return (z_moved_fifo_handler_sample_t*)(x); }
@@ -29,18 +32,26 @@ static inline z_moved_keyexpr_t* z_keyexpr_move(z_owned_keyexpr_t* x) { return (
 static inline z_moved_link_t* z_link_move(z_owned_link_t* x) { return (z_moved_link_t*)(x); }
 static inline z_moved_link_event_t* z_link_event_move(z_owned_link_event_t* x) { return (z_moved_link_event_t*)(x); }
 static inline z_moved_link_events_listener_t* z_link_events_listener_move(z_owned_link_events_listener_t* x) { return (z_moved_link_events_listener_t*)(x); }
+static inline z_moved_liveliness_election_t* z_liveliness_election_move(z_owned_liveliness_election_t* x) { return (z_moved_liveliness_election_t*)(x); }
+static inline z_moved_liveliness_lock_t* z_liveliness_lock_move(z_owned_liveliness_lock_t* x) { return (z_moved_liveliness_lock_t*)(x); }
 static inline z_moved_liveliness_token_t* z_liveliness_token_move(z_owned_liveliness_token_t* x) { return (z_moved_liveliness_token_t*)(x); }
+static inline z_moved_liveliness_tracker_t* z_liveliness_tracker_move(z_owned_liveliness_tracker_t* x) { return (z_moved_liveliness_tracker_t*)(x); }
 static inline z_moved_matching_listener_t* z_matching_listener_move(z_owned_matching_listener_t* x) { return (z_moved_matching_listener_t*)(x); }
 static inline z_moved_memory_layout_t* z_memory_layout_move(z_owned_memory_layout_t* x) { return (z_moved_memory_layout_t*)(x); }
 static inline z_moved_mutex_t* z_mutex_move(z_owned_mutex_t* x) { return (z_moved_mutex_t*)(x); }
+static inline z_moved_parameters_t* z_parameters_move(z_owned_parameters_t* x) { return (z_moved_parameters_t*)(x); }
 static inline z_moved_precomputed_layout_t* z_precomputed_layout_move(z_owned_precomputed_layout_t* x) { return (z_moved_precomputed_layout_t*)(x); }
+static inline z_moved_prefix_publisher_t* z_prefix_publisher_move(z_owned_prefix_publisher_t* x) { return (z_moved_prefix_publisher_t*)(x); }
 static inline z_moved_ptr_in_segment_t* z_ptr_in_segment_move(z_owned_ptr_in_segment_t* x) { return (z_moved_ptr_in_segment_t*)(x); }
 static inline z_moved_publisher_t* z_publisher_move(z_owned_publisher_t* x) { return (z_moved_publisher_t*)(x); }
 static inline z_moved_querier_t* z_querier_move(z_owned_querier_t* x) { return (z_moved_querier_t*)(x); }
 static inline z_moved_query_t* z_query_move(z_owned_query_t* x) { return (z_moved_query_t*)(x); }
 static inline z_moved_queryable_t* z_queryable_move(z_owned_queryable_t* x) { return (z_moved_queryable_t*)(x); }
+static inline z_moved_reply_array_t* z_reply_array_move(z_owned_reply_array_t* x) { return (z_moved_reply_array_t*)(x); }
 static inline z_moved_reply_t* z_reply_move(z_owned_reply_t* x) { return (z_moved_reply_t*)(x); }
 static inline z_moved_reply_err_t* z_reply_err_move(z_owned_reply_err_t* x) { return (z_moved_reply_err_t*)(x); }
+static inline z_moved_reply_stream_t* z_reply_stream_move(z_owned_reply_stream_t* x) { return (z_moved_reply_stream_t*)(x); }
+static inline z_moved_ring_handler_matching_status_t* z_ring_handler_matching_status_move(z_owned_ring_handler_matching_status_t* x) { return (z_moved_ring_handler_matching_status_t*)(x); }
 static inline z_moved_ring_handler_query_t* z_ring_handler_query_move(z_owned_ring_handler_query_t* x) { return (z_moved_ring_handler_query_t*)(x); }
 static inline z_moved_ring_handler_reply_t* z_ring_handler_reply_move(z_owned_ring_handler_reply_t* x) { return (z_moved_ring_handler_reply_t*)(x); }
 static inline z_moved_ring_handler_sample_t* z_ring_handler_sample_move(z_owned_ring_handler_sample_t* x) { return (z_moved_ring_handler_sample_t*)(x); }
@@ -52,7 +63,9 @@ static inline z_moved_shm_client_storage_t* z_shm_client_storage_move(z_owned_sh
 static inline z_moved_shm_t* z_shm_move(z_owned_shm_t* x) { return (z_moved_shm_t*)(x); }
 static inline z_moved_shm_mut_t* z_shm_mut_move(z_owned_shm_mut_t* x) { return (z_moved_shm_mut_t*)(x); }
 static inline z_moved_shm_provider_t* z_shm_provider_move(z_owned_shm_provider_t* x) { return (z_moved_shm_provider_t*)(x); }
+static inline z_moved_slice_array_t* z_slice_array_move(z_owned_slice_array_t* x) { return (z_moved_slice_array_t*)(x); }
 static inline z_moved_slice_t* z_slice_move(z_owned_slice_t* x) { return (z_moved_slice_t*)(x); }
+static inline z_moved_slice_map_t* z_slice_map_move(z_owned_slice_map_t* x) { return (z_moved_slice_map_t*)(x); }
 static inline z_moved_string_array_t* z_string_array_move(z_owned_string_array_t* x) { return (z_moved_string_array_t*)(x); }
 static inline z_moved_string_t* z_string_move(z_owned_string_t* x) { return (z_moved_string_t*)(x); }
 static inline z_moved_subscriber_t* z_subscriber_move(z_owned_subscriber_t* x) { return (z_moved_subscriber_t*)(x); }
@@ -66,8 +79,10 @@ static inline zc_moved_shm_client_list_t* zc_shm_client_list_move(zc_owned_shm_c
 static inline ze_moved_advanced_publisher_t* ze_advanced_publisher_move(ze_owned_advanced_publisher_t* x) { return (ze_moved_advanced_publisher_t*)(x); }
 static inline ze_moved_advanced_subscriber_t* ze_advanced_subscriber_move(ze_owned_advanced_subscriber_t* x) { return (ze_moved_advanced_subscriber_t*)(x); }
 static inline ze_moved_closure_miss_t* ze_closure_miss_move(ze_owned_closure_miss_t* x) { return (ze_moved_closure_miss_t*)(x); }
+static inline ze_moved_fifo_handler_miss_t* ze_fifo_handler_miss_move(ze_owned_fifo_handler_miss_t* x) { return (ze_moved_fifo_handler_miss_t*)(x); }
 static inline ze_moved_publication_cache_t* ze_publication_cache_move(ze_owned_publication_cache_t* x) { return (ze_moved_publication_cache_t*)(x); }
 static inline ze_moved_querying_subscriber_t* ze_querying_subscriber_move(ze_owned_querying_subscriber_t* x) { return (ze_moved_querying_subscriber_t*)(x); }
+static inline ze_moved_ring_handler_miss_t* ze_ring_handler_miss_move(ze_owned_ring_handler_miss_t* x) { return (ze_moved_ring_handler_miss_t*)(x); }
 static inline ze_moved_sample_miss_listener_t* ze_sample_miss_listener_move(ze_owned_sample_miss_listener_t* x) { return (ze_moved_sample_miss_listener_t*)(x); }
 static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x) { return (ze_moved_serializer_t*)(x); }
 
@@ -78,10 +93,12 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_bytes_writer_t : z_bytes_writer_loan, \
         z_owned_cancellation_token_t : z_cancellation_token_loan, \
         z_owned_closure_hello_t : z_closure_hello_loan, \
+        z_owned_closure_leadership_status_t : z_closure_leadership_status_loan, \
         z_owned_closure_link_event_t : z_closure_link_event_loan, \
         z_owned_closure_link_t : z_closure_link_loan, \
         z_owned_closure_matching_status_t : z_closure_matching_status_loan, \
         z_owned_closure_query_t : z_closure_query_loan, \
+        z_owned_closure_reply_consolidation_t : z_closure_reply_consolidation_loan, \
         z_owned_closure_reply_t : z_closure_reply_loan, \
         z_owned_closure_sample_t : z_closure_sample_loan, \
         z_owned_closure_transport_event_t : z_closure_transport_event_loan, \
@@ -90,6 +107,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_condvar_t : z_condvar_loan, \
         z_owned_config_t : z_config_loan, \
         z_owned_encoding_t : z_encoding_loan, \
+        z_owned_fifo_handler_matching_status_t : z_fifo_handler_matching_status_loan, \
         z_owned_fifo_handler_query_t : z_fifo_handler_query_loan, \
         z_owned_fifo_handler_reply_t : z_fifo_handler_reply_loan, \
         z_owned_fifo_handler_sample_t : z_fifo_handler_sample_loan, \
@@ -98,16 +116,24 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_link_event_t : z_link_event_loan, \
         z_owned_link_events_listener_t : z_link_events_listener_loan, \
         z_owned_link_t : z_link_loan, \
+        z_owned_liveliness_election_t : z_liveliness_election_loan, \
+        z_owned_liveliness_lock_t : z_liveliness_lock_loan, \
         z_owned_liveliness_token_t : z_liveliness_token_loan, \
+        z_owned_liveliness_tracker_t : z_liveliness_tracker_loan, \
         z_owned_memory_layout_t : z_memory_layout_loan, \
+        z_owned_parameters_t : z_parameters_loan, \
         z_owned_precomputed_layout_t : z_precomputed_layout_loan, \
+        z_owned_prefix_publisher_t : z_prefix_publisher_loan, \
         z_owned_ptr_in_segment_t : z_ptr_in_segment_loan, \
         z_owned_publisher_t : z_publisher_loan, \
         z_owned_querier_t : z_querier_loan, \
         z_owned_query_t : z_query_loan, \
         z_owned_queryable_t : z_queryable_loan, \
+        z_owned_reply_array_t : z_reply_array_loan, \
         z_owned_reply_err_t : z_reply_err_loan, \
         z_owned_reply_t : z_reply_loan, \
+        z_owned_reply_stream_t : z_reply_stream_loan, \
+        z_owned_ring_handler_matching_status_t : z_ring_handler_matching_status_loan, \
         z_owned_ring_handler_query_t : z_ring_handler_query_loan, \
         z_owned_ring_handler_reply_t : z_ring_handler_reply_loan, \
         z_owned_ring_handler_sample_t : z_ring_handler_sample_loan, \
@@ -118,7 +144,9 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_shm_t : z_shm_loan, \
         z_owned_shm_mut_t : z_shm_mut_loan, \
         z_owned_shm_provider_t : z_shm_provider_loan, \
+        z_owned_slice_array_t : z_slice_array_loan, \
         z_owned_slice_t : z_slice_loan, \
+        z_owned_slice_map_t : z_slice_map_loan, \
         z_owned_string_array_t : z_string_array_loan, \
         z_owned_string_t : z_string_loan, \
         z_owned_subscriber_t : z_subscriber_loan, \
@@ -133,8 +161,10 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         ze_owned_advanced_publisher_t : ze_advanced_publisher_loan, \
         ze_owned_advanced_subscriber_t : ze_advanced_subscriber_loan, \
         ze_owned_closure_miss_t : ze_closure_miss_loan, \
+        ze_owned_fifo_handler_miss_t : ze_fifo_handler_miss_loan, \
         ze_owned_publication_cache_t : ze_publication_cache_loan, \
         ze_owned_querying_subscriber_t : ze_querying_subscriber_loan, \
+        ze_owned_ring_handler_miss_t : ze_ring_handler_miss_loan, \
         ze_owned_serializer_t : ze_serializer_loan \
     )(&this_)
 
@@ -156,15 +186,21 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_link_event_t : z_link_event_loan_mut, \
         z_owned_link_t : z_link_loan_mut, \
         z_owned_mutex_t : z_mutex_loan_mut, \
+        z_owned_parameters_t : z_parameters_loan_mut, \
+        z_owned_prefix_publisher_t : z_prefix_publisher_loan_mut, \
         z_owned_publisher_t : z_publisher_loan_mut, \
         z_owned_querier_t : z_querier_loan_mut, \
         z_owned_query_t : z_query_loan_mut, \
+        z_owned_reply_array_t : z_reply_array_loan_mut, \
         z_owned_reply_err_t : z_reply_err_loan_mut, \
         z_owned_reply_t : z_reply_loan_mut, \
+        z_owned_reply_stream_t : z_reply_stream_loan_mut, \
         z_owned_sample_t : z_sample_loan_mut, \
         z_owned_session_t : z_session_loan_mut, \
         z_owned_shm_t : z_shm_loan_mut, \
         z_owned_shm_mut_t : z_shm_mut_loan_mut, \
+        z_owned_slice_array_t : z_slice_array_loan_mut, \
+        z_owned_slice_map_t : z_slice_map_loan_mut, \
         z_owned_string_array_t : z_string_array_loan_mut, \
         z_owned_transport_event_t : z_transport_event_loan_mut, \
         z_owned_transport_t : z_transport_loan_mut, \
@@ -180,10 +216,12 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_cancellation_token_t* : z_cancellation_token_drop, \
         z_moved_chunk_alloc_result_t* : z_chunk_alloc_result_drop, \
         z_moved_closure_hello_t* : z_closure_hello_drop, \
+        z_moved_closure_leadership_status_t* : z_closure_leadership_status_drop, \
         z_moved_closure_link_t* : z_closure_link_drop, \
         z_moved_closure_link_event_t* : z_closure_link_event_drop, \
         z_moved_closure_matching_status_t* : z_closure_matching_status_drop, \
         z_moved_closure_query_t* : z_closure_query_drop, \
+        z_moved_closure_reply_consolidation_t* : z_closure_reply_consolidation_drop, \
         z_moved_closure_reply_t* : z_closure_reply_drop, \
         z_moved_closure_sample_t* : z_closure_sample_drop, \
         z_moved_closure_transport_t* : z_closure_transport_drop, \
@@ -192,6 +230,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_condvar_t* : z_condvar_drop, \
         z_moved_config_t* : z_config_drop, \
         z_moved_encoding_t* : z_encoding_drop, \
+        z_moved_fifo_handler_matching_status_t* : z_fifo_handler_matching_status_drop, \
         z_moved_fifo_handler_query_t* : z_fifo_handler_query_drop, \
         z_moved_fifo_handler_reply_t* : z_fifo_handler_reply_drop, \
         z_moved_fifo_handler_sample_t* : z_fifo_handler_sample_drop, \
@@ -200,18 +239,26 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_link_t* : z_link_drop, \
         z_moved_link_event_t* : z_link_event_drop, \
         z_moved_link_events_listener_t* : z_link_events_listener_drop, \
+        z_moved_liveliness_election_t* : z_liveliness_election_drop, \
+        z_moved_liveliness_lock_t* : z_liveliness_lock_drop, \
         z_moved_liveliness_token_t* : z_liveliness_token_drop, \
+        z_moved_liveliness_tracker_t* : z_liveliness_tracker_drop, \
         z_moved_matching_listener_t* : z_matching_listener_drop, \
         z_moved_memory_layout_t* : z_memory_layout_drop, \
         z_moved_mutex_t* : z_mutex_drop, \
+        z_moved_parameters_t* : z_parameters_drop, \
         z_moved_precomputed_layout_t* : z_precomputed_layout_drop, \
+        z_moved_prefix_publisher_t* : z_prefix_publisher_drop, \
         z_moved_ptr_in_segment_t* : z_ptr_in_segment_drop, \
         z_moved_publisher_t* : z_publisher_drop, \
         z_moved_querier_t* : z_querier_drop, \
         z_moved_query_t* : z_query_drop, \
         z_moved_queryable_t* : z_queryable_drop, \
+        z_moved_reply_array_t* : z_reply_array_drop, \
         z_moved_reply_t* : z_reply_drop, \
         z_moved_reply_err_t* : z_reply_err_drop, \
+        z_moved_reply_stream_t* : z_reply_stream_drop, \
+        z_moved_ring_handler_matching_status_t* : z_ring_handler_matching_status_drop, \
         z_moved_ring_handler_query_t* : z_ring_handler_query_drop, \
         z_moved_ring_handler_reply_t* : z_ring_handler_reply_drop, \
         z_moved_ring_handler_sample_t* : z_ring_handler_sample_drop, \
@@ -223,7 +270,9 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_moved_shm_t* : z_shm_drop, \
         z_moved_shm_mut_t* : z_shm_mut_drop, \
         z_moved_shm_provider_t* : z_shm_provider_drop, \
+        z_moved_slice_array_t* : z_slice_array_drop, \
         z_moved_slice_t* : z_slice_drop, \
+        z_moved_slice_map_t* : z_slice_map_drop, \
         z_moved_string_array_t* : z_string_array_drop, \
         z_moved_string_t* : z_string_drop, \
         z_moved_subscriber_t* : z_subscriber_drop, \
@@ -237,8 +286,10 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         ze_moved_advanced_publisher_t* : ze_advanced_publisher_drop, \
         ze_moved_advanced_subscriber_t* : ze_advanced_subscriber_drop, \
         ze_moved_closure_miss_t* : ze_closure_miss_drop, \
+        ze_moved_fifo_handler_miss_t* : ze_fifo_handler_miss_drop, \
         ze_moved_publication_cache_t* : ze_publication_cache_drop, \
         ze_moved_querying_subscriber_t* : ze_querying_subscriber_drop, \
+        ze_moved_ring_handler_miss_t* : ze_ring_handler_miss_drop, \
         ze_moved_sample_miss_listener_t* : ze_sample_miss_listener_drop, \
         ze_moved_serializer_t* : ze_serializer_drop \
     )(this_)
@@ -250,10 +301,12 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_cancellation_token_t : z_cancellation_token_move, \
         z_owned_chunk_alloc_result_t : z_chunk_alloc_result_move, \
         z_owned_closure_hello_t : z_closure_hello_move, \
+        z_owned_closure_leadership_status_t : z_closure_leadership_status_move, \
         z_owned_closure_link_t : z_closure_link_move, \
         z_owned_closure_link_event_t : z_closure_link_event_move, \
         z_owned_closure_matching_status_t : z_closure_matching_status_move, \
         z_owned_closure_query_t : z_closure_query_move, \
+        z_owned_closure_reply_consolidation_t : z_closure_reply_consolidation_move, \
         z_owned_closure_reply_t : z_closure_reply_move, \
         z_owned_closure_sample_t : z_closure_sample_move, \
         z_owned_closure_transport_t : z_closure_transport_move, \
@@ -262,6 +315,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_condvar_t : z_condvar_move, \
         z_owned_config_t : z_config_move, \
         z_owned_encoding_t : z_encoding_move, \
+        z_owned_fifo_handler_matching_status_t : z_fifo_handler_matching_status_move, \
         z_owned_fifo_handler_query_t : z_fifo_handler_query_move, \
         z_owned_fifo_handler_reply_t : z_fifo_handler_reply_move, \
         z_owned_fifo_handler_sample_t : z_fifo_handler_sample_move, \
@@ -270,18 +324,26 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_link_t : z_link_move, \
         z_owned_link_event_t : z_link_event_move, \
         z_owned_link_events_listener_t : z_link_events_listener_move, \
+        z_owned_liveliness_election_t : z_liveliness_election_move, \
+        z_owned_liveliness_lock_t : z_liveliness_lock_move, \
         z_owned_liveliness_token_t : z_liveliness_token_move, \
+        z_owned_liveliness_tracker_t : z_liveliness_tracker_move, \
         z_owned_matching_listener_t : z_matching_listener_move, \
         z_owned_memory_layout_t : z_memory_layout_move, \
         z_owned_mutex_t : z_mutex_move, \
+        z_owned_parameters_t : z_parameters_move, \
         z_owned_precomputed_layout_t : z_precomputed_layout_move, \
+        z_owned_prefix_publisher_t : z_prefix_publisher_move, \
         z_owned_ptr_in_segment_t : z_ptr_in_segment_move, \
         z_owned_publisher_t : z_publisher_move, \
         z_owned_querier_t : z_querier_move, \
         z_owned_query_t : z_query_move, \
         z_owned_queryable_t : z_queryable_move, \
+        z_owned_reply_array_t : z_reply_array_move, \
         z_owned_reply_t : z_reply_move, \
         z_owned_reply_err_t : z_reply_err_move, \
+        z_owned_reply_stream_t : z_reply_stream_move, \
+        z_owned_ring_handler_matching_status_t : z_ring_handler_matching_status_move, \
         z_owned_ring_handler_query_t : z_ring_handler_query_move, \
         z_owned_ring_handler_reply_t : z_ring_handler_reply_move, \
         z_owned_ring_handler_sample_t : z_ring_handler_sample_move, \
@@ -293,7 +355,9 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_shm_t : z_shm_move, \
         z_owned_shm_mut_t : z_shm_mut_move, \
         z_owned_shm_provider_t : z_shm_provider_move, \
+        z_owned_slice_array_t : z_slice_array_move, \
         z_owned_slice_t : z_slice_move, \
+        z_owned_slice_map_t : z_slice_map_move, \
         z_owned_string_array_t : z_string_array_move, \
         z_owned_string_t : z_string_move, \
         z_owned_subscriber_t : z_subscriber_move, \
@@ -307,8 +371,10 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         ze_owned_advanced_publisher_t : ze_advanced_publisher_move, \
         ze_owned_advanced_subscriber_t : ze_advanced_subscriber_move, \
         ze_owned_closure_miss_t : ze_closure_miss_move, \
+        ze_owned_fifo_handler_miss_t : ze_fifo_handler_miss_move, \
         ze_owned_publication_cache_t : ze_publication_cache_move, \
         ze_owned_querying_subscriber_t : ze_querying_subscriber_move, \
+        ze_owned_ring_handler_miss_t : ze_ring_handler_miss_move, \
         ze_owned_sample_miss_listener_t : ze_sample_miss_listener_move, \
         ze_owned_serializer_t : ze_serializer_move \
     )(&this_)
@@ -320,10 +386,12 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_cancellation_token_t* : z_internal_cancellation_token_null, \
         z_owned_chunk_alloc_result_t* : z_internal_chunk_alloc_result_null, \
         z_owned_closure_hello_t* : z_internal_closure_hello_null, \
+        z_owned_closure_leadership_status_t* : z_internal_closure_leadership_status_null, \
         z_owned_closure_link_event_t* : z_internal_closure_link_event_null, \
         z_owned_closure_link_t* : z_internal_closure_link_null, \
         z_owned_closure_matching_status_t* : z_internal_closure_matching_status_null, \
         z_owned_closure_query_t* : z_internal_closure_query_null, \
+        z_owned_closure_reply_consolidation_t* : z_internal_closure_reply_consolidation_null, \
         z_owned_closure_reply_t* : z_internal_closure_reply_null, \
         z_owned_closure_sample_t* : z_internal_closure_sample_null, \
         z_owned_closure_transport_event_t* : z_internal_closure_transport_event_null, \
@@ -332,6 +400,7 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_condvar_t* : z_internal_condvar_null, \
         z_owned_config_t* : z_internal_config_null, \
         z_owned_encoding_t* : z_internal_encoding_null, \
+        z_owned_fifo_handler_matching_status_t* : z_internal_fifo_handler_matching_status_null, \
         z_owned_fifo_handler_query_t* : z_internal_fifo_handler_query_null, \
         z_owned_fifo_handler_reply_t* : z_internal_fifo_handler_reply_null, \
         z_owned_fifo_handler_sample_t* : z_internal_fifo_handler_sample_null, \
@@ -340,18 +409,26 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_link_event_t* : z_internal_link_event_null, \
         z_owned_link_events_listener_t* : z_internal_link_events_listener_null, \
         z_owned_link_t* : z_internal_link_null, \
+        z_owned_liveliness_election_t* : z_internal_liveliness_election_null, \
+        z_owned_liveliness_lock_t* : z_internal_liveliness_lock_null, \
         z_owned_liveliness_token_t* : z_internal_liveliness_token_null, \
+        z_owned_liveliness_tracker_t* : z_internal_liveliness_tracker_null, \
         z_owned_matching_listener_t* : z_internal_matching_listener_null, \
         z_owned_memory_layout_t* : z_internal_memory_layout_null, \
         z_owned_mutex_t* : z_internal_mutex_null, \
+        z_owned_parameters_t* : z_internal_parameters_null, \
         z_owned_precomputed_layout_t* : z_internal_precomputed_layout_null, \
+        z_owned_prefix_publisher_t* : z_internal_prefix_publisher_null, \
         z_owned_ptr_in_segment_t* : z_internal_ptr_in_segment_null, \
         z_owned_publisher_t* : z_internal_publisher_null, \
         z_owned_querier_t* : z_internal_querier_null, \
         z_owned_query_t* : z_internal_query_null, \
         z_owned_queryable_t* : z_internal_queryable_null, \
+        z_owned_reply_array_t* : z_internal_reply_array_null, \
         z_owned_reply_err_t* : z_internal_reply_err_null, \
         z_owned_reply_t* : z_internal_reply_null, \
+        z_owned_reply_stream_t* : z_internal_reply_stream_null, \
+        z_owned_ring_handler_matching_status_t* : z_internal_ring_handler_matching_status_null, \
         z_owned_ring_handler_query_t* : z_internal_ring_handler_query_null, \
         z_owned_ring_handler_reply_t* : z_internal_ring_handler_reply_null, \
         z_owned_ring_handler_sample_t* : z_internal_ring_handler_sample_null, \
@@ -363,6 +440,8 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         z_owned_shm_mut_t* : z_internal_shm_mut_null, \
         z_owned_shm_t* : z_internal_shm_null, \
         z_owned_shm_provider_t* : z_internal_shm_provider_null, \
+        z_owned_slice_array_t* : z_internal_slice_array_null, \
+        z_owned_slice_map_t* : z_internal_slice_map_null, \
         z_owned_slice_t* : z_internal_slice_null, \
         z_owned_string_array_t* : z_internal_string_array_null, \
         z_owned_string_t* : z_internal_string_null, \
@@ -377,8 +456,10 @@ static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x
         ze_owned_advanced_publisher_t* : ze_internal_advanced_publisher_null, \
         ze_owned_advanced_subscriber_t* : ze_internal_advanced_subscriber_null, \
         ze_owned_closure_miss_t* : ze_internal_closure_miss_null, \
+        ze_owned_fifo_handler_miss_t* : ze_internal_fifo_handler_miss_null, \
         ze_owned_publication_cache_t* : ze_internal_publication_cache_null, \
         ze_owned_querying_subscriber_t* : ze_internal_querying_subscriber_null, \
+        ze_owned_ring_handler_miss_t* : ze_internal_ring_handler_miss_null, \
         ze_owned_sample_miss_listener_t* : ze_internal_sample_miss_listener_null, \
         ze_owned_serializer_t* : ze_internal_serializer_null \
     )(this_)
@@ -388,10 +469,12 @@ static inline void z_bytes_writer_take(z_owned_bytes_writer_t* this_, z_moved_by
 static inline void z_cancellation_token_take(z_owned_cancellation_token_t* this_, z_moved_cancellation_token_t* x) { *this_ = x->_this; z_internal_cancellation_token_null(&x->_this); }
 static inline void z_chunk_alloc_result_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_result_t* x) { *this_ = x->_this; z_internal_chunk_alloc_result_null(&x->_this); }
 static inline void z_closure_hello_take(z_owned_closure_hello_t* this_, z_moved_closure_hello_t* x) { *this_ = x->_this; z_internal_closure_hello_null(&x->_this); }
+static inline void z_closure_leadership_status_take(z_owned_closure_leadership_status_t* closure_, z_moved_closure_leadership_status_t* x) { *closure_ = x->_this; z_internal_closure_leadership_status_null(&x->_this); }
 static inline void z_closure_link_take(z_owned_closure_link_t* closure_, z_moved_closure_link_t* x) { *closure_ = x->_this; z_internal_closure_link_null(&x->_this); }
 static inline void z_closure_link_event_take(z_owned_closure_link_event_t* closure_, z_moved_closure_link_event_t* x) { *closure_ = x->_this; z_internal_closure_link_event_null(&x->_this); }
 static inline void z_closure_matching_status_take(z_owned_closure_matching_status_t* closure_, z_moved_closure_matching_status_t* x) { *closure_ = x->_this; z_internal_closure_matching_status_null(&x->_this); }
 static inline void z_closure_query_take(z_owned_closure_query_t* closure_, z_moved_closure_query_t* x) { *closure_ = x->_this; z_internal_closure_query_null(&x->_this); }
+static inline void z_closure_reply_consolidation_take(z_owned_closure_reply_consolidation_t* closure_, z_moved_closure_reply_consolidation_t* x) { *closure_ = x->_this; z_internal_closure_reply_consolidation_null(&x->_this); }
 static inline void z_closure_reply_take(z_owned_closure_reply_t* closure_, z_moved_closure_reply_t* x) { *closure_ = x->_this; z_internal_closure_reply_null(&x->_this); }
 static inline void z_closure_sample_take(z_owned_closure_sample_t* closure_, z_moved_closure_sample_t* x) { *closure_ = x->_this; z_internal_closure_sample_null(&x->_this); }
 static inline void z_closure_transport_take(z_owned_closure_transport_t* closure_, z_moved_closure_transport_t* x) { *closure_ = x->_this; z_internal_closure_transport_null(&x->_this); }
@@ -400,6 +483,7 @@ static inline void z_closure_zid_take(z_owned_closure_zid_t* closure_, z_moved_c
 static inline void z_condvar_take(z_owned_condvar_t* this_, z_moved_condvar_t* x) { *this_ = x->_this; z_internal_condvar_null(&x->_this); }
 static inline void z_config_take(z_owned_config_t* this_, z_moved_config_t* x) { *this_ = x->_this; z_internal_config_null(&x->_this); }
 static inline void z_encoding_take(z_owned_encoding_t* this_, z_moved_encoding_t* x) { *this_ = x->_this; z_internal_encoding_null(&x->_this); }
+static inline void z_fifo_handler_matching_status_take(z_owned_fifo_handler_matching_status_t* this_, z_moved_fifo_handler_matching_status_t* x) { *this_ = x->_this; z_internal_fifo_handler_matching_status_null(&x->_this); }
 static inline void z_fifo_handler_query_take(z_owned_fifo_handler_query_t* this_, z_moved_fifo_handler_query_t* x) { *this_ = x->_this; z_internal_fifo_handler_query_null(&x->_this); }
 static inline void z_fifo_handler_reply_take(z_owned_fifo_handler_reply_t* this_, z_moved_fifo_handler_reply_t* x) { *this_ = x->_this; z_internal_fifo_handler_reply_null(&x->_this); }
 static inline void z_fifo_handler_sample_take(z_owned_fifo_handler_sample_t* this_, z_moved_fifo_handler_sample_t* x) { *this_ = x->_this; z_internal_fifo_handler_sample_null(&x->_this); }
@@ -408,18 +492,26 @@ static inline void z_keyexpr_take(z_owned_keyexpr_t* this_, z_moved_keyexpr_t* x
 static inline void z_link_take(z_owned_link_t* this_, z_moved_link_t* x) { *this_ = x->_this; z_internal_link_null(&x->_this); }
 static inline void z_link_event_take(z_owned_link_event_t* this_, z_moved_link_event_t* x) { *this_ = x->_this; z_internal_link_event_null(&x->_this); }
 static inline void z_link_events_listener_take(z_owned_link_events_listener_t* this_, z_moved_link_events_listener_t* x) { *this_ = x->_this; z_internal_link_events_listener_null(&x->_this); }
+static inline void z_liveliness_election_take(z_owned_liveliness_election_t* this_, z_moved_liveliness_election_t* x) { *this_ = x->_this; z_internal_liveliness_election_null(&x->_this); }
+static inline void z_liveliness_lock_take(z_owned_liveliness_lock_t* this_, z_moved_liveliness_lock_t* x) { *this_ = x->_this; z_internal_liveliness_lock_null(&x->_this); }
 static inline void z_liveliness_token_take(z_owned_liveliness_token_t* this_, z_moved_liveliness_token_t* x) { *this_ = x->_this; z_internal_liveliness_token_null(&x->_this); }
+static inline void z_liveliness_tracker_take(z_owned_liveliness_tracker_t* this_, z_moved_liveliness_tracker_t* x) { *this_ = x->_this; z_internal_liveliness_tracker_null(&x->_this); }
 static inline void z_matching_listener_take(z_owned_matching_listener_t* this_, z_moved_matching_listener_t* x) { *this_ = x->_this; z_internal_matching_listener_null(&x->_this); }
 static inline void z_memory_layout_take(z_owned_memory_layout_t* this_, z_moved_memory_layout_t* x) { *this_ = x->_this; z_internal_memory_layout_null(&x->_this); }
 static inline void z_mutex_take(z_owned_mutex_t* this_, z_moved_mutex_t* x) { *this_ = x->_this; z_internal_mutex_null(&x->_this); }
+static inline void z_parameters_take(z_owned_parameters_t* this_, z_moved_parameters_t* x) { *this_ = x->_this; z_internal_parameters_null(&x->_this); }
 static inline void z_precomputed_layout_take(z_owned_precomputed_layout_t* this_, z_moved_precomputed_layout_t* x) { *this_ = x->_this; z_internal_precomputed_layout_null(&x->_this); }
+static inline void z_prefix_publisher_take(z_owned_prefix_publisher_t* this_, z_moved_prefix_publisher_t* x) { *this_ = x->_this; z_internal_prefix_publisher_null(&x->_this); }
 static inline void z_ptr_in_segment_take(z_owned_ptr_in_segment_t* this_, z_moved_ptr_in_segment_t* x) { *this_ = x->_this; z_internal_ptr_in_segment_null(&x->_this); }
 static inline void z_publisher_take(z_owned_publisher_t* this_, z_moved_publisher_t* x) { *this_ = x->_this; z_internal_publisher_null(&x->_this); }
 static inline void z_querier_take(z_owned_querier_t* this_, z_moved_querier_t* x) { *this_ = x->_this; z_internal_querier_null(&x->_this); }
 static inline void z_query_take(z_owned_query_t* this_, z_moved_query_t* x) { *this_ = x->_this; z_internal_query_null(&x->_this); }
 static inline void z_queryable_take(z_owned_queryable_t* this_, z_moved_queryable_t* x) { *this_ = x->_this; z_internal_queryable_null(&x->_this); }
+static inline void z_reply_array_take(z_owned_reply_array_t* this_, z_moved_reply_array_t* x) { *this_ = x->_this; z_internal_reply_array_null(&x->_this); }
 static inline void z_reply_take(z_owned_reply_t* this_, z_moved_reply_t* x) { *this_ = x->_this; z_internal_reply_null(&x->_this); }
 static inline void z_reply_err_take(z_owned_reply_err_t* this_, z_moved_reply_err_t* x) { *this_ = x->_this; z_internal_reply_err_null(&x->_this); }
+static inline void z_reply_stream_take(z_owned_reply_stream_t* this_, z_moved_reply_stream_t* x) { *this_ = x->_this; z_internal_reply_stream_null(&x->_this); }
+static inline void z_ring_handler_matching_status_take(z_owned_ring_handler_matching_status_t* this_, z_moved_ring_handler_matching_status_t* x) { *this_ = x->_this; z_internal_ring_handler_matching_status_null(&x->_this); }
 static inline void z_ring_handler_query_take(z_owned_ring_handler_query_t* this_, z_moved_ring_handler_query_t* x) { *this_ = x->_this; z_internal_ring_handler_query_null(&x->_this); }
 static inline void z_ring_handler_reply_take(z_owned_ring_handler_reply_t* this_, z_moved_ring_handler_reply_t* x) { *this_ = x->_this; z_internal_ring_handler_reply_null(&x->_this); }
 static inline void z_ring_handler_sample_take(z_owned_ring_handler_sample_t* this_, z_moved_ring_handler_sample_t* x) { *this_ = x->_this; z_internal_ring_handler_sample_null(&x->_this); }
@@ -431,7 +523,9 @@ static inline void z_shm_client_storage_take(z_owned_shm_client_storage_t* this_
 static inline void z_shm_take(z_owned_shm_t* this_, z_moved_shm_t* x) { *this_ = x->_this; z_internal_shm_null(&x->_this); }
 static inline void z_shm_mut_take(z_owned_shm_mut_t* this_, z_moved_shm_mut_t* x) { *this_ = x->_this; z_internal_shm_mut_null(&x->_this); }
 static inline void z_shm_provider_take(z_owned_shm_provider_t* this_, z_moved_shm_provider_t* x) { *this_ = x->_this; z_internal_shm_provider_null(&x->_this); }
+static inline void z_slice_array_take(z_owned_slice_array_t* this_, z_moved_slice_array_t* x) { *this_ = x->_this; z_internal_slice_array_null(&x->_this); }
 static inline void z_slice_take(z_owned_slice_t* this_, z_moved_slice_t* x) { *this_ = x->_this; z_internal_slice_null(&x->_this); }
+static inline void z_slice_map_take(z_owned_slice_map_t* this_, z_moved_slice_map_t* x) { *this_ = x->_this; z_internal_slice_map_null(&x->_this); }
 static inline void z_string_array_take(z_owned_string_array_t* this_, z_moved_string_array_t* x) { *this_ = x->_this; z_internal_string_array_null(&x->_this); }
 static inline void z_string_take(z_owned_string_t* this_, z_moved_string_t* x) { *this_ = x->_this; z_internal_string_null(&x->_this); }
 static inline void z_subscriber_take(z_owned_subscriber_t* this_, z_moved_subscriber_t* x) { *this_ = x->_this; z_internal_subscriber_null(&x->_this); }
@@ -445,8 +539,10 @@ static inline void zc_shm_client_list_take(zc_owned_shm_client_list_t* this_, zc
 static inline void ze_advanced_publisher_take(ze_owned_advanced_publisher_t* this_, ze_moved_advanced_publisher_t* x) { *this_ = x->_this; ze_internal_advanced_publisher_null(&x->_this); }
 static inline void ze_advanced_subscriber_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subscriber_t* x) { *this_ = x->_this; ze_internal_advanced_subscriber_null(&x->_this); }
 static inline void ze_closure_miss_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) { *closure_ = x->_this; ze_internal_closure_miss_null(&x->_this); }
+static inline void ze_fifo_handler_miss_take(ze_owned_fifo_handler_miss_t* this_, ze_moved_fifo_handler_miss_t* x) { *this_ = x->_this; ze_internal_fifo_handler_miss_null(&x->_this); }
 static inline void ze_publication_cache_take(ze_owned_publication_cache_t* this_, ze_moved_publication_cache_t* x) { *this_ = x->_this; ze_internal_publication_cache_null(&x->_this); }
 static inline void ze_querying_subscriber_take(ze_owned_querying_subscriber_t* this_, ze_moved_querying_subscriber_t* x) { *this_ = x->_this; ze_internal_querying_subscriber_null(&x->_this); }
+static inline void ze_ring_handler_miss_take(ze_owned_ring_handler_miss_t* this_, ze_moved_ring_handler_miss_t* x) { *this_ = x->_this; ze_internal_ring_handler_miss_null(&x->_this); }
 static inline void ze_sample_miss_listener_take(ze_owned_sample_miss_listener_t* this_, ze_moved_sample_miss_listener_t* x) { *this_ = x->_this; ze_internal_sample_miss_listener_null(&x->_this); }
 static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_serializer_t* x) { *this_ = x->_this; ze_internal_serializer_null(&x->_this); }
 
@@ -458,10 +554,12 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_cancellation_token_t* : z_cancellation_token_take, \
         z_owned_chunk_alloc_result_t* : z_chunk_alloc_result_take, \
         z_owned_closure_hello_t* : z_closure_hello_take, \
+        z_owned_closure_leadership_status_t* : z_closure_leadership_status_take, \
         z_owned_closure_link_t* : z_closure_link_take, \
         z_owned_closure_link_event_t* : z_closure_link_event_take, \
         z_owned_closure_matching_status_t* : z_closure_matching_status_take, \
         z_owned_closure_query_t* : z_closure_query_take, \
+        z_owned_closure_reply_consolidation_t* : z_closure_reply_consolidation_take, \
         z_owned_closure_reply_t* : z_closure_reply_take, \
         z_owned_closure_sample_t* : z_closure_sample_take, \
         z_owned_closure_transport_t* : z_closure_transport_take, \
@@ -470,6 +568,7 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_condvar_t* : z_condvar_take, \
         z_owned_config_t* : z_config_take, \
         z_owned_encoding_t* : z_encoding_take, \
+        z_owned_fifo_handler_matching_status_t* : z_fifo_handler_matching_status_take, \
         z_owned_fifo_handler_query_t* : z_fifo_handler_query_take, \
         z_owned_fifo_handler_reply_t* : z_fifo_handler_reply_take, \
         z_owned_fifo_handler_sample_t* : z_fifo_handler_sample_take, \
@@ -478,18 +577,26 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_link_t* : z_link_take, \
         z_owned_link_event_t* : z_link_event_take, \
         z_owned_link_events_listener_t* : z_link_events_listener_take, \
+        z_owned_liveliness_election_t* : z_liveliness_election_take, \
+        z_owned_liveliness_lock_t* : z_liveliness_lock_take, \
         z_owned_liveliness_token_t* : z_liveliness_token_take, \
+        z_owned_liveliness_tracker_t* : z_liveliness_tracker_take, \
         z_owned_matching_listener_t* : z_matching_listener_take, \
         z_owned_memory_layout_t* : z_memory_layout_take, \
         z_owned_mutex_t* : z_mutex_take, \
+        z_owned_parameters_t* : z_parameters_take, \
         z_owned_precomputed_layout_t* : z_precomputed_layout_take, \
+        z_owned_prefix_publisher_t* : z_prefix_publisher_take, \
         z_owned_ptr_in_segment_t* : z_ptr_in_segment_take, \
         z_owned_publisher_t* : z_publisher_take, \
         z_owned_querier_t* : z_querier_take, \
         z_owned_query_t* : z_query_take, \
         z_owned_queryable_t* : z_queryable_take, \
+        z_owned_reply_array_t* : z_reply_array_take, \
         z_owned_reply_t* : z_reply_take, \
         z_owned_reply_err_t* : z_reply_err_take, \
+        z_owned_reply_stream_t* : z_reply_stream_take, \
+        z_owned_ring_handler_matching_status_t* : z_ring_handler_matching_status_take, \
         z_owned_ring_handler_query_t* : z_ring_handler_query_take, \
         z_owned_ring_handler_reply_t* : z_ring_handler_reply_take, \
         z_owned_ring_handler_sample_t* : z_ring_handler_sample_take, \
@@ -501,7 +608,9 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_shm_t* : z_shm_take, \
         z_owned_shm_mut_t* : z_shm_mut_take, \
         z_owned_shm_provider_t* : z_shm_provider_take, \
+        z_owned_slice_array_t* : z_slice_array_take, \
         z_owned_slice_t* : z_slice_take, \
+        z_owned_slice_map_t* : z_slice_map_take, \
         z_owned_string_array_t* : z_string_array_take, \
         z_owned_string_t* : z_string_take, \
         z_owned_subscriber_t* : z_subscriber_take, \
@@ -515,8 +624,10 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         ze_owned_advanced_publisher_t* : ze_advanced_publisher_take, \
         ze_owned_advanced_subscriber_t* : ze_advanced_subscriber_take, \
         ze_owned_closure_miss_t* : ze_closure_miss_take, \
+        ze_owned_fifo_handler_miss_t* : ze_fifo_handler_miss_take, \
         ze_owned_publication_cache_t* : ze_publication_cache_take, \
         ze_owned_querying_subscriber_t* : ze_querying_subscriber_take, \
+        ze_owned_ring_handler_miss_t* : ze_ring_handler_miss_take, \
         ze_owned_sample_miss_listener_t* : ze_sample_miss_listener_take, \
         ze_owned_serializer_t* : ze_serializer_take \
     )(this_, x)
@@ -540,11 +651,13 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_cancellation_token_t : z_internal_cancellation_token_check, \
         z_owned_chunk_alloc_result_t : z_internal_chunk_alloc_result_check, \
         z_owned_closure_hello_t : z_internal_closure_hello_check, \
+        z_owned_closure_leadership_status_t : z_internal_closure_leadership_status_check, \
         z_owned_closure_link_t : z_internal_closure_link_check, \
         z_owned_closure_link_event_t : z_internal_closure_link_event_check, \
         z_owned_closure_matching_status_t : z_internal_closure_matching_status_check, \
         z_owned_closure_query_t : z_internal_closure_query_check, \
         z_owned_closure_reply_t : z_internal_closure_reply_check, \
+        z_owned_closure_reply_consolidation_t : z_internal_closure_reply_consolidation_check, \
         z_owned_closure_sample_t : z_internal_closure_sample_check, \
         z_owned_closure_transport_t : z_internal_closure_transport_check, \
         z_owned_closure_transport_event_t : z_internal_closure_transport_event_check, \
@@ -552,6 +665,7 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_condvar_t : z_internal_condvar_check, \
         z_owned_config_t : z_internal_config_check, \
         z_owned_encoding_t : z_internal_encoding_check, \
+        z_owned_fifo_handler_matching_status_t : z_internal_fifo_handler_matching_status_check, \
         z_owned_fifo_handler_query_t : z_internal_fifo_handler_query_check, \
         z_owned_fifo_handler_reply_t : z_internal_fifo_handler_reply_check, \
         z_owned_fifo_handler_sample_t : z_internal_fifo_handler_sample_check, \
@@ -560,18 +674,26 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_link_t : z_internal_link_check, \
         z_owned_link_event_t : z_internal_link_event_check, \
         z_owned_link_events_listener_t : z_internal_link_events_listener_check, \
+        z_owned_liveliness_election_t : z_internal_liveliness_election_check, \
+        z_owned_liveliness_lock_t : z_internal_liveliness_lock_check, \
         z_owned_liveliness_token_t : z_internal_liveliness_token_check, \
+        z_owned_liveliness_tracker_t : z_internal_liveliness_tracker_check, \
         z_owned_matching_listener_t : z_internal_matching_listener_check, \
         z_owned_memory_layout_t : z_internal_memory_layout_check, \
         z_owned_mutex_t : z_internal_mutex_check, \
+        z_owned_parameters_t : z_internal_parameters_check, \
         z_owned_precomputed_layout_t : z_internal_precomputed_layout_check, \
+        z_owned_prefix_publisher_t : z_internal_prefix_publisher_check, \
         z_owned_ptr_in_segment_t : z_internal_ptr_in_segment_check, \
         z_owned_publisher_t : z_internal_publisher_check, \
         z_owned_querier_t : z_internal_querier_check, \
         z_owned_query_t : z_internal_query_check, \
         z_owned_queryable_t : z_internal_queryable_check, \
+        z_owned_reply_array_t : z_internal_reply_array_check, \
         z_owned_reply_t : z_internal_reply_check, \
         z_owned_reply_err_t : z_internal_reply_err_check, \
+        z_owned_reply_stream_t : z_internal_reply_stream_check, \
+        z_owned_ring_handler_matching_status_t : z_internal_ring_handler_matching_status_check, \
         z_owned_ring_handler_query_t : z_internal_ring_handler_query_check, \
         z_owned_ring_handler_reply_t : z_internal_ring_handler_reply_check, \
         z_owned_ring_handler_sample_t : z_internal_ring_handler_sample_check, \
@@ -583,7 +705,9 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         z_owned_shm_client_storage_t : z_internal_shm_client_storage_check, \
         z_owned_shm_mut_t : z_internal_shm_mut_check, \
         z_owned_shm_provider_t : z_internal_shm_provider_check, \
+        z_owned_slice_array_t : z_internal_slice_array_check, \
         z_owned_slice_t : z_internal_slice_check, \
+        z_owned_slice_map_t : z_internal_slice_map_check, \
         z_owned_string_array_t : z_internal_string_array_check, \
         z_owned_string_t : z_internal_string_check, \
         z_owned_subscriber_t : z_internal_subscriber_check, \
@@ -597,8 +721,10 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
         ze_owned_advanced_publisher_t : ze_internal_advanced_publisher_check, \
         ze_owned_advanced_subscriber_t : ze_internal_advanced_subscriber_check, \
         ze_owned_closure_miss_t : ze_internal_closure_miss_check, \
+        ze_owned_fifo_handler_miss_t : ze_internal_fifo_handler_miss_check, \
         ze_owned_publication_cache_t : ze_internal_publication_cache_check, \
         ze_owned_querying_subscriber_t : ze_internal_querying_subscriber_check, \
+        ze_owned_ring_handler_miss_t : ze_internal_ring_handler_miss_check, \
         ze_owned_sample_miss_listener_t : ze_internal_sample_miss_listener_check, \
         ze_owned_serializer_t : ze_internal_serializer_check \
     )(&this_)
@@ -606,6 +732,7 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
 #define z_call(closure, hello) \
     _Generic((closure), \
         const z_loaned_closure_hello_t* : z_closure_hello_call, \
+        const z_loaned_closure_leadership_status_t* : z_closure_leadership_status_call, \
         const z_loaned_closure_link_t* : z_closure_link_call, \
         const z_loaned_closure_link_event_t* : z_closure_link_event_call, \
         const z_loaned_closure_matching_status_t* : z_closure_matching_status_call, \
@@ -620,11 +747,13 @@ static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_ser
 
 typedef void(*z_closure_drop_callback_t)(void *context);
 typedef void(*z_closure_hello_callback_t)(z_loaned_hello_t *hello, void *context);
+typedef void(*z_closure_leadership_status_callback_t)(const z_leadership_status_t *leadership_status, void *context);
 typedef void(*z_closure_link_callback_t)(z_loaned_link_t *link, void *context);
 typedef void(*z_closure_link_event_callback_t)(z_loaned_link_event_t *event, void *context);
 typedef void(*z_closure_matching_status_callback_t)(const z_matching_status_t *matching_status, void *context);
 typedef void(*z_closure_query_callback_t)(z_loaned_query_t *query, void *context);
 typedef void(*z_closure_reply_callback_t)(z_loaned_reply_t *reply, void *context);
+typedef void(*z_closure_reply_consolidation_callback_t)(z_loaned_reply_t *kept, z_loaned_reply_t *candidate, bool *replace, void *context);
 typedef void(*z_closure_sample_callback_t)(z_loaned_sample_t *sample, void *context);
 typedef void(*z_closure_transport_callback_t)(z_loaned_transport_t *transport, void *context);
 typedef void(*z_closure_transport_event_callback_t)(z_loaned_transport_event_t *event, void *context);
@@ -635,11 +764,13 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
 #define z_closure(this_, call, drop, context) \
     _Generic((this_), \
         z_owned_closure_hello_t* : z_closure_hello, \
+        z_owned_closure_leadership_status_t* : z_closure_leadership_status, \
         z_owned_closure_link_t* : z_closure_link, \
         z_owned_closure_link_event_t* : z_closure_link_event, \
         z_owned_closure_matching_status_t* : z_closure_matching_status, \
         z_owned_closure_query_t* : z_closure_query, \
         z_owned_closure_reply_t* : z_closure_reply, \
+        z_owned_closure_reply_consolidation_t* : z_closure_reply_consolidation, \
         z_owned_closure_sample_t* : z_closure_sample, \
         z_owned_closure_transport_t* : z_closure_transport, \
         z_owned_closure_transport_event_t* : z_closure_transport_event, \
@@ -648,25 +779,33 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
         ze_owned_closure_miss_t* : ze_closure_miss \
     )(this_, call, drop, context)
 
-#define z_try_recv(this_, query) \
+#define z_try_recv(this_, matching_status) \
     _Generic((this_), \
+        const z_loaned_fifo_handler_matching_status_t* : z_fifo_handler_matching_status_try_recv, \
         const z_loaned_fifo_handler_query_t* : z_fifo_handler_query_try_recv, \
         const z_loaned_fifo_handler_reply_t* : z_fifo_handler_reply_try_recv, \
         const z_loaned_fifo_handler_sample_t* : z_fifo_handler_sample_try_recv, \
+        const z_loaned_ring_handler_matching_status_t* : z_ring_handler_matching_status_try_recv, \
         const z_loaned_ring_handler_query_t* : z_ring_handler_query_try_recv, \
         const z_loaned_ring_handler_reply_t* : z_ring_handler_reply_try_recv, \
-        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_try_recv \
-    )(this_, query)
+        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_try_recv, \
+        const ze_loaned_fifo_handler_miss_t* : ze_fifo_handler_miss_try_recv, \
+        const ze_loaned_ring_handler_miss_t* : ze_ring_handler_miss_try_recv \
+    )(this_, matching_status)
 
-#define z_recv(this_, query) \
+#define z_recv(this_, matching_status) \
     _Generic((this_), \
+        const z_loaned_fifo_handler_matching_status_t* : z_fifo_handler_matching_status_recv, \
         const z_loaned_fifo_handler_query_t* : z_fifo_handler_query_recv, \
         const z_loaned_fifo_handler_reply_t* : z_fifo_handler_reply_recv, \
         const z_loaned_fifo_handler_sample_t* : z_fifo_handler_sample_recv, \
+        const z_loaned_ring_handler_matching_status_t* : z_ring_handler_matching_status_recv, \
         const z_loaned_ring_handler_query_t* : z_ring_handler_query_recv, \
         const z_loaned_ring_handler_reply_t* : z_ring_handler_reply_recv, \
-        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_recv \
-    )(this_, query)
+        const z_loaned_ring_handler_sample_t* : z_ring_handler_sample_recv, \
+        const ze_loaned_fifo_handler_miss_t* : ze_fifo_handler_miss_recv, \
+        const ze_loaned_ring_handler_miss_t* : ze_ring_handler_miss_recv \
+    )(this_, matching_status)
 
 #define z_clone(dst, this_) \
     _Generic((dst), \
@@ -677,15 +816,19 @@ typedef void(*ze_closure_miss_callback_t)(const ze_miss_t *matching_status, void
         z_owned_hello_t* : z_hello_clone, \
         z_owned_keyexpr_t* : z_keyexpr_clone, \
         z_owned_link_t* : z_link_clone, \
+        z_owned_parameters_t* : z_parameters_clone, \
         z_owned_ptr_in_segment_t* : z_ptr_in_segment_clone, \
         z_owned_query_t* : z_query_clone, \
+        z_owned_reply_array_t* : z_reply_array_clone, \
         z_owned_reply_t* : z_reply_clone, \
         z_owned_reply_err_t* : z_reply_err_clone, \
         z_owned_sample_t* : z_sample_clone, \
         z_owned_shared_shm_provider_t* : z_shared_shm_provider_clone, \
         z_owned_shm_client_storage_t* : z_shm_client_storage_clone, \
         z_owned_shm_t* : z_shm_clone, \
+        z_owned_slice_array_t* : z_slice_array_clone, \
         z_owned_slice_t* : z_slice_clone, \
+        z_owned_slice_map_t* : z_slice_map_clone, \
         z_owned_string_array_t* : z_string_array_clone, \
         z_owned_string_t* : z_string_clone, \
         z_owned_transport_t* : z_transport_clone \
@@ -698,10 +841,12 @@ static inline z_moved_bytes_writer_t* z_bytes_writer_move(z_owned_bytes_writer_t
 static inline z_moved_cancellation_token_t* z_cancellation_token_move(z_owned_cancellation_token_t* x) { return reinterpret_cast<z_moved_cancellation_token_t*>(x); }
 static inline z_moved_chunk_alloc_result_t* z_chunk_alloc_result_move(z_owned_chunk_alloc_result_t* x) { return reinterpret_cast<z_moved_chunk_alloc_result_t*>(x); }
 static inline z_moved_closure_hello_t* z_closure_hello_move(z_owned_closure_hello_t* x) { return reinterpret_cast<z_moved_closure_hello_t*>(x); }
+static inline z_moved_closure_leadership_status_t* z_closure_leadership_status_move(z_owned_closure_leadership_status_t* x) { return reinterpret_cast<z_moved_closure_leadership_status_t*>(x); }
 static inline z_moved_closure_link_t* z_closure_link_move(z_owned_closure_link_t* x) { return reinterpret_cast<z_moved_closure_link_t*>(x); }
 static inline z_moved_closure_link_event_t* z_closure_link_event_move(z_owned_closure_link_event_t* x) { return reinterpret_cast<z_moved_closure_link_event_t*>(x); }
 static inline z_moved_closure_matching_status_t* z_closure_matching_status_move(z_owned_closure_matching_status_t* x) { return reinterpret_cast<z_moved_closure_matching_status_t*>(x); }
 static inline z_moved_closure_query_t* z_closure_query_move(z_owned_closure_query_t* x) { return reinterpret_cast<z_moved_closure_query_t*>(x); }
+static inline z_moved_closure_reply_consolidation_t* z_closure_reply_consolidation_move(z_owned_closure_reply_consolidation_t* x) { return reinterpret_cast<z_moved_closure_reply_consolidation_t*>(x); }
 static inline z_moved_closure_reply_t* z_closure_reply_move(z_owned_closure_reply_t* x) { return reinterpret_cast<z_moved_closure_reply_t*>(x); }
 static inline z_moved_closure_sample_t* z_closure_sample_move(z_owned_closure_sample_t* x) { return reinterpret_cast<z_moved_closure_sample_t*>(x); }
 static inline z_moved_closure_transport_t* z_closure_transport_move(z_owned_closure_transport_t* x) { return reinterpret_cast<z_moved_closure_transport_t*>(x); }
@@ -710,6 +855,7 @@ static inline z_moved_closure_zid_t* z_closure_zid_move(z_owned_closure_zid_t* x
 static inline z_moved_condvar_t* z_condvar_move(z_owned_condvar_t* x) { return reinterpret_cast<z_moved_condvar_t*>(x); }
 static inline z_moved_config_t* z_config_move(z_owned_config_t* x) { return reinterpret_cast<z_moved_config_t*>(x); }
 static inline z_moved_encoding_t* z_encoding_move(z_owned_encoding_t* x) { return reinterpret_cast<z_moved_encoding_t*>(x); }
+static inline z_moved_fifo_handler_matching_status_t* z_fifo_handler_matching_status_move(z_owned_fifo_handler_matching_status_t* x) { return reinterpret_cast<z_moved_fifo_handler_matching_status_t*>(x); }
 static inline z_moved_fifo_handler_query_t* z_fifo_handler_query_move(z_owned_fifo_handler_query_t* x) { return reinterpret_cast<z_moved_fifo_handler_query_t*>(x); }
 static inline z_moved_fifo_handler_reply_t* z_fifo_handler_reply_move(z_owned_fifo_handler_reply_t* x) { return reinterpret_cast<z_moved_fifo_handler_reply_t*>(x); }
 static inline z_moved_fifo_handler_sample_t* z_fifo_handler_sample_move(z_owned_fifo_handler_sample_t* x) { return reinterpret_cast<z_moved_fifo_handler_sample_t*>(x); }
@@ -718,18 +864,26 @@ static inline z_moved_keyexpr_t* z_keyexpr_move(z_owned_keyexpr_t* x) { return r
 static inline z_moved_link_t* z_link_move(z_owned_link_t* x) { return reinterpret_cast<z_moved_link_t*>(x); }
 static inline z_moved_link_event_t* z_link_event_move(z_owned_link_event_t* x) { return reinterpret_cast<z_moved_link_event_t*>(x); }
 static inline z_moved_link_events_listener_t* z_link_events_listener_move(z_owned_link_events_listener_t* x) { return reinterpret_cast<z_moved_link_events_listener_t*>(x); }
+static inline z_moved_liveliness_election_t* z_liveliness_election_move(z_owned_liveliness_election_t* x) { return reinterpret_cast<z_moved_liveliness_election_t*>(x); }
+static inline z_moved_liveliness_lock_t* z_liveliness_lock_move(z_owned_liveliness_lock_t* x) { return reinterpret_cast<z_moved_liveliness_lock_t*>(x); }
 static inline z_moved_liveliness_token_t* z_liveliness_token_move(z_owned_liveliness_token_t* x) { return reinterpret_cast<z_moved_liveliness_token_t*>(x); }
+static inline z_moved_liveliness_tracker_t* z_liveliness_tracker_move(z_owned_liveliness_tracker_t* x) { return reinterpret_cast<z_moved_liveliness_tracker_t*>(x); }
 static inline z_moved_matching_listener_t* z_matching_listener_move(z_owned_matching_listener_t* x) { return reinterpret_cast<z_moved_matching_listener_t*>(x); }
 static inline z_moved_memory_layout_t* z_memory_layout_move(z_owned_memory_layout_t* x) { return reinterpret_cast<z_moved_memory_layout_t*>(x); }
 static inline z_moved_mutex_t* z_mutex_move(z_owned_mutex_t* x) { return reinterpret_cast<z_moved_mutex_t*>(x); }
+static inline z_moved_parameters_t* z_parameters_move(z_owned_parameters_t* x) { return reinterpret_cast<z_moved_parameters_t*>(x); }
 static inline z_moved_precomputed_layout_t* z_precomputed_layout_move(z_owned_precomputed_layout_t* x) { return reinterpret_cast<z_moved_precomputed_layout_t*>(x); }
+static inline z_moved_prefix_publisher_t* z_prefix_publisher_move(z_owned_prefix_publisher_t* x) { return reinterpret_cast<z_moved_prefix_publisher_t*>(x); }
 static inline z_moved_ptr_in_segment_t* z_ptr_in_segment_move(z_owned_ptr_in_segment_t* x) { return reinterpret_cast<z_moved_ptr_in_segment_t*>(x); }
 static inline z_moved_publisher_t* z_publisher_move(z_owned_publisher_t* x) { return reinterpret_cast<z_moved_publisher_t*>(x); }
 static inline z_moved_querier_t* z_querier_move(z_owned_querier_t* x) { return reinterpret_cast<z_moved_querier_t*>(x); }
 static inline z_moved_query_t* z_query_move(z_owned_query_t* x) { return reinterpret_cast<z_moved_query_t*>(x); }
 static inline z_moved_queryable_t* z_queryable_move(z_owned_queryable_t* x) { return reinterpret_cast<z_moved_queryable_t*>(x); }
+static inline z_moved_reply_array_t* z_reply_array_move(z_owned_reply_array_t* x) { return reinterpret_cast<z_moved_reply_array_t*>(x); }
 static inline z_moved_reply_t* z_reply_move(z_owned_reply_t* x) { return reinterpret_cast<z_moved_reply_t*>(x); }
 static inline z_moved_reply_err_t* z_reply_err_move(z_owned_reply_err_t* x) { return reinterpret_cast<z_moved_reply_err_t*>(x); }
+static inline z_moved_reply_stream_t* z_reply_stream_move(z_owned_reply_stream_t* x) { return reinterpret_cast<z_moved_reply_stream_t*>(x); }
+static inline z_moved_ring_handler_matching_status_t* z_ring_handler_matching_status_move(z_owned_ring_handler_matching_status_t* x) { return reinterpret_cast<z_moved_ring_handler_matching_status_t*>(x); }
 static inline z_moved_ring_handler_query_t* z_ring_handler_query_move(z_owned_ring_handler_query_t* x) { return reinterpret_cast<z_moved_ring_handler_query_t*>(x); }
 static inline z_moved_ring_handler_reply_t* z_ring_handler_reply_move(z_owned_ring_handler_reply_t* x) { return reinterpret_cast<z_moved_ring_handler_reply_t*>(x); }
 static inline z_moved_ring_handler_sample_t* z_ring_handler_sample_move(z_owned_ring_handler_sample_t* x) { return reinterpret_cast<z_moved_ring_handler_sample_t*>(x); }
@@ -741,7 +895,9 @@ static inline z_moved_shm_client_storage_t* z_shm_client_storage_move(z_owned_sh
 static inline z_moved_shm_t* z_shm_move(z_owned_shm_t* x) { return reinterpret_cast<z_moved_shm_t*>(x); }
 static inline z_moved_shm_mut_t* z_shm_mut_move(z_owned_shm_mut_t* x) { return reinterpret_cast<z_moved_shm_mut_t*>(x); }
 static inline z_moved_shm_provider_t* z_shm_provider_move(z_owned_shm_provider_t* x) { return reinterpret_cast<z_moved_shm_provider_t*>(x); }
+static inline z_moved_slice_array_t* z_slice_array_move(z_owned_slice_array_t* x) { return reinterpret_cast<z_moved_slice_array_t*>(x); }
 static inline z_moved_slice_t* z_slice_move(z_owned_slice_t* x) { return reinterpret_cast<z_moved_slice_t*>(x); }
+static inline z_moved_slice_map_t* z_slice_map_move(z_owned_slice_map_t* x) { return reinterpret_cast<z_moved_slice_map_t*>(x); }
 static inline z_moved_string_array_t* z_string_array_move(z_owned_string_array_t* x) { return reinterpret_cast<z_moved_string_array_t*>(x); }
 static inline z_moved_string_t* z_string_move(z_owned_string_t* x) { return reinterpret_cast<z_moved_string_t*>(x); }
 static inline z_moved_subscriber_t* z_subscriber_move(z_owned_subscriber_t* x) { return reinterpret_cast<z_moved_subscriber_t*>(x); }
@@ -755,8 +911,10 @@ static inline zc_moved_shm_client_list_t* zc_shm_client_list_move(zc_owned_shm_c
 static inline ze_moved_advanced_publisher_t* ze_advanced_publisher_move(ze_owned_advanced_publisher_t* x) { return reinterpret_cast<ze_moved_advanced_publisher_t*>(x); }
 static inline ze_moved_advanced_subscriber_t* ze_advanced_subscriber_move(ze_owned_advanced_subscriber_t* x) { return reinterpret_cast<ze_moved_advanced_subscriber_t*>(x); }
 static inline ze_moved_closure_miss_t* ze_closure_miss_move(ze_owned_closure_miss_t* x) { return reinterpret_cast<ze_moved_closure_miss_t*>(x); }
+static inline ze_moved_fifo_handler_miss_t* ze_fifo_handler_miss_move(ze_owned_fifo_handler_miss_t* x) { return reinterpret_cast<ze_moved_fifo_handler_miss_t*>(x); }
 static inline ze_moved_publication_cache_t* ze_publication_cache_move(ze_owned_publication_cache_t* x) { return reinterpret_cast<ze_moved_publication_cache_t*>(x); }
 static inline ze_moved_querying_subscriber_t* ze_querying_subscriber_move(ze_owned_querying_subscriber_t* x) { return reinterpret_cast<ze_moved_querying_subscriber_t*>(x); }
+static inline ze_moved_ring_handler_miss_t* ze_ring_handler_miss_move(ze_owned_ring_handler_miss_t* x) { return reinterpret_cast<ze_moved_ring_handler_miss_t*>(x); }
 static inline ze_moved_sample_miss_listener_t* ze_sample_miss_listener_move(ze_owned_sample_miss_listener_t* x) { return reinterpret_cast<ze_moved_sample_miss_listener_t*>(x); }
 static inline ze_moved_serializer_t* ze_serializer_move(ze_owned_serializer_t* x) { return reinterpret_cast<ze_moved_serializer_t*>(x); }
 
@@ -766,10 +924,12 @@ inline const z_loaned_bytes_t* z_loan(const z_owned_bytes_t& this_) { return z_b
 inline const z_loaned_bytes_writer_t* z_loan(const z_owned_bytes_writer_t& this_) { return z_bytes_writer_loan(&this_); };
 inline const z_loaned_cancellation_token_t* z_loan(const z_owned_cancellation_token_t& this_) { return z_cancellation_token_loan(&this_); };
 inline const z_loaned_closure_hello_t* z_loan(const z_owned_closure_hello_t& closure) { return z_closure_hello_loan(&closure); };
+inline const z_loaned_closure_leadership_status_t* z_loan(const z_owned_closure_leadership_status_t& closure) { return z_closure_leadership_status_loan(&closure); };
 inline const z_loaned_closure_link_event_t* z_loan(const z_owned_closure_link_event_t& closure) { return z_closure_link_event_loan(&closure); };
 inline const z_loaned_closure_link_t* z_loan(const z_owned_closure_link_t& closure) { return z_closure_link_loan(&closure); };
 inline const z_loaned_closure_matching_status_t* z_loan(const z_owned_closure_matching_status_t& closure) { return z_closure_matching_status_loan(&closure); };
 inline const z_loaned_closure_query_t* z_loan(const z_owned_closure_query_t& closure) { return z_closure_query_loan(&closure); };
+inline const z_loaned_closure_reply_consolidation_t* z_loan(const z_owned_closure_reply_consolidation_t& closure) { return z_closure_reply_consolidation_loan(&closure); };
 inline const z_loaned_closure_reply_t* z_loan(const z_owned_closure_reply_t& closure) { return z_closure_reply_loan(&closure); };
 inline const z_loaned_closure_sample_t* z_loan(const z_owned_closure_sample_t& closure) { return z_closure_sample_loan(&closure); };
 inline const z_loaned_closure_transport_event_t* z_loan(const z_owned_closure_transport_event_t& closure) { return z_closure_transport_event_loan(&closure); };
@@ -778,6 +938,7 @@ inline const z_loaned_closure_zid_t* z_loan(const z_owned_closure_zid_t& closure
 inline const z_loaned_condvar_t* z_loan(const z_owned_condvar_t& this_) { return z_condvar_loan(&this_); };
 inline const z_loaned_config_t* z_loan(const z_owned_config_t& this_) { return z_config_loan(&this_); };
 inline const z_loaned_encoding_t* z_loan(const z_owned_encoding_t& this_) { return z_encoding_loan(&this_); };
+inline const z_loaned_fifo_handler_matching_status_t* z_loan(const z_owned_fifo_handler_matching_status_t& this_) { return z_fifo_handler_matching_status_loan(&this_); };
 inline const z_loaned_fifo_handler_query_t* z_loan(const z_owned_fifo_handler_query_t& this_) { return z_fifo_handler_query_loan(&this_); };
 inline const z_loaned_fifo_handler_reply_t* z_loan(const z_owned_fifo_handler_reply_t& this_) { return z_fifo_handler_reply_loan(&this_); };
 inline const z_loaned_fifo_handler_sample_t* z_loan(const z_owned_fifo_handler_sample_t& this_) { return z_fifo_handler_sample_loan(&this_); };
@@ -786,16 +947,24 @@ inline const z_loaned_keyexpr_t* z_loan(const z_owned_keyexpr_t& this_) { return
 inline const z_loaned_link_event_t* z_loan(const z_owned_link_event_t& this_) { return z_link_event_loan(&this_); };
 inline const z_loaned_link_events_listener_t* z_loan(const z_owned_link_events_listener_t& this_) { return z_link_events_listener_loan(&this_); };
 inline const z_loaned_link_t* z_loan(const z_owned_link_t& this_) { return z_link_loan(&this_); };
+inline const z_loaned_liveliness_election_t* z_loan(const z_owned_liveliness_election_t& this_) { return z_liveliness_election_loan(&this_); };
+inline const z_loaned_liveliness_lock_t* z_loan(const z_owned_liveliness_lock_t& this_) { return z_liveliness_lock_loan(&this_); };
 inline const z_loaned_liveliness_token_t* z_loan(const z_owned_liveliness_token_t& this_) { return z_liveliness_token_loan(&this_); };
+inline const z_loaned_liveliness_tracker_t* z_loan(const z_owned_liveliness_tracker_t& this_) { return z_liveliness_tracker_loan(&this_); };
 inline const z_loaned_memory_layout_t* z_loan(const z_owned_memory_layout_t& this_) { return z_memory_layout_loan(&this_); };
+inline const z_loaned_parameters_t* z_loan(const z_owned_parameters_t& this_) { return z_parameters_loan(&this_); };
 inline const z_loaned_precomputed_layout_t* z_loan(const z_owned_precomputed_layout_t& this_) { return z_precomputed_layout_loan(&this_); };
+inline const z_loaned_prefix_publisher_t* z_loan(const z_owned_prefix_publisher_t& this_) { return z_prefix_publisher_loan(&this_); };
 inline const z_loaned_ptr_in_segment_t* z_loan(const z_owned_ptr_in_segment_t& this_) { return z_ptr_in_segment_loan(&this_); };
 inline const z_loaned_publisher_t* z_loan(const z_owned_publisher_t& this_) { return z_publisher_loan(&this_); };
 inline const z_loaned_querier_t* z_loan(const z_owned_querier_t& this_) { return z_querier_loan(&this_); };
 inline const z_loaned_query_t* z_loan(const z_owned_query_t& this_) { return z_query_loan(&this_); };
 inline const z_loaned_queryable_t* z_loan(const z_owned_queryable_t& this_) { return z_queryable_loan(&this_); };
+inline const z_loaned_reply_array_t* z_loan(const z_owned_reply_array_t& this_) { return z_reply_array_loan(&this_); };
 inline const z_loaned_reply_err_t* z_loan(const z_owned_reply_err_t& this_) { return z_reply_err_loan(&this_); };
 inline const z_loaned_reply_t* z_loan(const z_owned_reply_t& this_) { return z_reply_loan(&this_); };
+inline const z_loaned_reply_stream_t* z_loan(const z_owned_reply_stream_t& this_) { return z_reply_stream_loan(&this_); };
+inline const z_loaned_ring_handler_matching_status_t* z_loan(const z_owned_ring_handler_matching_status_t& this_) { return z_ring_handler_matching_status_loan(&this_); };
 inline const z_loaned_ring_handler_query_t* z_loan(const z_owned_ring_handler_query_t& this_) { return z_ring_handler_query_loan(&this_); };
 inline const z_loaned_ring_handler_reply_t* z_loan(const z_owned_ring_handler_reply_t& this_) { return z_ring_handler_reply_loan(&this_); };
 inline const z_loaned_ring_handler_sample_t* z_loan(const z_owned_ring_handler_sample_t& this_) { return z_ring_handler_sample_loan(&this_); };
@@ -806,7 +975,9 @@ inline const z_loaned_shm_client_storage_t* z_loan(const z_owned_shm_client_stor
 inline const z_loaned_shm_t* z_loan(const z_owned_shm_t& this_) { return z_shm_loan(&this_); };
 inline const z_loaned_shm_mut_t* z_loan(const z_owned_shm_mut_t& this_) { return z_shm_mut_loan(&this_); };
 inline const z_loaned_shm_provider_t* z_loan(const z_owned_shm_provider_t& this_) { return z_shm_provider_loan(&this_); };
+inline const z_loaned_slice_array_t* z_loan(const z_owned_slice_array_t& this_) { return z_slice_array_loan(&this_); };
 inline const z_loaned_slice_t* z_loan(const z_owned_slice_t& this_) { return z_slice_loan(&this_); };
+inline const z_loaned_slice_map_t* z_loan(const z_owned_slice_map_t& this_) { return z_slice_map_loan(&this_); };
 inline const z_loaned_string_array_t* z_loan(const z_owned_string_array_t& this_) { return z_string_array_loan(&this_); };
 inline const z_loaned_string_t* z_loan(const z_owned_string_t& this_) { return z_string_loan(&this_); };
 inline const z_loaned_subscriber_t* z_loan(const z_owned_subscriber_t& this_) { return z_subscriber_loan(&this_); };
@@ -821,8 +992,10 @@ inline const zc_loaned_shm_client_list_t* z_loan(const zc_owned_shm_client_list_
 inline const ze_loaned_advanced_publisher_t* z_loan(const ze_owned_advanced_publisher_t& this_) { return ze_advanced_publisher_loan(&this_); };
 inline const ze_loaned_advanced_subscriber_t* z_loan(const ze_owned_advanced_subscriber_t& this_) { return ze_advanced_subscriber_loan(&this_); };
 inline const ze_loaned_closure_miss_t* z_loan(const ze_owned_closure_miss_t& closure) { return ze_closure_miss_loan(&closure); };
+inline const ze_loaned_fifo_handler_miss_t* z_loan(const ze_owned_fifo_handler_miss_t& this_) { return ze_fifo_handler_miss_loan(&this_); };
 inline const ze_loaned_publication_cache_t* z_loan(const ze_owned_publication_cache_t& this_) { return ze_publication_cache_loan(&this_); };
 inline const ze_loaned_querying_subscriber_t* z_loan(const ze_owned_querying_subscriber_t& this_) { return ze_querying_subscriber_loan(&this_); };
+inline const ze_loaned_ring_handler_miss_t* z_loan(const ze_owned_ring_handler_miss_t& this_) { return ze_ring_handler_miss_loan(&this_); };
 inline const ze_loaned_serializer_t* z_loan(const ze_owned_serializer_t& this_) { return ze_serializer_loan(&this_); };
 
 
@@ -842,15 +1015,21 @@ inline z_loaned_hello_t* z_loan_mut(z_owned_hello_t& this_) { return z_hello_loa
 inline z_loaned_link_event_t* z_loan_mut(z_owned_link_event_t& this_) { return z_link_event_loan_mut(&this_); };
 inline z_loaned_link_t* z_loan_mut(z_owned_link_t& this_) { return z_link_loan_mut(&this_); };
 inline z_loaned_mutex_t* z_loan_mut(z_owned_mutex_t& this_) { return z_mutex_loan_mut(&this_); };
+inline z_loaned_parameters_t* z_loan_mut(z_owned_parameters_t& this_) { return z_parameters_loan_mut(&this_); };
+inline z_loaned_prefix_publisher_t* z_loan_mut(z_owned_prefix_publisher_t& this_) { return z_prefix_publisher_loan_mut(&this_); };
 inline z_loaned_publisher_t* z_loan_mut(z_owned_publisher_t& this_) { return z_publisher_loan_mut(&this_); };
 inline z_loaned_querier_t* z_loan_mut(z_owned_querier_t& this_) { return z_querier_loan_mut(&this_); };
 inline z_loaned_query_t* z_loan_mut(z_owned_query_t& this_) { return z_query_loan_mut(&this_); };
+inline z_loaned_reply_array_t* z_loan_mut(z_owned_reply_array_t& this_) { return z_reply_array_loan_mut(&this_); };
 inline z_loaned_reply_err_t* z_loan_mut(z_owned_reply_err_t& this_) { return z_reply_err_loan_mut(&this_); };
 inline z_loaned_reply_t* z_loan_mut(z_owned_reply_t& this_) { return z_reply_loan_mut(&this_); };
+inline z_loaned_reply_stream_t* z_loan_mut(z_owned_reply_stream_t& this_) { return z_reply_stream_loan_mut(&this_); };
 inline z_loaned_sample_t* z_loan_mut(z_owned_sample_t& this_) { return z_sample_loan_mut(&this_); };
 inline z_loaned_session_t* z_loan_mut(z_owned_session_t& this_) { return z_session_loan_mut(&this_); };
 inline z_loaned_shm_t* z_loan_mut(z_owned_shm_t& this_) { return z_shm_loan_mut(&this_); };
 inline z_loaned_shm_mut_t* z_loan_mut(z_owned_shm_mut_t& this_) { return z_shm_mut_loan_mut(&this_); };
+inline z_loaned_slice_array_t* z_loan_mut(z_owned_slice_array_t& this_) { return z_slice_array_loan_mut(&this_); };
+inline z_loaned_slice_map_t* z_loan_mut(z_owned_slice_map_t& this_) { return z_slice_map_loan_mut(&this_); };
 inline z_loaned_string_array_t* z_loan_mut(z_owned_string_array_t& this_) { return z_string_array_loan_mut(&this_); };
 inline z_loaned_transport_event_t* z_loan_mut(z_owned_transport_event_t& this_) { return z_transport_event_loan_mut(&this_); };
 inline z_loaned_transport_t* z_loan_mut(z_owned_transport_t& this_) { return z_transport_loan_mut(&this_); };
@@ -864,10 +1043,12 @@ inline void z_drop(z_moved_bytes_writer_t* this_) { z_bytes_writer_drop(this_);
 inline void z_drop(z_moved_cancellation_token_t* this_) { z_cancellation_token_drop(this_); };
 inline void z_drop(z_moved_chunk_alloc_result_t* this_) { z_chunk_alloc_result_drop(this_); };
 inline void z_drop(z_moved_closure_hello_t* this_) { z_closure_hello_drop(this_); };
+inline void z_drop(z_moved_closure_leadership_status_t* closure_) { z_closure_leadership_status_drop(closure_); };
 inline void z_drop(z_moved_closure_link_t* closure_) { z_closure_link_drop(closure_); };
 inline void z_drop(z_moved_closure_link_event_t* closure_) { z_closure_link_event_drop(closure_); };
 inline void z_drop(z_moved_closure_matching_status_t* closure_) { z_closure_matching_status_drop(closure_); };
 inline void z_drop(z_moved_closure_query_t* closure_) { z_closure_query_drop(closure_); };
+inline void z_drop(z_moved_closure_reply_consolidation_t* closure_) { z_closure_reply_consolidation_drop(closure_); };
 inline void z_drop(z_moved_closure_reply_t* closure_) { z_closure_reply_drop(closure_); };
 inline void z_drop(z_moved_closure_sample_t* closure_) { z_closure_sample_drop(closure_); };
 inline void z_drop(z_moved_closure_transport_t* closure_) { z_closure_transport_drop(closure_); };
@@ -876,6 +1057,7 @@ inline void z_drop(z_moved_closure_zid_t* closure_) { z_closure_zid_drop(closure
 inline void z_drop(z_moved_condvar_t* this_) { z_condvar_drop(this_); };
 inline void z_drop(z_moved_config_t* this_) { z_config_drop(this_); };
 inline void z_drop(z_moved_encoding_t* this_) { z_encoding_drop(this_); };
+inline void z_drop(z_moved_fifo_handler_matching_status_t* this_) { z_fifo_handler_matching_status_drop(this_); };
 inline void z_drop(z_moved_fifo_handler_query_t* this_) { z_fifo_handler_query_drop(this_); };
 inline void z_drop(z_moved_fifo_handler_reply_t* this_) { z_fifo_handler_reply_drop(this_); };
 inline void z_drop(z_moved_fifo_handler_sample_t* this_) { z_fifo_handler_sample_drop(this_); };
@@ -884,18 +1066,26 @@ inline void z_drop(z_moved_keyexpr_t* this_) { z_keyexpr_drop(this_); };
 inline void z_drop(z_moved_link_t* this_) { z_link_drop(this_); };
 inline void z_drop(z_moved_link_event_t* this_) { z_link_event_drop(this_); };
 inline void z_drop(z_moved_link_events_listener_t* this_) { z_link_events_listener_drop(this_); };
+inline void z_drop(z_moved_liveliness_election_t* this_) { z_liveliness_election_drop(this_); };
+inline void z_drop(z_moved_liveliness_lock_t* this_) { z_liveliness_lock_drop(this_); };
 inline void z_drop(z_moved_liveliness_token_t* this_) { z_liveliness_token_drop(this_); };
+inline void z_drop(z_moved_liveliness_tracker_t* this_) { z_liveliness_tracker_drop(this_); };
 inline void z_drop(z_moved_matching_listener_t* this_) { z_matching_listener_drop(this_); };
 inline void z_drop(z_moved_memory_layout_t* this_) { z_memory_layout_drop(this_); };
 inline void z_drop(z_moved_mutex_t* this_) { z_mutex_drop(this_); };
+inline void z_drop(z_moved_parameters_t* this_) { z_parameters_drop(this_); };
 inline void z_drop(z_moved_precomputed_layout_t* this_) { z_precomputed_layout_drop(this_); };
+inline void z_drop(z_moved_prefix_publisher_t* this_) { z_prefix_publisher_drop(this_); };
 inline void z_drop(z_moved_ptr_in_segment_t* this_) { z_ptr_in_segment_drop(this_); };
 inline void z_drop(z_moved_publisher_t* this_) { z_publisher_drop(this_); };
 inline void z_drop(z_moved_querier_t* this_) { z_querier_drop(this_); };
 inline void z_drop(z_moved_query_t* this_) { z_query_drop(this_); };
 inline void z_drop(z_moved_queryable_t* this_) { z_queryable_drop(this_); };
+inline void z_drop(z_moved_reply_array_t* this_) { z_reply_array_drop(this_); };
 inline void z_drop(z_moved_reply_t* this_) { z_reply_drop(this_); };
 inline void z_drop(z_moved_reply_err_t* this_) { z_reply_err_drop(this_); };
+inline void z_drop(z_moved_reply_stream_t* this_) { z_reply_stream_drop(this_); };
+inline void z_drop(z_moved_ring_handler_matching_status_t* this_) { z_ring_handler_matching_status_drop(this_); };
 inline void z_drop(z_moved_ring_handler_query_t* this_) { z_ring_handler_query_drop(this_); };
 inline void z_drop(z_moved_ring_handler_reply_t* this_) { z_ring_handler_reply_drop(this_); };
 inline void z_drop(z_moved_ring_handler_sample_t* this_) { z_ring_handler_sample_drop(this_); };
@@ -907,7 +1097,9 @@ inline void z_drop(z_moved_shm_client_storage_t* this_) { z_shm_client_storage_d
 inline void z_drop(z_moved_shm_t* this_) { z_shm_drop(this_); };
 inline void z_drop(z_moved_shm_mut_t* this_) { z_shm_mut_drop(this_); };
 inline void z_drop(z_moved_shm_provider_t* this_) { z_shm_provider_drop(this_); };
+inline void z_drop(z_moved_slice_array_t* this_) { z_slice_array_drop(this_); };
 inline void z_drop(z_moved_slice_t* this_) { z_slice_drop(this_); };
+inline void z_drop(z_moved_slice_map_t* this_) { z_slice_map_drop(this_); };
 inline void z_drop(z_moved_string_array_t* this_) { z_string_array_drop(this_); };
 inline void z_drop(z_moved_string_t* this_) { z_string_drop(this_); };
 inline void z_drop(z_moved_subscriber_t* this_) { z_subscriber_drop(this_); };
@@ -921,8 +1113,10 @@ inline void z_drop(zc_moved_shm_client_list_t* this_) { zc_shm_client_list_drop(
 inline void z_drop(ze_moved_advanced_publisher_t* this_) { ze_advanced_publisher_drop(this_); };
 inline void z_drop(ze_moved_advanced_subscriber_t* this_) { ze_advanced_subscriber_drop(this_); };
 inline void z_drop(ze_moved_closure_miss_t* closure_) { ze_closure_miss_drop(closure_); };
+inline void z_drop(ze_moved_fifo_handler_miss_t* this_) { ze_fifo_handler_miss_drop(this_); };
 inline void z_drop(ze_moved_publication_cache_t* this_) { ze_publication_cache_drop(this_); };
 inline void z_drop(ze_moved_querying_subscriber_t* this_) { ze_querying_subscriber_drop(this_); };
+inline void z_drop(ze_moved_ring_handler_miss_t* this_) { ze_ring_handler_miss_drop(this_); };
 inline void z_drop(ze_moved_sample_miss_listener_t* this_) { ze_sample_miss_listener_drop(this_); };
 inline void z_drop(ze_moved_serializer_t* this_) { ze_serializer_drop(this_); };
 
@@ -932,10 +1126,12 @@ inline z_moved_bytes_writer_t* z_move(z_owned_bytes_writer_t& this_) { return z_
 inline z_moved_cancellation_token_t* z_move(z_owned_cancellation_token_t& this_) { return z_cancellation_token_move(&this_); };
 inline z_moved_chunk_alloc_result_t* z_move(z_owned_chunk_alloc_result_t& this_) { return z_chunk_alloc_result_move(&this_); };
 inline z_moved_closure_hello_t* z_move(z_owned_closure_hello_t& this_) { return z_closure_hello_move(&this_); };
+inline z_moved_closure_leadership_status_t* z_move(z_owned_closure_leadership_status_t& closure_) { return z_closure_leadership_status_move(&closure_); };
 inline z_moved_closure_link_t* z_move(z_owned_closure_link_t& closure_) { return z_closure_link_move(&closure_); };
 inline z_moved_closure_link_event_t* z_move(z_owned_closure_link_event_t& closure_) { return z_closure_link_event_move(&closure_); };
 inline z_moved_closure_matching_status_t* z_move(z_owned_closure_matching_status_t& closure_) { return z_closure_matching_status_move(&closure_); };
 inline z_moved_closure_query_t* z_move(z_owned_closure_query_t& closure_) { return z_closure_query_move(&closure_); };
+inline z_moved_closure_reply_consolidation_t* z_move(z_owned_closure_reply_consolidation_t& closure_) { return z_closure_reply_consolidation_move(&closure_); };
 inline z_moved_closure_reply_t* z_move(z_owned_closure_reply_t& closure_) { return z_closure_reply_move(&closure_); };
 inline z_moved_closure_sample_t* z_move(z_owned_closure_sample_t& closure_) { return z_closure_sample_move(&closure_); };
 inline z_moved_closure_transport_t* z_move(z_owned_closure_transport_t& closure_) { return z_closure_transport_move(&closure_); };
@@ -944,6 +1140,7 @@ inline z_moved_closure_zid_t* z_move(z_owned_closure_zid_t& closure_) { return z
 inline z_moved_condvar_t* z_move(z_owned_condvar_t& this_) { return z_condvar_move(&this_); };
 inline z_moved_config_t* z_move(z_owned_config_t& this_) { return z_config_move(&this_); };
 inline z_moved_encoding_t* z_move(z_owned_encoding_t& this_) { return z_encoding_move(&this_); };
+inline z_moved_fifo_handler_matching_status_t* z_move(z_owned_fifo_handler_matching_status_t& this_) { return z_fifo_handler_matching_status_move(&this_); };
 inline z_moved_fifo_handler_query_t* z_move(z_owned_fifo_handler_query_t& this_) { return z_fifo_handler_query_move(&this_); };
 inline z_moved_fifo_handler_reply_t* z_move(z_owned_fifo_handler_reply_t& this_) { return z_fifo_handler_reply_move(&this_); };
 inline z_moved_fifo_handler_sample_t* z_move(z_owned_fifo_handler_sample_t& this_) { return z_fifo_handler_sample_move(&this_); };
@@ -952,18 +1149,26 @@ inline z_moved_keyexpr_t* z_move(z_owned_keyexpr_t& this_) { return z_keyexpr_mo
 inline z_moved_link_t* z_move(z_owned_link_t& this_) { return z_link_move(&this_); };
 inline z_moved_link_event_t* z_move(z_owned_link_event_t& this_) { return z_link_event_move(&this_); };
 inline z_moved_link_events_listener_t* z_move(z_owned_link_events_listener_t& this_) { return z_link_events_listener_move(&this_); };
+inline z_moved_liveliness_election_t* z_move(z_owned_liveliness_election_t& this_) { return z_liveliness_election_move(&this_); };
+inline z_moved_liveliness_lock_t* z_move(z_owned_liveliness_lock_t& this_) { return z_liveliness_lock_move(&this_); };
 inline z_moved_liveliness_token_t* z_move(z_owned_liveliness_token_t& this_) { return z_liveliness_token_move(&this_); };
+inline z_moved_liveliness_tracker_t* z_move(z_owned_liveliness_tracker_t& this_) { return z_liveliness_tracker_move(&this_); };
 inline z_moved_matching_listener_t* z_move(z_owned_matching_listener_t& this_) { return z_matching_listener_move(&this_); };
 inline z_moved_memory_layout_t* z_move(z_owned_memory_layout_t& this_) { return z_memory_layout_move(&this_); };
 inline z_moved_mutex_t* z_move(z_owned_mutex_t& this_) { return z_mutex_move(&this_); };
+inline z_moved_parameters_t* z_move(z_owned_parameters_t& this_) { return z_parameters_move(&this_); };
 inline z_moved_precomputed_layout_t* z_move(z_owned_precomputed_layout_t& this_) { return z_precomputed_layout_move(&this_); };
+inline z_moved_prefix_publisher_t* z_move(z_owned_prefix_publisher_t& this_) { return z_prefix_publisher_move(&this_); };
 inline z_moved_ptr_in_segment_t* z_move(z_owned_ptr_in_segment_t& this_) { return z_ptr_in_segment_move(&this_); };
 inline z_moved_publisher_t* z_move(z_owned_publisher_t& this_) { return z_publisher_move(&this_); };
 inline z_moved_querier_t* z_move(z_owned_querier_t& this_) { return z_querier_move(&this_); };
 inline z_moved_query_t* z_move(z_owned_query_t& this_) { return z_query_move(&this_); };
 inline z_moved_queryable_t* z_move(z_owned_queryable_t& this_) { return z_queryable_move(&this_); };
+inline z_moved_reply_array_t* z_move(z_owned_reply_array_t& this_) { return z_reply_array_move(&this_); };
 inline z_moved_reply_t* z_move(z_owned_reply_t& this_) { return z_reply_move(&this_); };
 inline z_moved_reply_err_t* z_move(z_owned_reply_err_t& this_) { return z_reply_err_move(&this_); };
+inline z_moved_reply_stream_t* z_move(z_owned_reply_stream_t& this_) { return z_reply_stream_move(&this_); };
+inline z_moved_ring_handler_matching_status_t* z_move(z_owned_ring_handler_matching_status_t& this_) { return z_ring_handler_matching_status_move(&this_); };
 inline z_moved_ring_handler_query_t* z_move(z_owned_ring_handler_query_t& this_) { return z_ring_handler_query_move(&this_); };
 inline z_moved_ring_handler_reply_t* z_move(z_owned_ring_handler_reply_t& this_) { return z_ring_handler_reply_move(&this_); };
 inline z_moved_ring_handler_sample_t* z_move(z_owned_ring_handler_sample_t& this_) { return z_ring_handler_sample_move(&this_); };
@@ -975,7 +1180,9 @@ inline z_moved_shm_client_storage_t* z_move(z_owned_shm_client_storage_t& this_)
 inline z_moved_shm_t* z_move(z_owned_shm_t& this_) { return z_shm_move(&this_); };
 inline z_moved_shm_mut_t* z_move(z_owned_shm_mut_t& this_) { return z_shm_mut_move(&this_); };
 inline z_moved_shm_provider_t* z_move(z_owned_shm_provider_t& this_) { return z_shm_provider_move(&this_); };
+inline z_moved_slice_array_t* z_move(z_owned_slice_array_t& this_) { return z_slice_array_move(&this_); };
 inline z_moved_slice_t* z_move(z_owned_slice_t& this_) { return z_slice_move(&this_); };
+inline z_moved_slice_map_t* z_move(z_owned_slice_map_t& this_) { return z_slice_map_move(&this_); };
 inline z_moved_string_array_t* z_move(z_owned_string_array_t& this_) { return z_string_array_move(&this_); };
 inline z_moved_string_t* z_move(z_owned_string_t& this_) { return z_string_move(&this_); };
 inline z_moved_subscriber_t* z_move(z_owned_subscriber_t& this_) { return z_subscriber_move(&this_); };
@@ -989,8 +1196,10 @@ inline zc_moved_shm_client_list_t* z_move(zc_owned_shm_client_list_t& this_) { r
 inline ze_moved_advanced_publisher_t* z_move(ze_owned_advanced_publisher_t& this_) { return ze_advanced_publisher_move(&this_); };
 inline ze_moved_advanced_subscriber_t* z_move(ze_owned_advanced_subscriber_t& this_) { return ze_advanced_subscriber_move(&this_); };
 inline ze_moved_closure_miss_t* z_move(ze_owned_closure_miss_t& closure_) { return ze_closure_miss_move(&closure_); };
+inline ze_moved_fifo_handler_miss_t* z_move(ze_owned_fifo_handler_miss_t& this_) { return ze_fifo_handler_miss_move(&this_); };
 inline ze_moved_publication_cache_t* z_move(ze_owned_publication_cache_t& this_) { return ze_publication_cache_move(&this_); };
 inline ze_moved_querying_subscriber_t* z_move(ze_owned_querying_subscriber_t& this_) { return ze_querying_subscriber_move(&this_); };
+inline ze_moved_ring_handler_miss_t* z_move(ze_owned_ring_handler_miss_t& this_) { return ze_ring_handler_miss_move(&this_); };
 inline ze_moved_sample_miss_listener_t* z_move(ze_owned_sample_miss_listener_t& this_) { return ze_sample_miss_listener_move(&this_); };
 inline ze_moved_serializer_t* z_move(ze_owned_serializer_t& this_) { return ze_serializer_move(&this_); };
 
@@ -1000,10 +1209,12 @@ inline void z_internal_null(z_owned_bytes_writer_t* this_) { z_internal_bytes_wr
 inline void z_internal_null(z_owned_cancellation_token_t* this_) { z_internal_cancellation_token_null(this_); };
 inline void z_internal_null(z_owned_chunk_alloc_result_t* this_) { z_internal_chunk_alloc_result_null(this_); };
 inline void z_internal_null(z_owned_closure_hello_t* this_) { z_internal_closure_hello_null(this_); };
+inline void z_internal_null(z_owned_closure_leadership_status_t* this_) { z_internal_closure_leadership_status_null(this_); };
 inline void z_internal_null(z_owned_closure_link_event_t* this_) { z_internal_closure_link_event_null(this_); };
 inline void z_internal_null(z_owned_closure_link_t* this_) { z_internal_closure_link_null(this_); };
 inline void z_internal_null(z_owned_closure_matching_status_t* this_) { z_internal_closure_matching_status_null(this_); };
 inline void z_internal_null(z_owned_closure_query_t* this_) { z_internal_closure_query_null(this_); };
+inline void z_internal_null(z_owned_closure_reply_consolidation_t* this_) { z_internal_closure_reply_consolidation_null(this_); };
 inline void z_internal_null(z_owned_closure_reply_t* this_) { z_internal_closure_reply_null(this_); };
 inline void z_internal_null(z_owned_closure_sample_t* this_) { z_internal_closure_sample_null(this_); };
 inline void z_internal_null(z_owned_closure_transport_event_t* this_) { z_internal_closure_transport_event_null(this_); };
@@ -1012,6 +1223,7 @@ inline void z_internal_null(z_owned_closure_zid_t* this_) { z_internal_closure_z
 inline void z_internal_null(z_owned_condvar_t* this_) { z_internal_condvar_null(this_); };
 inline void z_internal_null(z_owned_config_t* this_) { z_internal_config_null(this_); };
 inline void z_internal_null(z_owned_encoding_t* this_) { z_internal_encoding_null(this_); };
+inline void z_internal_null(z_owned_fifo_handler_matching_status_t* this_) { z_internal_fifo_handler_matching_status_null(this_); };
 inline void z_internal_null(z_owned_fifo_handler_query_t* this_) { z_internal_fifo_handler_query_null(this_); };
 inline void z_internal_null(z_owned_fifo_handler_reply_t* this_) { z_internal_fifo_handler_reply_null(this_); };
 inline void z_internal_null(z_owned_fifo_handler_sample_t* this_) { z_internal_fifo_handler_sample_null(this_); };
@@ -1020,18 +1232,26 @@ inline void z_internal_null(z_owned_keyexpr_t* this_) { z_internal_keyexpr_null(
 inline void z_internal_null(z_owned_link_event_t* this_) { z_internal_link_event_null(this_); };
 inline void z_internal_null(z_owned_link_events_listener_t* this_) { z_internal_link_events_listener_null(this_); };
 inline void z_internal_null(z_owned_link_t* this_) { z_internal_link_null(this_); };
+inline void z_internal_null(z_owned_liveliness_election_t* this_) { z_internal_liveliness_election_null(this_); };
+inline void z_internal_null(z_owned_liveliness_lock_t* this_) { z_internal_liveliness_lock_null(this_); };
 inline void z_internal_null(z_owned_liveliness_token_t* this_) { z_internal_liveliness_token_null(this_); };
+inline void z_internal_null(z_owned_liveliness_tracker_t* this_) { z_internal_liveliness_tracker_null(this_); };
 inline void z_internal_null(z_owned_matching_listener_t* this_) { z_internal_matching_listener_null(this_); };
 inline void z_internal_null(z_owned_memory_layout_t* this_) { z_internal_memory_layout_null(this_); };
 inline void z_internal_null(z_owned_mutex_t* this_) { z_internal_mutex_null(this_); };
+inline void z_internal_null(z_owned_parameters_t* this_) { z_internal_parameters_null(this_); };
 inline void z_internal_null(z_owned_precomputed_layout_t* this_) { z_internal_precomputed_layout_null(this_); };
+inline void z_internal_null(z_owned_prefix_publisher_t* this_) { z_internal_prefix_publisher_null(this_); };
 inline void z_internal_null(z_owned_ptr_in_segment_t* this_) { z_internal_ptr_in_segment_null(this_); };
 inline void z_internal_null(z_owned_publisher_t* this_) { z_internal_publisher_null(this_); };
 inline void z_internal_null(z_owned_querier_t* this_) { z_internal_querier_null(this_); };
 inline void z_internal_null(z_owned_query_t* this_) { z_internal_query_null(this_); };
 inline void z_internal_null(z_owned_queryable_t* this_) { z_internal_queryable_null(this_); };
+inline void z_internal_null(z_owned_reply_array_t* this_) { z_internal_reply_array_null(this_); };
 inline void z_internal_null(z_owned_reply_err_t* this_) { z_internal_reply_err_null(this_); };
 inline void z_internal_null(z_owned_reply_t* this_) { z_internal_reply_null(this_); };
+inline void z_internal_null(z_owned_reply_stream_t* this_) { z_internal_reply_stream_null(this_); };
+inline void z_internal_null(z_owned_ring_handler_matching_status_t* this_) { z_internal_ring_handler_matching_status_null(this_); };
 inline void z_internal_null(z_owned_ring_handler_query_t* this_) { z_internal_ring_handler_query_null(this_); };
 inline void z_internal_null(z_owned_ring_handler_reply_t* this_) { z_internal_ring_handler_reply_null(this_); };
 inline void z_internal_null(z_owned_ring_handler_sample_t* this_) { z_internal_ring_handler_sample_null(this_); };
@@ -1043,6 +1263,8 @@ inline void z_internal_null(z_owned_shm_client_storage_t* this_) { z_internal_sh
 inline void z_internal_null(z_owned_shm_mut_t* this_) { z_internal_shm_mut_null(this_); };
 inline void z_internal_null(z_owned_shm_t* this_) { z_internal_shm_null(this_); };
 inline void z_internal_null(z_owned_shm_provider_t* this_) { z_internal_shm_provider_null(this_); };
+inline void z_internal_null(z_owned_slice_array_t* this_) { z_internal_slice_array_null(this_); };
+inline void z_internal_null(z_owned_slice_map_t* this_) { z_internal_slice_map_null(this_); };
 inline void z_internal_null(z_owned_slice_t* this_) { z_internal_slice_null(this_); };
 inline void z_internal_null(z_owned_string_array_t* this_) { z_internal_string_array_null(this_); };
 inline void z_internal_null(z_owned_string_t* this_) { z_internal_string_null(this_); };
@@ -1057,8 +1279,10 @@ inline void z_internal_null(zc_owned_shm_client_list_t* this_) { zc_internal_shm
 inline void z_internal_null(ze_owned_advanced_publisher_t* this_) { ze_internal_advanced_publisher_null(this_); };
 inline void z_internal_null(ze_owned_advanced_subscriber_t* this_) { ze_internal_advanced_subscriber_null(this_); };
 inline void z_internal_null(ze_owned_closure_miss_t* this_) { ze_internal_closure_miss_null(this_); };
+inline void z_internal_null(ze_owned_fifo_handler_miss_t* this_) { ze_internal_fifo_handler_miss_null(this_); };
 inline void z_internal_null(ze_owned_publication_cache_t* this_) { ze_internal_publication_cache_null(this_); };
 inline void z_internal_null(ze_owned_querying_subscriber_t* this_) { ze_internal_querying_subscriber_null(this_); };
+inline void z_internal_null(ze_owned_ring_handler_miss_t* this_) { ze_internal_ring_handler_miss_null(this_); };
 inline void z_internal_null(ze_owned_sample_miss_listener_t* this_) { ze_internal_sample_miss_listener_null(this_); };
 inline void z_internal_null(ze_owned_serializer_t* this_) { ze_internal_serializer_null(this_); };
 
@@ -1067,10 +1291,12 @@ static inline void z_bytes_writer_take(z_owned_bytes_writer_t* this_, z_moved_by
 static inline void z_cancellation_token_take(z_owned_cancellation_token_t* this_, z_moved_cancellation_token_t* x) { *this_ = x->_this; z_internal_cancellation_token_null(&x->_this); }
 static inline void z_chunk_alloc_result_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_result_t* x) { *this_ = x->_this; z_internal_chunk_alloc_result_null(&x->_this); }
 static inline void z_closure_hello_take(z_owned_closure_hello_t* this_, z_moved_closure_hello_t* x) { *this_ = x->_this; z_internal_closure_hello_null(&x->_this); }
+static inline void z_closure_leadership_status_take(z_owned_closure_leadership_status_t* closure_, z_moved_closure_leadership_status_t* x) { *closure_ = x->_this; z_internal_closure_leadership_status_null(&x->_this); }
 static inline void z_closure_link_take(z_owned_closure_link_t* closure_, z_moved_closure_link_t* x) { *closure_ = x->_this; z_internal_closure_link_null(&x->_this); }
 static inline void z_closure_link_event_take(z_owned_closure_link_event_t* closure_, z_moved_closure_link_event_t* x) { *closure_ = x->_this; z_internal_closure_link_event_null(&x->_this); }
 static inline void z_closure_matching_status_take(z_owned_closure_matching_status_t* closure_, z_moved_closure_matching_status_t* x) { *closure_ = x->_this; z_internal_closure_matching_status_null(&x->_this); }
 static inline void z_closure_query_take(z_owned_closure_query_t* closure_, z_moved_closure_query_t* x) { *closure_ = x->_this; z_internal_closure_query_null(&x->_this); }
+static inline void z_closure_reply_consolidation_take(z_owned_closure_reply_consolidation_t* closure_, z_moved_closure_reply_consolidation_t* x) { *closure_ = x->_this; z_internal_closure_reply_consolidation_null(&x->_this); }
 static inline void z_closure_reply_take(z_owned_closure_reply_t* closure_, z_moved_closure_reply_t* x) { *closure_ = x->_this; z_internal_closure_reply_null(&x->_this); }
 static inline void z_closure_sample_take(z_owned_closure_sample_t* closure_, z_moved_closure_sample_t* x) { *closure_ = x->_this; z_internal_closure_sample_null(&x->_this); }
 static inline void z_closure_transport_take(z_owned_closure_transport_t* closure_, z_moved_closure_transport_t* x) { *closure_ = x->_this; z_internal_closure_transport_null(&x->_this); }
@@ -1079,6 +1305,7 @@ static inline void z_closure_zid_take(z_owned_closure_zid_t* closure_, z_moved_c
 static inline void z_condvar_take(z_owned_condvar_t* this_, z_moved_condvar_t* x) { *this_ = x->_this; z_internal_condvar_null(&x->_this); }
 static inline void z_config_take(z_owned_config_t* this_, z_moved_config_t* x) { *this_ = x->_this; z_internal_config_null(&x->_this); }
 static inline void z_encoding_take(z_owned_encoding_t* this_, z_moved_encoding_t* x) { *this_ = x->_this; z_internal_encoding_null(&x->_this); }
+static inline void z_fifo_handler_matching_status_take(z_owned_fifo_handler_matching_status_t* this_, z_moved_fifo_handler_matching_status_t* x) { *this_ = x->_this; z_internal_fifo_handler_matching_status_null(&x->_this); }
 static inline void z_fifo_handler_query_take(z_owned_fifo_handler_query_t* this_, z_moved_fifo_handler_query_t* x) { *this_ = x->_this; z_internal_fifo_handler_query_null(&x->_this); }
 static inline void z_fifo_handler_reply_take(z_owned_fifo_handler_reply_t* this_, z_moved_fifo_handler_reply_t* x) { *this_ = x->_this; z_internal_fifo_handler_reply_null(&x->_this); }
 static inline void z_fifo_handler_sample_take(z_owned_fifo_handler_sample_t* this_, z_moved_fifo_handler_sample_t* x) { *this_ = x->_this; z_internal_fifo_handler_sample_null(&x->_this); }
@@ -1087,18 +1314,26 @@ static inline void z_keyexpr_take(z_owned_keyexpr_t* this_, z_moved_keyexpr_t* x
 static inline void z_link_take(z_owned_link_t* this_, z_moved_link_t* x) { *this_ = x->_this; z_internal_link_null(&x->_this); }
 static inline void z_link_event_take(z_owned_link_event_t* this_, z_moved_link_event_t* x) { *this_ = x->_this; z_internal_link_event_null(&x->_this); }
 static inline void z_link_events_listener_take(z_owned_link_events_listener_t* this_, z_moved_link_events_listener_t* x) { *this_ = x->_this; z_internal_link_events_listener_null(&x->_this); }
+static inline void z_liveliness_election_take(z_owned_liveliness_election_t* this_, z_moved_liveliness_election_t* x) { *this_ = x->_this; z_internal_liveliness_election_null(&x->_this); }
+static inline void z_liveliness_lock_take(z_owned_liveliness_lock_t* this_, z_moved_liveliness_lock_t* x) { *this_ = x->_this; z_internal_liveliness_lock_null(&x->_this); }
 static inline void z_liveliness_token_take(z_owned_liveliness_token_t* this_, z_moved_liveliness_token_t* x) { *this_ = x->_this; z_internal_liveliness_token_null(&x->_this); }
+static inline void z_liveliness_tracker_take(z_owned_liveliness_tracker_t* this_, z_moved_liveliness_tracker_t* x) { *this_ = x->_this; z_internal_liveliness_tracker_null(&x->_this); }
 static inline void z_matching_listener_take(z_owned_matching_listener_t* this_, z_moved_matching_listener_t* x) { *this_ = x->_this; z_internal_matching_listener_null(&x->_this); }
 static inline void z_memory_layout_take(z_owned_memory_layout_t* this_, z_moved_memory_layout_t* x) { *this_ = x->_this; z_internal_memory_layout_null(&x->_this); }
 static inline void z_mutex_take(z_owned_mutex_t* this_, z_moved_mutex_t* x) { *this_ = x->_this; z_internal_mutex_null(&x->_this); }
+static inline void z_parameters_take(z_owned_parameters_t* this_, z_moved_parameters_t* x) { *this_ = x->_this; z_internal_parameters_null(&x->_this); }
 static inline void z_precomputed_layout_take(z_owned_precomputed_layout_t* this_, z_moved_precomputed_layout_t* x) { *this_ = x->_this; z_internal_precomputed_layout_null(&x->_this); }
+static inline void z_prefix_publisher_take(z_owned_prefix_publisher_t* this_, z_moved_prefix_publisher_t* x) { *this_ = x->_this; z_internal_prefix_publisher_null(&x->_this); }
 static inline void z_ptr_in_segment_take(z_owned_ptr_in_segment_t* this_, z_moved_ptr_in_segment_t* x) { *this_ = x->_this; z_internal_ptr_in_segment_null(&x->_this); }
 static inline void z_publisher_take(z_owned_publisher_t* this_, z_moved_publisher_t* x) { *this_ = x->_this; z_internal_publisher_null(&x->_this); }
 static inline void z_querier_take(z_owned_querier_t* this_, z_moved_querier_t* x) { *this_ = x->_this; z_internal_querier_null(&x->_this); }
 static inline void z_query_take(z_owned_query_t* this_, z_moved_query_t* x) { *this_ = x->_this; z_internal_query_null(&x->_this); }
 static inline void z_queryable_take(z_owned_queryable_t* this_, z_moved_queryable_t* x) { *this_ = x->_this; z_internal_queryable_null(&x->_this); }
+static inline void z_reply_array_take(z_owned_reply_array_t* this_, z_moved_reply_array_t* x) { *this_ = x->_this; z_internal_reply_array_null(&x->_this); }
 static inline void z_reply_take(z_owned_reply_t* this_, z_moved_reply_t* x) { *this_ = x->_this; z_internal_reply_null(&x->_this); }
 static inline void z_reply_err_take(z_owned_reply_err_t* this_, z_moved_reply_err_t* x) { *this_ = x->_this; z_internal_reply_err_null(&x->_this); }
+static inline void z_reply_stream_take(z_owned_reply_stream_t* this_, z_moved_reply_stream_t* x) { *this_ = x->_this; z_internal_reply_stream_null(&x->_this); }
+static inline void z_ring_handler_matching_status_take(z_owned_ring_handler_matching_status_t* this_, z_moved_ring_handler_matching_status_t* x) { *this_ = x->_this; z_internal_ring_handler_matching_status_null(&x->_this); }
 static inline void z_ring_handler_query_take(z_owned_ring_handler_query_t* this_, z_moved_ring_handler_query_t* x) { *this_ = x->_this; z_internal_ring_handler_query_null(&x->_this); }
 static inline void z_ring_handler_reply_take(z_owned_ring_handler_reply_t* this_, z_moved_ring_handler_reply_t* x) { *this_ = x->_this; z_internal_ring_handler_reply_null(&x->_this); }
 static inline void z_ring_handler_sample_take(z_owned_ring_handler_sample_t* this_, z_moved_ring_handler_sample_t* x) { *this_ = x->_this; z_internal_ring_handler_sample_null(&x->_this); }
@@ -1110,7 +1345,9 @@ static inline void z_shm_client_storage_take(z_owned_shm_client_storage_t* this_
 static inline void z_shm_take(z_owned_shm_t* this_, z_moved_shm_t* x) { *this_ = x->_this; z_internal_shm_null(&x->_this); }
 static inline void z_shm_mut_take(z_owned_shm_mut_t* this_, z_moved_shm_mut_t* x) { *this_ = x->_this; z_internal_shm_mut_null(&x->_this); }
 static inline void z_shm_provider_take(z_owned_shm_provider_t* this_, z_moved_shm_provider_t* x) { *this_ = x->_this; z_internal_shm_provider_null(&x->_this); }
+static inline void z_slice_array_take(z_owned_slice_array_t* this_, z_moved_slice_array_t* x) { *this_ = x->_this; z_internal_slice_array_null(&x->_this); }
 static inline void z_slice_take(z_owned_slice_t* this_, z_moved_slice_t* x) { *this_ = x->_this; z_internal_slice_null(&x->_this); }
+static inline void z_slice_map_take(z_owned_slice_map_t* this_, z_moved_slice_map_t* x) { *this_ = x->_this; z_internal_slice_map_null(&x->_this); }
 static inline void z_string_array_take(z_owned_string_array_t* this_, z_moved_string_array_t* x) { *this_ = x->_this; z_internal_string_array_null(&x->_this); }
 static inline void z_string_take(z_owned_string_t* this_, z_moved_string_t* x) { *this_ = x->_this; z_internal_string_null(&x->_this); }
 static inline void z_subscriber_take(z_owned_subscriber_t* this_, z_moved_subscriber_t* x) { *this_ = x->_this; z_internal_subscriber_null(&x->_this); }
@@ -1124,8 +1361,10 @@ static inline void zc_shm_client_list_take(zc_owned_shm_client_list_t* this_, zc
 static inline void ze_advanced_publisher_take(ze_owned_advanced_publisher_t* this_, ze_moved_advanced_publisher_t* x) { *this_ = x->_this; ze_internal_advanced_publisher_null(&x->_this); }
 static inline void ze_advanced_subscriber_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subscriber_t* x) { *this_ = x->_this; ze_internal_advanced_subscriber_null(&x->_this); }
 static inline void ze_closure_miss_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) { *closure_ = x->_this; ze_internal_closure_miss_null(&x->_this); }
+static inline void ze_fifo_handler_miss_take(ze_owned_fifo_handler_miss_t* this_, ze_moved_fifo_handler_miss_t* x) { *this_ = x->_this; ze_internal_fifo_handler_miss_null(&x->_this); }
 static inline void ze_publication_cache_take(ze_owned_publication_cache_t* this_, ze_moved_publication_cache_t* x) { *this_ = x->_this; ze_internal_publication_cache_null(&x->_this); }
 static inline void ze_querying_subscriber_take(ze_owned_querying_subscriber_t* this_, ze_moved_querying_subscriber_t* x) { *this_ = x->_this; ze_internal_querying_subscriber_null(&x->_this); }
+static inline void ze_ring_handler_miss_take(ze_owned_ring_handler_miss_t* this_, ze_moved_ring_handler_miss_t* x) { *this_ = x->_this; ze_internal_ring_handler_miss_null(&x->_this); }
 static inline void ze_sample_miss_listener_take(ze_owned_sample_miss_listener_t* this_, ze_moved_sample_miss_listener_t* x) { *this_ = x->_this; ze_internal_sample_miss_listener_null(&x->_this); }
 static inline void ze_serializer_take(ze_owned_serializer_t* this_, ze_moved_serializer_t* x) { *this_ = x->_this; ze_internal_serializer_null(&x->_this); }
 
@@ -1146,6 +1385,9 @@ inline void z_take(z_owned_chunk_alloc_result_t* this_, z_moved_chunk_alloc_resu
 inline void z_take(z_owned_closure_hello_t* this_, z_moved_closure_hello_t* x) {
     z_closure_hello_take(this_, x);
 };
+inline void z_take(z_owned_closure_leadership_status_t* closure_, z_moved_closure_leadership_status_t* x) {
+    z_closure_leadership_status_take(closure_, x);
+};
 inline void z_take(z_owned_closure_link_t* closure_, z_moved_closure_link_t* x) {
     z_closure_link_take(closure_, x);
 };
@@ -1158,6 +1400,9 @@ inline void z_take(z_owned_closure_matching_status_t* closure_, z_moved_closure_
 inline void z_take(z_owned_closure_query_t* closure_, z_moved_closure_query_t* x) {
     z_closure_query_take(closure_, x);
 };
+inline void z_take(z_owned_closure_reply_consolidation_t* closure_, z_moved_closure_reply_consolidation_t* x) {
+    z_closure_reply_consolidation_take(closure_, x);
+};
 inline void z_take(z_owned_closure_reply_t* closure_, z_moved_closure_reply_t* x) {
     z_closure_reply_take(closure_, x);
 };
@@ -1182,6 +1427,9 @@ inline void z_take(z_owned_config_t* this_, z_moved_config_t* x) {
 inline void z_take(z_owned_encoding_t* this_, z_moved_encoding_t* x) {
     z_encoding_take(this_, x);
 };
+inline void z_take(z_owned_fifo_handler_matching_status_t* this_, z_moved_fifo_handler_matching_status_t* x) {
+    z_fifo_handler_matching_status_take(this_, x);
+};
 inline void z_take(z_owned_fifo_handler_query_t* this_, z_moved_fifo_handler_query_t* x) {
     z_fifo_handler_query_take(this_, x);
 };
@@ -1206,9 +1454,18 @@ inline void z_take(z_owned_link_event_t* this_, z_moved_link_event_t* x) {
 inline void z_take(z_owned_link_events_listener_t* this_, z_moved_link_events_listener_t* x) {
     z_link_events_listener_take(this_, x);
 };
+inline void z_take(z_owned_liveliness_election_t* this_, z_moved_liveliness_election_t* x) {
+    z_liveliness_election_take(this_, x);
+};
+inline void z_take(z_owned_liveliness_lock_t* this_, z_moved_liveliness_lock_t* x) {
+    z_liveliness_lock_take(this_, x);
+};
 inline void z_take(z_owned_liveliness_token_t* this_, z_moved_liveliness_token_t* x) {
     z_liveliness_token_take(this_, x);
 };
+inline void z_take(z_owned_liveliness_tracker_t* this_, z_moved_liveliness_tracker_t* x) {
+    z_liveliness_tracker_take(this_, x);
+};
 inline void z_take(z_owned_matching_listener_t* this_, z_moved_matching_listener_t* x) {
     z_matching_listener_take(this_, x);
 };
@@ -1218,9 +1475,15 @@ inline void z_take(z_owned_memory_layout_t* this_, z_moved_memory_layout_t* x) {
 inline void z_take(z_owned_mutex_t* this_, z_moved_mutex_t* x) {
     z_mutex_take(this_, x);
 };
+inline void z_take(z_owned_parameters_t* this_, z_moved_parameters_t* x) {
+    z_parameters_take(this_, x);
+};
 inline void z_take(z_owned_precomputed_layout_t* this_, z_moved_precomputed_layout_t* x) {
     z_precomputed_layout_take(this_, x);
 };
+inline void z_take(z_owned_prefix_publisher_t* this_, z_moved_prefix_publisher_t* x) {
+    z_prefix_publisher_take(this_, x);
+};
 inline void z_take(z_owned_ptr_in_segment_t* this_, z_moved_ptr_in_segment_t* x) {
     z_ptr_in_segment_take(this_, x);
 };
@@ -1236,12 +1499,21 @@ inline void z_take(z_owned_query_t* this_, z_moved_query_t* x) {
 inline void z_take(z_owned_queryable_t* this_, z_moved_queryable_t* x) {
     z_queryable_take(this_, x);
 };
+inline void z_take(z_owned_reply_array_t* this_, z_moved_reply_array_t* x) {
+    z_reply_array_take(this_, x);
+};
 inline void z_take(z_owned_reply_t* this_, z_moved_reply_t* x) {
     z_reply_take(this_, x);
 };
 inline void z_take(z_owned_reply_err_t* this_, z_moved_reply_err_t* x) {
     z_reply_err_take(this_, x);
 };
+inline void z_take(z_owned_reply_stream_t* this_, z_moved_reply_stream_t* x) {
+    z_reply_stream_take(this_, x);
+};
+inline void z_take(z_owned_ring_handler_matching_status_t* this_, z_moved_ring_handler_matching_status_t* x) {
+    z_ring_handler_matching_status_take(this_, x);
+};
 inline void z_take(z_owned_ring_handler_query_t* this_, z_moved_ring_handler_query_t* x) {
     z_ring_handler_query_take(this_, x);
 };
@@ -1275,9 +1547,15 @@ inline void z_take(z_owned_shm_mut_t* this_, z_moved_shm_mut_t* x) {
 inline void z_take(z_owned_shm_provider_t* this_, z_moved_shm_provider_t* x) {
     z_shm_provider_take(this_, x);
 };
+inline void z_take(z_owned_slice_array_t* this_, z_moved_slice_array_t* x) {
+    z_slice_array_take(this_, x);
+};
 inline void z_take(z_owned_slice_t* this_, z_moved_slice_t* x) {
     z_slice_take(this_, x);
 };
+inline void z_take(z_owned_slice_map_t* this_, z_moved_slice_map_t* x) {
+    z_slice_map_take(this_, x);
+};
 inline void z_take(z_owned_string_array_t* this_, z_moved_string_array_t* x) {
     z_string_array_take(this_, x);
 };
@@ -1317,12 +1595,18 @@ inline void z_take(ze_owned_advanced_subscriber_t* this_, ze_moved_advanced_subs
 inline void z_take(ze_owned_closure_miss_t* closure_, ze_moved_closure_miss_t* x) {
     ze_closure_miss_take(closure_, x);
 };
+inline void z_take(ze_owned_fifo_handler_miss_t* this_, ze_moved_fifo_handler_miss_t* x) {
+    ze_fifo_handler_miss_take(this_, x);
+};
 inline void z_take(ze_owned_publication_cache_t* this_, ze_moved_publication_cache_t* x) {
     ze_publication_cache_take(this_, x);
 };
 inline void z_take(ze_owned_querying_subscriber_t* this_, ze_moved_querying_subscriber_t* x) {
     ze_querying_subscriber_take(this_, x);
 };
+inline void z_take(ze_owned_ring_handler_miss_t* this_, ze_moved_ring_handler_miss_t* x) {
+    ze_ring_handler_miss_take(this_, x);
+};
 inline void z_take(ze_owned_sample_miss_listener_t* this_, ze_moved_sample_miss_listener_t* x) {
     ze_sample_miss_listener_take(this_, x);
 };
@@ -1362,11 +1646,13 @@ inline bool z_internal_check(const z_owned_bytes_writer_t& this_) { return z_int
 inline bool z_internal_check(const z_owned_cancellation_token_t& this_) { return z_internal_cancellation_token_check(&this_); };
 inline bool z_internal_check(const z_owned_chunk_alloc_result_t& this_) { return z_internal_chunk_alloc_result_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_hello_t& this_) { return z_internal_closure_hello_check(&this_); };
+inline bool z_internal_check(const z_owned_closure_leadership_status_t& this_) { return z_internal_closure_leadership_status_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_link_t& this_) { return z_internal_closure_link_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_link_event_t& this_) { return z_internal_closure_link_event_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_matching_status_t& this_) { return z_internal_closure_matching_status_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_query_t& this_) { return z_internal_closure_query_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_reply_t& this_) { return z_internal_closure_reply_check(&this_); };
+inline bool z_internal_check(const z_owned_closure_reply_consolidation_t& this_) { return z_internal_closure_reply_consolidation_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_sample_t& this_) { return z_internal_closure_sample_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_transport_t& this_) { return z_internal_closure_transport_check(&this_); };
 inline bool z_internal_check(const z_owned_closure_transport_event_t& this_) { return z_internal_closure_transport_event_check(&this_); };
@@ -1374,6 +1660,7 @@ inline bool z_internal_check(const z_owned_closure_zid_t& this_) { return z_inte
 inline bool z_internal_check(const z_owned_condvar_t& this_) { return z_internal_condvar_check(&this_); };
 inline bool z_internal_check(const z_owned_config_t& this_) { return z_internal_config_check(&this_); };
 inline bool z_internal_check(const z_owned_encoding_t& this_) { return z_internal_encoding_check(&this_); };
+inline bool z_internal_check(const z_owned_fifo_handler_matching_status_t& this_) { return z_internal_fifo_handler_matching_status_check(&this_); };
 inline bool z_internal_check(const z_owned_fifo_handler_query_t& this_) { return z_internal_fifo_handler_query_check(&this_); };
 inline bool z_internal_check(const z_owned_fifo_handler_reply_t& this_) { return z_internal_fifo_handler_reply_check(&this_); };
 inline bool z_internal_check(const z_owned_fifo_handler_sample_t& this_) { return z_internal_fifo_handler_sample_check(&this_); };
@@ -1382,18 +1669,26 @@ inline bool z_internal_check(const z_owned_keyexpr_t& this_) { return z_internal
 inline bool z_internal_check(const z_owned_link_t& this_) { return z_internal_link_check(&this_); };
 inline bool z_internal_check(const z_owned_link_event_t& this_) { return z_internal_link_event_check(&this_); };
 inline bool z_internal_check(const z_owned_link_events_listener_t& this_) { return z_internal_link_events_listener_check(&this_); };
+inline bool z_internal_check(const z_owned_liveliness_election_t& this_) { return z_internal_liveliness_election_check(&this_); };
+inline bool z_internal_check(const z_owned_liveliness_lock_t& this_) { return z_internal_liveliness_lock_check(&this_); };
 inline bool z_internal_check(const z_owned_liveliness_token_t& this_) { return z_internal_liveliness_token_check(&this_); };
+inline bool z_internal_check(const z_owned_liveliness_tracker_t& this_) { return z_internal_liveliness_tracker_check(&this_); };
 inline bool z_internal_check(const z_owned_matching_listener_t& this_) { return z_internal_matching_listener_check(&this_); };
 inline bool z_internal_check(const z_owned_memory_layout_t& this_) { return z_internal_memory_layout_check(&this_); };
 inline bool z_internal_check(const z_owned_mutex_t& this_) { return z_internal_mutex_check(&this_); };
+inline bool z_internal_check(const z_owned_parameters_t& this_) { return z_internal_parameters_check(&this_); };
 inline bool z_internal_check(const z_owned_precomputed_layout_t& this_) { return z_internal_precomputed_layout_check(&this_); };
+inline bool z_internal_check(const z_owned_prefix_publisher_t& this_) { return z_internal_prefix_publisher_check(&this_); };
 inline bool z_internal_check(const z_owned_ptr_in_segment_t& this_) { return z_internal_ptr_in_segment_check(&this_); };
 inline bool z_internal_check(const z_owned_publisher_t& this_) { return z_internal_publisher_check(&this_); };
 inline bool z_internal_check(const z_owned_querier_t& this_) { return z_internal_querier_check(&this_); };
 inline bool z_internal_check(const z_owned_query_t& query) { return z_internal_query_check(&query); };
 inline bool z_internal_check(const z_owned_queryable_t& this_) { return z_internal_queryable_check(&this_); };
+inline bool z_internal_check(const z_owned_reply_array_t& this_) { return z_internal_reply_array_check(&this_); };
 inline bool z_internal_check(const z_owned_reply_t& this_) { return z_internal_reply_check(&this_); };
 inline bool z_internal_check(const z_owned_reply_err_t& this_) { return z_internal_reply_err_check(&this_); };
+inline bool z_internal_check(const z_owned_reply_stream_t& this_) { return z_internal_reply_stream_check(&this_); };
+inline bool z_internal_check(const z_owned_ring_handler_matching_status_t& this_) { return z_internal_ring_handler_matching_status_check(&this_); };
 inline bool z_internal_check(const z_owned_ring_handler_query_t& this_) { return z_internal_ring_handler_query_check(&this_); };
 inline bool z_internal_check(const z_owned_ring_handler_reply_t& this_) { return z_internal_ring_handler_reply_check(&this_); };
 inline bool z_internal_check(const z_owned_ring_handler_sample_t& this_) { return z_internal_ring_handler_sample_check(&this_); };
@@ -1405,7 +1700,9 @@ inline bool z_internal_check(const z_owned_shm_client_t& this_) { return z_inter
 inline bool z_internal_check(const z_owned_shm_client_storage_t& this_) { return z_internal_shm_client_storage_check(&this_); };
 inline bool z_internal_check(const z_owned_shm_mut_t& this_) { return z_internal_shm_mut_check(&this_); };
 inline bool z_internal_check(const z_owned_shm_provider_t& this_) { return z_internal_shm_provider_check(&this_); };
+inline bool z_internal_check(const z_owned_slice_array_t& this_) { return z_internal_slice_array_check(&this_); };
 inline bool z_internal_check(const z_owned_slice_t& this_) { return z_internal_slice_check(&this_); };
+inline bool z_internal_check(const z_owned_slice_map_t& this_) { return z_internal_slice_map_check(&this_); };
 inline bool z_internal_check(const z_owned_string_array_t& this_) { return z_internal_string_array_check(&this_); };
 inline bool z_internal_check(const z_owned_string_t& this_) { return z_internal_string_check(&this_); };
 inline bool z_internal_check(const z_owned_subscriber_t& this_) { return z_internal_subscriber_check(&this_); };
@@ -1419,8 +1716,10 @@ inline bool z_internal_check(const zc_owned_shm_client_list_t& this_) { return z
 inline bool z_internal_check(const ze_owned_advanced_publisher_t& this_) { return ze_internal_advanced_publisher_check(&this_); };
 inline bool z_internal_check(const ze_owned_advanced_subscriber_t& this_) { return ze_internal_advanced_subscriber_check(&this_); };
 inline bool z_internal_check(const ze_owned_closure_miss_t& this_) { return ze_internal_closure_miss_check(&this_); };
+inline bool z_internal_check(const ze_owned_fifo_handler_miss_t& this_) { return ze_internal_fifo_handler_miss_check(&this_); };
 inline bool z_internal_check(const ze_owned_publication_cache_t& this_) { return ze_internal_publication_cache_check(&this_); };
 inline bool z_internal_check(const ze_owned_querying_subscriber_t& this_) { return ze_internal_querying_subscriber_check(&this_); };
+inline bool z_internal_check(const ze_owned_ring_handler_miss_t& this_) { return ze_internal_ring_handler_miss_check(&this_); };
 inline bool z_internal_check(const ze_owned_sample_miss_listener_t& this_) { return ze_internal_sample_miss_listener_check(&this_); };
 inline bool z_internal_check(const ze_owned_serializer_t& this_) { return ze_internal_serializer_check(&this_); };
 
@@ -1428,6 +1727,9 @@ inline bool z_internal_check(const ze_owned_serializer_t& this_) { return ze_int
 inline void z_call(const z_loaned_closure_hello_t* closure, z_loaned_hello_t* hello) {
     z_closure_hello_call(closure, hello);
 };
+inline void z_call(const z_loaned_closure_leadership_status_t* closure, const z_leadership_status_t* leadership_status) {
+    z_closure_leadership_status_call(closure, leadership_status);
+};
 inline void z_call(const z_loaned_closure_link_t* closure, z_loaned_link_t* link) {
     z_closure_link_call(closure, link);
 };
@@ -1461,11 +1763,13 @@ inline void z_call(const ze_loaned_closure_miss_t* closure, const ze_miss_t* mat
 
 extern "C" using z_closure_drop_callback_t = void(void* context);
 extern "C" using z_closure_hello_callback_t = void(z_loaned_hello_t *hello, void *context);
+extern "C" using z_closure_leadership_status_callback_t = void(const z_leadership_status_t *leadership_status, void *context);
 extern "C" using z_closure_link_callback_t = void(z_loaned_link_t *link, void *context);
 extern "C" using z_closure_link_event_callback_t = void(z_loaned_link_event_t *event, void *context);
 extern "C" using z_closure_matching_status_callback_t = void(const z_matching_status_t *matching_status, void *context);
 extern "C" using z_closure_query_callback_t = void(z_loaned_query_t *query, void *context);
 extern "C" using z_closure_reply_callback_t = void(z_loaned_reply_t *reply, void *context);
+extern "C" using z_closure_reply_consolidation_callback_t = void(z_loaned_reply_t *kept, z_loaned_reply_t *candidate, bool *replace, void *context);
 extern "C" using z_closure_sample_callback_t = void(z_loaned_sample_t *sample, void *context);
 extern "C" using z_closure_transport_callback_t = void(z_loaned_transport_t *transport, void *context);
 extern "C" using z_closure_transport_event_callback_t = void(z_loaned_transport_event_t *event, void *context);
@@ -1477,6 +1781,10 @@ inline void z_closure(z_owned_closure_hello_t* this_, z_closure_hello_callback_t
     z_closure_drop_callback_t* drop, void* context) {
     z_closure_hello(this_, call, drop, context);
 };
+inline void z_closure(z_owned_closure_leadership_status_t* this_, z_closure_leadership_status_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    z_closure_leadership_status(this_, call, drop, context);
+};
 inline void z_closure(z_owned_closure_link_t* this_, z_closure_link_callback_t* call,
     z_closure_drop_callback_t* drop, void* context) {
     z_closure_link(this_, call, drop, context);
@@ -1497,6 +1805,10 @@ inline void z_closure(z_owned_closure_reply_t* this_, z_closure_reply_callback_t
     z_closure_drop_callback_t* drop, void* context) {
     z_closure_reply(this_, call, drop, context);
 };
+inline void z_closure(z_owned_closure_reply_consolidation_t* this_, z_closure_reply_consolidation_callback_t* call,
+    z_closure_drop_callback_t* drop, void* context) {
+    z_closure_reply_consolidation(this_, call, drop, context);
+};
 inline void z_closure(z_owned_closure_sample_t* this_, z_closure_sample_callback_t* call,
     z_closure_drop_callback_t* drop, void* context) {
     z_closure_sample(this_, call, drop, context);
@@ -1523,6 +1835,9 @@ inline void z_closure(ze_owned_closure_miss_t* this_, ze_closure_miss_callback_t
 };
 
 
+inline z_result_t z_try_recv(const z_loaned_fifo_handler_matching_status_t* this_, z_matching_status_t* matching_status) {
+    return z_fifo_handler_matching_status_try_recv(this_, matching_status);
+};
 inline z_result_t z_try_recv(const z_loaned_fifo_handler_query_t* this_, z_owned_query_t* query) {
     return z_fifo_handler_query_try_recv(this_, query);
 };
@@ -1532,6 +1847,9 @@ inline z_result_t z_try_recv(const z_loaned_fifo_handler_reply_t* this_, z_owned
 inline z_result_t z_try_recv(const z_loaned_fifo_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_fifo_handler_sample_try_recv(this_, sample);
 };
+inline z_result_t z_try_recv(const z_loaned_ring_handler_matching_status_t* this_, z_matching_status_t* matching_status) {
+    return z_ring_handler_matching_status_try_recv(this_, matching_status);
+};
 inline z_result_t z_try_recv(const z_loaned_ring_handler_query_t* this_, z_owned_query_t* query) {
     return z_ring_handler_query_try_recv(this_, query);
 };
@@ -1541,8 +1859,17 @@ inline z_result_t z_try_recv(const z_loaned_ring_handler_reply_t* this_, z_owned
 inline z_result_t z_try_recv(const z_loaned_ring_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_ring_handler_sample_try_recv(this_, sample);
 };
+inline z_result_t z_try_recv(const ze_loaned_fifo_handler_miss_t* this_, ze_miss_t* miss) {
+    return ze_fifo_handler_miss_try_recv(this_, miss);
+};
+inline z_result_t z_try_recv(const ze_loaned_ring_handler_miss_t* this_, ze_miss_t* miss) {
+    return ze_ring_handler_miss_try_recv(this_, miss);
+};
 
 
+inline z_result_t z_recv(const z_loaned_fifo_handler_matching_status_t* this_, z_matching_status_t* matching_status) {
+    return z_fifo_handler_matching_status_recv(this_, matching_status);
+};
 inline z_result_t z_recv(const z_loaned_fifo_handler_query_t* this_, z_owned_query_t* query) {
     return z_fifo_handler_query_recv(this_, query);
 };
@@ -1552,6 +1879,9 @@ inline z_result_t z_recv(const z_loaned_fifo_handler_reply_t* this_, z_owned_rep
 inline z_result_t z_recv(const z_loaned_fifo_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_fifo_handler_sample_recv(this_, sample);
 };
+inline z_result_t z_recv(const z_loaned_ring_handler_matching_status_t* this_, z_matching_status_t* matching_status) {
+    return z_ring_handler_matching_status_recv(this_, matching_status);
+};
 inline z_result_t z_recv(const z_loaned_ring_handler_query_t* this_, z_owned_query_t* query) {
     return z_ring_handler_query_recv(this_, query);
 };
@@ -1561,6 +1891,12 @@ inline z_result_t z_recv(const z_loaned_ring_handler_reply_t* this_, z_owned_rep
 inline z_result_t z_recv(const z_loaned_ring_handler_sample_t* this_, z_owned_sample_t* sample) {
     return z_ring_handler_sample_recv(this_, sample);
 };
+inline z_result_t z_recv(const ze_loaned_fifo_handler_miss_t* this_, ze_miss_t* miss) {
+    return ze_fifo_handler_miss_recv(this_, miss);
+};
+inline z_result_t z_recv(const ze_loaned_ring_handler_miss_t* this_, ze_miss_t* miss) {
+    return ze_ring_handler_miss_recv(this_, miss);
+};
 
 
 inline void z_clone(z_owned_bytes_t* dst, const z_loaned_bytes_t* this_) {
@@ -1584,12 +1920,18 @@ inline void z_clone(z_owned_keyexpr_t* dst, const z_loaned_keyexpr_t* this_) {
 inline void z_clone(z_owned_link_t* this_, const z_loaned_link_t* link) {
     z_link_clone(this_, link);
 };
+inline void z_clone(z_owned_parameters_t* dst, const z_loaned_parameters_t* this_) {
+    z_parameters_clone(dst, this_);
+};
 inline void z_clone(z_owned_ptr_in_segment_t* out, const z_loaned_ptr_in_segment_t* this_) {
     z_ptr_in_segment_clone(out, this_);
 };
 inline void z_clone(z_owned_query_t* dst, const z_loaned_query_t* this_) {
     z_query_clone(dst, this_);
 };
+inline void z_clone(z_owned_reply_array_t* dst, const z_loaned_reply_array_t* this_) {
+    z_reply_array_clone(dst, this_);
+};
 inline void z_clone(z_owned_reply_t* dst, const z_loaned_reply_t* this_) {
     z_reply_clone(dst, this_);
 };
@@ -1608,9 +1950,15 @@ inline void z_clone(z_owned_shm_client_storage_t* this_, const z_loaned_shm_clie
 inline void z_clone(z_owned_shm_t* out, const z_loaned_shm_t* this_) {
     z_shm_clone(out, this_);
 };
+inline void z_clone(z_owned_slice_array_t* dst, const z_loaned_slice_array_t* this_) {
+    z_slice_array_clone(dst, this_);
+};
 inline void z_clone(z_owned_slice_t* dst, const z_loaned_slice_t* this_) {
     z_slice_clone(dst, this_);
 };
+inline void z_clone(z_owned_slice_map_t* dst, const z_loaned_slice_map_t* this_) {
+    z_slice_map_clone(dst, this_);
+};
 inline void z_clone(z_owned_string_array_t* dst, const z_loaned_string_array_t* this_) {
     z_string_array_clone(dst, this_);
 };
@@ -1631,6 +1979,8 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_cancellation_token_t> { type
 template<> struct z_owned_to_loaned_type_t<z_owned_cancellation_token_t> { typedef z_loaned_cancellation_token_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_hello_t> { typedef z_owned_closure_hello_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_hello_t> { typedef z_loaned_closure_hello_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_closure_leadership_status_t> { typedef z_owned_closure_leadership_status_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_closure_leadership_status_t> { typedef z_loaned_closure_leadership_status_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_link_event_t> { typedef z_owned_closure_link_event_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_link_event_t> { typedef z_loaned_closure_link_event_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_link_t> { typedef z_owned_closure_link_t type; };
@@ -1639,6 +1989,8 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_closure_matching_status_t> {
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_matching_status_t> { typedef z_loaned_closure_matching_status_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_query_t> { typedef z_owned_closure_query_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_query_t> { typedef z_loaned_closure_query_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_closure_reply_consolidation_t> { typedef z_owned_closure_reply_consolidation_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_closure_reply_consolidation_t> { typedef z_loaned_closure_reply_consolidation_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_reply_t> { typedef z_owned_closure_reply_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_closure_reply_t> { typedef z_loaned_closure_reply_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_closure_sample_t> { typedef z_owned_closure_sample_t type; };
@@ -1655,6 +2007,8 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_config_t> { typedef z_owned_
 template<> struct z_owned_to_loaned_type_t<z_owned_config_t> { typedef z_loaned_config_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_encoding_t> { typedef z_owned_encoding_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_encoding_t> { typedef z_loaned_encoding_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_fifo_handler_matching_status_t> { typedef z_owned_fifo_handler_matching_status_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_fifo_handler_matching_status_t> { typedef z_loaned_fifo_handler_matching_status_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_fifo_handler_query_t> { typedef z_owned_fifo_handler_query_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_fifo_handler_query_t> { typedef z_loaned_fifo_handler_query_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_fifo_handler_reply_t> { typedef z_owned_fifo_handler_reply_t type; };
@@ -1671,12 +2025,22 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_link_events_listener_t> { ty
 template<> struct z_owned_to_loaned_type_t<z_owned_link_events_listener_t> { typedef z_loaned_link_events_listener_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_link_t> { typedef z_owned_link_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_link_t> { typedef z_loaned_link_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_liveliness_election_t> { typedef z_owned_liveliness_election_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_liveliness_election_t> { typedef z_loaned_liveliness_election_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_liveliness_lock_t> { typedef z_owned_liveliness_lock_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_liveliness_lock_t> { typedef z_loaned_liveliness_lock_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_liveliness_token_t> { typedef z_owned_liveliness_token_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_liveliness_token_t> { typedef z_loaned_liveliness_token_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_liveliness_tracker_t> { typedef z_owned_liveliness_tracker_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_liveliness_tracker_t> { typedef z_loaned_liveliness_tracker_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_memory_layout_t> { typedef z_owned_memory_layout_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_memory_layout_t> { typedef z_loaned_memory_layout_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_parameters_t> { typedef z_owned_parameters_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_parameters_t> { typedef z_loaned_parameters_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_precomputed_layout_t> { typedef z_owned_precomputed_layout_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_precomputed_layout_t> { typedef z_loaned_precomputed_layout_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_prefix_publisher_t> { typedef z_owned_prefix_publisher_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_prefix_publisher_t> { typedef z_loaned_prefix_publisher_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_ptr_in_segment_t> { typedef z_owned_ptr_in_segment_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_ptr_in_segment_t> { typedef z_loaned_ptr_in_segment_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_publisher_t> { typedef z_owned_publisher_t type; };
@@ -1687,10 +2051,16 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_query_t> { typedef z_owned_q
 template<> struct z_owned_to_loaned_type_t<z_owned_query_t> { typedef z_loaned_query_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_queryable_t> { typedef z_owned_queryable_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_queryable_t> { typedef z_loaned_queryable_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_reply_array_t> { typedef z_owned_reply_array_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_reply_array_t> { typedef z_loaned_reply_array_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_reply_err_t> { typedef z_owned_reply_err_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_reply_err_t> { typedef z_loaned_reply_err_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_reply_t> { typedef z_owned_reply_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_reply_t> { typedef z_loaned_reply_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_reply_stream_t> { typedef z_owned_reply_stream_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_reply_stream_t> { typedef z_loaned_reply_stream_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_ring_handler_matching_status_t> { typedef z_owned_ring_handler_matching_status_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_ring_handler_matching_status_t> { typedef z_loaned_ring_handler_matching_status_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_ring_handler_query_t> { typedef z_owned_ring_handler_query_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_ring_handler_query_t> { typedef z_loaned_ring_handler_query_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_ring_handler_reply_t> { typedef z_owned_ring_handler_reply_t type; };
@@ -1711,8 +2081,12 @@ template<> struct z_loaned_to_owned_type_t<z_loaned_shm_mut_t> { typedef z_owned
 template<> struct z_owned_to_loaned_type_t<z_owned_shm_mut_t> { typedef z_loaned_shm_mut_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_shm_provider_t> { typedef z_owned_shm_provider_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_shm_provider_t> { typedef z_loaned_shm_provider_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_slice_array_t> { typedef z_owned_slice_array_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_slice_array_t> { typedef z_loaned_slice_array_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_slice_t> { typedef z_owned_slice_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_slice_t> { typedef z_loaned_slice_t type; };
+template<> struct z_loaned_to_owned_type_t<z_loaned_slice_map_t> { typedef z_owned_slice_map_t type; };
+template<> struct z_owned_to_loaned_type_t<z_owned_slice_map_t> { typedef z_loaned_slice_map_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_string_array_t> { typedef z_owned_string_array_t type; };
 template<> struct z_owned_to_loaned_type_t<z_owned_string_array_t> { typedef z_loaned_string_array_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_string_t> { typedef z_owned_string_t type; };
@@ -1735,10 +2109,14 @@ template<> struct z_loaned_to_owned_type_t<ze_loaned_advanced_subscriber_t> { ty
 template<> struct z_owned_to_loaned_type_t<ze_owned_advanced_subscriber_t> { typedef ze_loaned_advanced_subscriber_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_closure_miss_t> { typedef ze_owned_closure_miss_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_closure_miss_t> { typedef ze_loaned_closure_miss_t type; };
+template<> struct z_loaned_to_owned_type_t<ze_loaned_fifo_handler_miss_t> { typedef ze_owned_fifo_handler_miss_t type; };
+template<> struct z_owned_to_loaned_type_t<ze_owned_fifo_handler_miss_t> { typedef ze_loaned_fifo_handler_miss_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_publication_cache_t> { typedef ze_owned_publication_cache_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_publication_cache_t> { typedef ze_loaned_publication_cache_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_querying_subscriber_t> { typedef ze_owned_querying_subscriber_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_querying_subscriber_t> { typedef ze_loaned_querying_subscriber_t type; };
+template<> struct z_loaned_to_owned_type_t<ze_loaned_ring_handler_miss_t> { typedef ze_owned_ring_handler_miss_t type; };
+template<> struct z_owned_to_loaned_type_t<ze_owned_ring_handler_miss_t> { typedef ze_loaned_ring_handler_miss_t type; };
 template<> struct z_loaned_to_owned_type_t<ze_loaned_serializer_t> { typedef ze_owned_serializer_t type; };
 template<> struct z_owned_to_loaned_type_t<ze_owned_serializer_t> { typedef ze_loaned_serializer_t type; };
 template<> struct z_loaned_to_owned_type_t<z_loaned_mutex_t> { typedef z_owned_mutex_t type; };
@@ -12,20 +12,29 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::{mem::MaybeUninit, time::Duration};
+use std::{
+    mem::MaybeUninit,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
 
-use zenoh::{handlers::Callback, liveliness::LivelinessSubscriberBuilder, sample::Sample, Wait};
+use zenoh::{
+    handlers::Callback, liveliness::LivelinessSubscriberBuilder, sample::Sample,
+    session::EntityGlobalId, Wait,
+};
 use zenoh_ext::{AdvancedSubscriberBuilderExt, HistoryConfig, RecoveryConfig, SampleMissListener};
 
 use crate::{
-    _declare_subscriber_inner, result,
-    transmute::{IntoCType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_closure_sample_call, z_closure_sample_loan, z_entity_global_id_t,
+    _declare_subscriber_inner, _is_paused, result,
+    transmute::{
+        IntoCType, IntoRustType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType,
+    },
+    z_closure_sample_call, z_closure_sample_loan, z_entity_global_id_t, z_id_t,
     z_liveliness_subscriber_options_t, z_loaned_keyexpr_t, z_loaned_session_t,
     z_moved_closure_sample_t, z_owned_subscriber_t, z_subscriber_options_t, ze_closure_miss_call,
     ze_closure_miss_loan, ze_loaned_advanced_subscriber_t, ze_moved_advanced_subscriber_t,
     ze_moved_closure_miss_t, ze_moved_sample_miss_listener_t, ze_owned_advanced_subscriber_t,
-    ze_owned_sample_miss_listener_t,
+    ze_owned_sample_miss_listener_t, CSubscriber,
 };
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -203,6 +212,7 @@ fn _declare_advanced_subscriber_inner(
         key_expr,
         callback,
         options.as_mut().map(|o| &mut o.subscriber_options),
+        None,
     );
     let mut sub = sub.advanced();
     if let Some(options) = options {
@@ -357,6 +367,7 @@ pub extern "C" fn ze_undeclare_advanced_subscriber(
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
 /// @brief A struct that represents missed samples.
 #[repr(C)]
+#[derive(Clone, Copy)]
 pub struct ze_miss_t {
     /// The source of missed samples.
     pub source: z_entity_global_id_t,
@@ -364,6 +375,16 @@ pub struct ze_miss_t {
     pub nb: u32,
 }
 
+impl Default for ze_miss_t {
+    fn default() -> Self {
+        ze_miss_t {
+            // an all-zero id, `EntityGlobalId::default()` having a random zid
+            source: EntityGlobalId::new(z_id_t::from([0; 16]).into_rust_type(), 0).into_c_type(),
+            nb: 0,
+        }
+    }
+}
+
 decl_c_type!(
     owned(ze_owned_sample_miss_listener_t, option SampleMissListener<()>),
 );
@@ -481,8 +502,9 @@ pub extern "C" fn ze_advanced_subscriber_declare_background_sample_miss_listener
 
 fn _advanced_subscriber_detect_publishers_inner(
     subscriber: &'static ze_loaned_advanced_subscriber_t,
-    callback: &'static mut z_moved_closure_sample_t,
+    callback: &mut z_moved_closure_sample_t,
     options: Option<&'static mut z_liveliness_subscriber_options_t>,
+    paused: Option<Arc<AtomicBool>>,
 ) -> LivelinessSubscriberBuilder<'static, 'static, Callback<Sample>> {
     let subscriber = subscriber.as_rust_type_ref();
     let callback = callback.take_rust_type();
@@ -490,6 +512,9 @@ fn _advanced_subscriber_detect_publishers_inner(
         .detect_publishers()
         .history(options.is_some_and(|o| o.history))
         .callback(move |sample| {
+            if _is_paused(&paused) {
+                return;
+            }
             let mut owned_sample = Some(sample);
             z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
                 owned_sample
@@ -518,10 +543,16 @@ pub extern "C" fn ze_advanced_subscriber_detect_publishers(
     options: Option<&'static mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
     let liveliness_subscriber = liveliness_subscriber.as_rust_type_mut_uninit();
-    let builder = _advanced_subscriber_detect_publishers_inner(subscriber, callback, options);
+    let paused = Arc::new(AtomicBool::new(false));
+    let builder = _advanced_subscriber_detect_publishers_inner(
+        subscriber,
+        callback,
+        options,
+        Some(paused.clone()),
+    );
     match builder.wait() {
-        Ok(s) => {
-            liveliness_subscriber.write(Some(s));
+        Ok(subscriber) => {
+            liveliness_subscriber.write(Some(CSubscriber { subscriber, paused }));
             result::Z_OK
         }
         Err(e) => {
@@ -546,7 +577,7 @@ pub extern "C" fn ze_advanced_subscriber_detect_publishers_background(
     callback: &'static mut z_moved_closure_sample_t,
     options: Option<&'static mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
-    let builder = _advanced_subscriber_detect_publishers_inner(subscriber, callback, options);
+    let builder = _advanced_subscriber_detect_publishers_inner(subscriber, callback, options, None);
     match builder.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -577,3 +608,30 @@ pub extern "C" fn ze_advanced_subscriber_id(
 ) -> z_entity_global_id_t {
     subscriber.as_rust_type_ref().id().into_c_type()
 }
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Advanced subscribers can not be paused: samples dropped while paused would bypass their miss detection and
+/// recovery. Undeclare the advanced subscriber to stop receiving samples.
+///
+/// @return `Z_EUNAVAILABLE`.
+#[no_mangle]
+pub extern "C" fn ze_advanced_subscriber_pause(
+    subscriber: &ze_loaned_advanced_subscriber_t,
+) -> result::z_result_t {
+    let _ = subscriber;
+    crate::report_error!("Advanced subscribers can not be paused");
+    result::Z_EUNAVAILABLE
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Advanced subscribers can not be paused, see `ze_advanced_subscriber_pause()`.
+///
+/// @return `Z_EUNAVAILABLE`.
+#[no_mangle]
+pub extern "C" fn ze_advanced_subscriber_resume(
+    subscriber: &ze_loaned_advanced_subscriber_t,
+) -> result::z_result_t {
+    let _ = subscriber;
+    crate::report_error!("Advanced subscribers can not be paused");
+    result::Z_EUNAVAILABLE
+}
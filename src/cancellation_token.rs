@@ -12,9 +12,13 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+use std::{
+    mem::MaybeUninit,
+    sync::{Mutex, MutexGuard},
+};
 
-use zenoh::{cancellation::CancellationToken, Wait};
+use zenoh::{cancellation::CancellationToken, query::Reply, Wait};
+use zenoh_runtime::ZRuntime;
 
 use crate::opaque_types::{
     z_loaned_cancellation_token_t, z_moved_cancellation_token_t, z_owned_cancellation_token_t,
@@ -129,3 +133,67 @@ pub extern "C" fn z_cancellation_token_cancel(
 pub extern "C" fn z_cancellation_token_is_cancelled(this_: &z_loaned_cancellation_token_t) -> bool {
     this_.as_rust_type_ref().is_cancelled()
 }
+
+/// Forwards the cancellation of a token to another one for as long as it is alive.
+pub(crate) struct CancellationForward {
+    source: CancellationToken,
+    /// The id of the handler forwarding the cancellation of `source`.
+    id: usize,
+}
+
+impl CancellationForward {
+    /// Returns `None` if `source` is already cancelled, in which case `target` is cancelled right away.
+    pub(crate) fn new(source: CancellationToken, target: CancellationToken) -> Option<Self> {
+        match source.add_on_cancel_handler(move || target.cancel().wait()) {
+            Ok(id) => Some(CancellationForward { source, id }),
+            Err(forward) => {
+                let _ = forward();
+                None
+            }
+        }
+    }
+}
+
+impl Drop for CancellationForward {
+    fn drop(&mut self) {
+        self.source.remove_on_cancel_handler(self.id);
+    }
+}
+
+/// Interrupts a query once its first successful reply is handed over, so that later replies never reach its callback.
+pub(crate) struct FirstReplyRace {
+    won: Mutex<bool>,
+    token: CancellationToken,
+    /// Forwards the cancellation of the user-supplied cancellation token to `token`.
+    _user_token: Option<CancellationForward>,
+}
+
+impl FirstReplyRace {
+    /// Returns the race along with the cancellation token the query should be interrupted with.
+    pub(crate) fn new(user_token: Option<CancellationToken>) -> (Self, CancellationToken) {
+        let token = CancellationToken::default();
+        let race = FirstReplyRace {
+            won: Mutex::new(false),
+            token: token.clone(),
+            _user_token: user_token.and_then(|t| CancellationForward::new(t, token.clone())),
+        };
+        (race, token)
+    }
+
+    /// Returns a guard to hold while handing the reply over to the callback, or `None` if the reply should be discarded.
+    pub(crate) fn admit(&self, reply: &Reply) -> Option<MutexGuard<'_, bool>> {
+        let mut won = self.won.lock().unwrap_or_else(|e| e.into_inner());
+        if *won {
+            return None;
+        }
+        if reply.result().is_ok() {
+            *won = true;
+            // Cancelling waits for the query callback to be dropped, so it can't be done from within the callback.
+            let token = self.token.clone();
+            ZRuntime::Application.spawn(async move {
+                let _ = token.cancel().await;
+            });
+        }
+        Some(won)
+    }
+}
@@ -0,0 +1,277 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::mem::MaybeUninit;
+
+use libc::c_void;
+use zenoh::handlers::{self, Callback, FifoChannelHandler, IntoHandler, RingChannelHandler};
+
+pub use crate::opaque_types::{
+    z_loaned_fifo_handler_matching_status_t, z_moved_fifo_handler_matching_status_t,
+    z_owned_fifo_handler_matching_status_t,
+};
+use crate::{
+    result::{self, z_result_t},
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_matching_status_t, z_owned_closure_matching_status_t,
+};
+decl_c_type!(
+    owned(z_owned_fifo_handler_matching_status_t, option FifoChannelHandler<z_matching_status_t>),
+    loaned(z_loaned_fifo_handler_matching_status_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_fifo_handler_matching_status_drop(
+    this_: &mut z_moved_fifo_handler_matching_status_t,
+) {
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_fifo_handler_matching_status_null(
+    this: &mut MaybeUninit<z_owned_fifo_handler_matching_status_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_fifo_handler_matching_status_check(
+    this_: &z_owned_fifo_handler_matching_status_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+extern "C" fn __z_handler_matching_status_send(
+    matching_status: &z_matching_status_t,
+    context: *mut c_void,
+) {
+    unsafe {
+        let f = (context as *mut Callback<z_matching_status_t>)
+            .as_mut()
+            .unwrap_unchecked();
+        f.call(*matching_status);
+    }
+}
+
+extern "C" fn __z_handler_matching_status_drop(context: *mut c_void) {
+    unsafe {
+        let f = Box::from_raw(context as *mut Callback<z_matching_status_t>);
+        std::mem::drop(f);
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the fifo channel
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_fifo_channel_matching_status_new(
+    callback: &mut MaybeUninit<z_owned_closure_matching_status_t>,
+    handler: &mut MaybeUninit<z_owned_fifo_handler_matching_status_t>,
+    capacity: usize,
+) {
+    let fifo = handlers::FifoChannel::new(capacity);
+    let (cb, h) = fifo.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(z_owned_closure_matching_status_t {
+        _call: Some(__z_handler_matching_status_send),
+        _context: cb_ptr,
+        _drop: Some(__z_handler_matching_status_drop),
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_fifo_handler_matching_status_loan(
+    this: &z_owned_fifo_handler_matching_status_t,
+) -> &z_loaned_fifo_handler_matching_status_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the fifo buffer. If there are no more pending matching statuses will block until next matching status is received, or until
+/// the channel is dropped (normally when the matching listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn z_fifo_handler_matching_status_recv(
+    this: &z_loaned_fifo_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<z_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(q) => {
+            matching_status.write(q);
+            result::Z_OK
+        }
+        Err(_) => {
+            matching_status.write(z_matching_status_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the fifo buffer.
+/// If there are no more pending matching statuses will return immediately (with matching status zero-initialized).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized),
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the matching status will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn z_fifo_handler_matching_status_try_recv(
+    this: &z_loaned_fifo_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<z_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(Some(q)) => {
+            matching_status.write(q);
+            result::Z_OK
+        }
+        Ok(None) => {
+            matching_status.write(z_matching_status_t::default());
+            result::Z_CHANNEL_NODATA
+        }
+        Err(_) => {
+            matching_status.write(z_matching_status_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+pub use crate::opaque_types::{
+    z_loaned_ring_handler_matching_status_t, z_moved_ring_handler_matching_status_t,
+    z_owned_ring_handler_matching_status_t,
+};
+decl_c_type!(
+    owned(
+        z_owned_ring_handler_matching_status_t,
+        option RingChannelHandler<z_matching_status_t>,
+    ),
+    loaned(z_loaned_ring_handler_matching_status_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_ring_handler_matching_status_drop(
+    this_: &mut z_moved_ring_handler_matching_status_t,
+) {
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_ring_handler_matching_status_null(
+    this: &mut MaybeUninit<z_owned_ring_handler_matching_status_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_ring_handler_matching_status_check(
+    this_: &z_owned_ring_handler_matching_status_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the ring channel
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_ring_channel_matching_status_new(
+    callback: &mut MaybeUninit<z_owned_closure_matching_status_t>,
+    handler: &mut MaybeUninit<z_owned_ring_handler_matching_status_t>,
+    capacity: usize,
+) {
+    let ring = handlers::RingChannel::new(capacity);
+    let (cb, h) = ring.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(z_owned_closure_matching_status_t {
+        _call: Some(__z_handler_matching_status_send),
+        _context: cb_ptr,
+        _drop: Some(__z_handler_matching_status_drop),
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_ring_handler_matching_status_loan(
+    this: &z_owned_ring_handler_matching_status_t,
+) -> &z_loaned_ring_handler_matching_status_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the ring buffer. If there are no more pending matching statuses will block until next matching status is received, or until
+/// the channel is dropped (normally when the matching listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn z_ring_handler_matching_status_recv(
+    this: &z_loaned_ring_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<z_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(q) => {
+            matching_status.write(q);
+            result::Z_OK
+        }
+        Err(_) => {
+            matching_status.write(z_matching_status_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns matching status from the ring buffer. If there are no more pending matching statuses will return immediately (with matching status zero-initialized).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the matching status will be zero-initialized),
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the matching status will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn z_ring_handler_matching_status_try_recv(
+    this: &z_loaned_ring_handler_matching_status_t,
+    matching_status: &mut MaybeUninit<z_matching_status_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(q) => {
+            let r = if q.is_some() {
+                result::Z_OK
+            } else {
+                result::Z_CHANNEL_NODATA
+            };
+            matching_status.write(q.unwrap_or_default());
+            r
+        }
+        Err(_) => {
+            matching_status.write(z_matching_status_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
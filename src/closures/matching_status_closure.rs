@@ -25,9 +25,10 @@ use crate::{
 /// A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
 #[repr(C)]
 pub struct z_owned_closure_matching_status_t {
-    _context: *mut c_void,
-    _call: Option<extern "C" fn(matching_status: &z_matching_status_t, context: *mut c_void)>,
-    _drop: Option<extern "C" fn(context: *mut c_void)>,
+    pub(crate) _context: *mut c_void,
+    pub(crate) _call:
+        Option<extern "C" fn(matching_status: &z_matching_status_t, context: *mut c_void)>,
+    pub(crate) _drop: Option<extern "C" fn(context: *mut c_void)>,
 }
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
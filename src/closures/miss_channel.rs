@@ -0,0 +1,268 @@
+//
+// Copyright (c) 2017, 2024 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::mem::MaybeUninit;
+
+use libc::c_void;
+use zenoh::handlers::{self, Callback, FifoChannelHandler, IntoHandler, RingChannelHandler};
+
+pub use crate::opaque_types::{
+    ze_loaned_fifo_handler_miss_t, ze_moved_fifo_handler_miss_t, ze_owned_fifo_handler_miss_t,
+};
+use crate::{
+    result::{self, z_result_t},
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    ze_miss_t, ze_owned_closure_miss_t,
+};
+decl_c_type!(
+    owned(ze_owned_fifo_handler_miss_t, option FifoChannelHandler<ze_miss_t>),
+    loaned(ze_loaned_fifo_handler_miss_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_fifo_handler_miss_drop(this_: &mut ze_moved_fifo_handler_miss_t) {
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_internal_fifo_handler_miss_null(
+    this: &mut MaybeUninit<ze_owned_fifo_handler_miss_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_internal_fifo_handler_miss_check(
+    this_: &ze_owned_fifo_handler_miss_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+extern "C" fn __ze_handler_miss_send(miss: &ze_miss_t, context: *mut c_void) {
+    unsafe {
+        let f = (context as *mut Callback<ze_miss_t>)
+            .as_mut()
+            .unwrap_unchecked();
+        f.call(*miss);
+    }
+}
+
+extern "C" fn __ze_handler_miss_drop(context: *mut c_void) {
+    unsafe {
+        let f = Box::from_raw(context as *mut Callback<ze_miss_t>);
+        std::mem::drop(f);
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the fifo channel
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn ze_fifo_channel_miss_new(
+    callback: &mut MaybeUninit<ze_owned_closure_miss_t>,
+    handler: &mut MaybeUninit<ze_owned_fifo_handler_miss_t>,
+    capacity: usize,
+) {
+    let fifo = handlers::FifoChannel::new(capacity);
+    let (cb, h) = fifo.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(ze_owned_closure_miss_t {
+        _call: Some(__ze_handler_miss_send),
+        _context: cb_ptr,
+        _drop: Some(__ze_handler_miss_drop),
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn ze_fifo_handler_miss_loan(
+    this: &ze_owned_fifo_handler_miss_t,
+) -> &ze_loaned_fifo_handler_miss_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns miss from the fifo buffer. If there are no more pending misses will block until next miss is received, or until
+/// the channel is dropped (normally when the sample miss listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn ze_fifo_handler_miss_recv(
+    this: &ze_loaned_fifo_handler_miss_t,
+    miss: &mut MaybeUninit<ze_miss_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(q) => {
+            miss.write(q);
+            result::Z_OK
+        }
+        Err(_) => {
+            miss.write(ze_miss_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns miss from the fifo buffer.
+/// If there are no more pending misses will return immediately (with miss zero-initialized).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized),
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the miss will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn ze_fifo_handler_miss_try_recv(
+    this: &ze_loaned_fifo_handler_miss_t,
+    miss: &mut MaybeUninit<ze_miss_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(Some(q)) => {
+            miss.write(q);
+            result::Z_OK
+        }
+        Ok(None) => {
+            miss.write(ze_miss_t::default());
+            result::Z_CHANNEL_NODATA
+        }
+        Err(_) => {
+            miss.write(ze_miss_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+pub use crate::opaque_types::{
+    ze_loaned_ring_handler_miss_t, ze_moved_ring_handler_miss_t, ze_owned_ring_handler_miss_t,
+};
+decl_c_type!(
+    owned(
+        ze_owned_ring_handler_miss_t,
+        option RingChannelHandler<ze_miss_t>,
+    ),
+    loaned(ze_loaned_ring_handler_miss_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the handler and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_ring_handler_miss_drop(this_: &mut ze_moved_ring_handler_miss_t) {
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a handler in gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_internal_ring_handler_miss_null(
+    this: &mut MaybeUninit<ze_owned_ring_handler_miss_t>,
+) {
+    this.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if handler is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn ze_internal_ring_handler_miss_check(
+    this_: &ze_owned_ring_handler_miss_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs send and recieve ends of the ring channel
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn ze_ring_channel_miss_new(
+    callback: &mut MaybeUninit<ze_owned_closure_miss_t>,
+    handler: &mut MaybeUninit<ze_owned_ring_handler_miss_t>,
+    capacity: usize,
+) {
+    let ring = handlers::RingChannel::new(capacity);
+    let (cb, h) = ring.into_handler();
+    let cb_ptr = Box::into_raw(Box::new(cb)) as *mut libc::c_void;
+    handler.as_rust_type_mut_uninit().write(Some(h));
+    callback.write(ze_owned_closure_miss_t {
+        _call: Some(__ze_handler_miss_send),
+        _context: cb_ptr,
+        _drop: Some(__ze_handler_miss_drop),
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows handler.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn ze_ring_handler_miss_loan(
+    this: &ze_owned_ring_handler_miss_t,
+) -> &ze_loaned_ring_handler_miss_t {
+    this.as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns miss from the ring buffer. If there are no more pending misses will block until next miss is received, or until
+/// the channel is dropped (normally when the sample miss listener is undeclared).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn ze_ring_handler_miss_recv(
+    this: &ze_loaned_ring_handler_miss_t,
+    miss: &mut MaybeUninit<ze_miss_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().recv() {
+        Ok(q) => {
+            miss.write(q);
+            result::Z_OK
+        }
+        Err(_) => {
+            miss.write(ze_miss_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns miss from the ring buffer. If there are no more pending misses will return immediately (with miss zero-initialized).
+/// @return 0 in case of success, `Z_CHANNEL_DISCONNECTED` if channel was dropped (the miss will be zero-initialized),
+/// `Z_CHANNEL_NODATA` if the channel is still alive, but its buffer is empty (the miss will be zero-initialized).
+#[no_mangle]
+pub extern "C" fn ze_ring_handler_miss_try_recv(
+    this: &ze_loaned_ring_handler_miss_t,
+    miss: &mut MaybeUninit<ze_miss_t>,
+) -> z_result_t {
+    match this.as_rust_type_ref().try_recv() {
+        Ok(q) => {
+            let r = if q.is_some() {
+                result::Z_OK
+            } else {
+                result::Z_CHANNEL_NODATA
+            };
+            miss.write(q.unwrap_or_default());
+            r
+        }
+        Err(_) => {
+            miss.write(ze_miss_t::default());
+            result::Z_CHANNEL_DISCONNECTED
+        }
+    }
+}
@@ -25,9 +25,9 @@ use crate::{
 /// A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
 #[repr(C)]
 pub struct ze_owned_closure_miss_t {
-    _context: *mut c_void,
-    _call: Option<extern "C" fn(matching_status: &ze_miss_t, context: *mut c_void)>,
-    _drop: Option<extern "C" fn(context: *mut c_void)>,
+    pub(crate) _context: *mut c_void,
+    pub(crate) _call: Option<extern "C" fn(matching_status: &ze_miss_t, context: *mut c_void)>,
+    pub(crate) _drop: Option<extern "C" fn(context: *mut c_void)>,
 }
 
 /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
@@ -20,6 +20,11 @@ mod query_closure;
 pub use reply_closure::*;
 mod reply_closure;
 
+#[cfg(feature = "unstable")]
+pub use reply_consolidation_closure::*;
+#[cfg(feature = "unstable")]
+mod reply_consolidation_closure;
+
 pub use zenohid_closure::*;
 mod zenohid_closure;
 
@@ -41,6 +46,11 @@ mod log_closure;
 pub use matching_status_closure::*;
 mod matching_status_closure;
 
+#[cfg(feature = "unstable")]
+pub use matching_status_channel::*;
+#[cfg(feature = "unstable")]
+mod matching_status_channel;
+
 #[cfg(feature = "unstable")]
 pub use transport_closure::*;
 #[cfg(feature = "unstable")]
@@ -56,6 +66,11 @@ pub use miss_closure::*;
 #[cfg(feature = "unstable")]
 mod miss_closure;
 
+#[cfg(feature = "unstable")]
+pub use miss_channel::*;
+#[cfg(feature = "unstable")]
+mod miss_channel;
+
 #[cfg(feature = "unstable")]
 pub use transport_event_closure::*;
 #[cfg(feature = "unstable")]
@@ -65,3 +80,8 @@ mod transport_event_closure;
 pub use link_event_closure::*;
 #[cfg(feature = "unstable")]
 mod link_event_closure;
+
+#[cfg(feature = "unstable")]
+pub use leadership_status_closure::*;
+#[cfg(feature = "unstable")]
+mod leadership_status_closure;
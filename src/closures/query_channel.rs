@@ -15,10 +15,7 @@
 use std::mem::MaybeUninit;
 
 use libc::c_void;
-use zenoh::{
-    handlers::{self, Callback, FifoChannelHandler, IntoHandler, RingChannelHandler},
-    query::Query,
-};
+use zenoh::handlers::{self, Callback, FifoChannelHandler, IntoHandler, RingChannelHandler};
 
 pub use crate::opaque_types::{
     z_loaned_fifo_handler_query_t, z_moved_fifo_handler_query_t, z_owned_fifo_handler_query_t,
@@ -26,10 +23,10 @@ pub use crate::opaque_types::{
 use crate::{
     result::{self, z_result_t},
     transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
-    z_loaned_query_t, z_owned_closure_query_t, z_owned_query_t,
+    z_loaned_query_t, z_owned_closure_query_t, z_owned_query_t, CQuery,
 };
 decl_c_type!(
-    owned(z_owned_fifo_handler_query_t, option FifoChannelHandler<Query> ),
+    owned(z_owned_fifo_handler_query_t, option FifoChannelHandler<CQuery> ),
     loaned(z_loaned_fifo_handler_query_t),
 );
 
@@ -57,17 +54,17 @@ pub extern "C" fn z_internal_fifo_handler_query_check(
 
 extern "C" fn __z_handler_query_send(query: &mut z_loaned_query_t, context: *mut c_void) {
     unsafe {
-        let f = (context as *mut Callback<Query>)
+        let f = (context as *mut Callback<CQuery>)
             .as_mut()
             .unwrap_unchecked();
-        let owned_ref: &mut Option<Query> = std::mem::transmute(query);
+        let owned_ref: &mut Option<CQuery> = std::mem::transmute(query);
         f.call(std::mem::take(owned_ref).unwrap_unchecked());
     }
 }
 
 extern "C" fn __z_handler_query_drop(context: *mut c_void) {
     unsafe {
-        let f = Box::from_raw(context as *mut Callback<Query>);
+        let f = Box::from_raw(context as *mut Callback<CQuery>);
         std::mem::drop(f);
     }
 }
@@ -154,7 +151,7 @@ pub use crate::opaque_types::{
 decl_c_type!(
     owned(
         z_owned_ring_handler_query_t,
-        option RingChannelHandler<Query>,
+        option RingChannelHandler<CQuery>,
     ),
     loaned(z_loaned_ring_handler_query_t),
 );
@@ -107,6 +107,12 @@ pub extern "C" fn z_closure_query_drop(closure_: &mut z_moved_closure_query_t) {
     let _ = closure_.take_rust_type();
 }
 
+impl From<z_owned_closure_query_t> for z_moved_closure_query_t {
+    fn from(this: z_owned_closure_query_t) -> Self {
+        z_moved_closure_query_t { _this: this }
+    }
+}
+
 impl<F: Fn(&mut z_loaned_query_t)> From<F> for z_owned_closure_query_t {
     fn from(f: F) -> Self {
         let this = Box::into_raw(Box::new(f)) as _;
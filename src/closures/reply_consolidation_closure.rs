@@ -0,0 +1,181 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+use std::mem::MaybeUninit;
+
+use libc::c_void;
+
+use crate::{
+    transmute::{LoanedCTypeRef, OwnedCTypeRef, TakeRustType},
+    z_loaned_reply_t,
+};
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A reply-consolidation closure, resolving conflicts between replies on the same key expression.
+///
+/// It is called with the reply currently kept for a key expression and a newly received candidate reply on the same key expression.
+/// Setting `replace` to ``true`` keeps the candidate instead of the current reply. The closure may also modify `kept`, e.g. to merge
+/// the candidate into it.
+///
+/// A closure is a structure that contains all the elements for stateful, memory-leak-free callbacks.
+#[repr(C)]
+pub struct z_owned_closure_reply_consolidation_t {
+    _context: *mut c_void,
+    _call: Option<
+        extern "C" fn(
+            kept: &mut z_loaned_reply_t,
+            candidate: &mut z_loaned_reply_t,
+            replace: &mut bool,
+            context: *mut c_void,
+        ),
+    >,
+    _drop: Option<extern "C" fn(context: *mut c_void)>,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Loaned closure.
+#[repr(C)]
+pub struct z_loaned_closure_reply_consolidation_t {
+    _0: usize,
+    _1: usize,
+    _2: usize,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Moved closure.
+#[repr(C)]
+pub struct z_moved_closure_reply_consolidation_t {
+    _this: z_owned_closure_reply_consolidation_t,
+}
+
+decl_c_type!(
+    owned(z_owned_closure_reply_consolidation_t),
+    loaned(z_loaned_closure_reply_consolidation_t),
+    moved(z_moved_closure_reply_consolidation_t),
+);
+
+impl Default for z_owned_closure_reply_consolidation_t {
+    fn default() -> Self {
+        z_owned_closure_reply_consolidation_t {
+            _context: std::ptr::null_mut(),
+            _call: None,
+            _drop: None,
+        }
+    }
+}
+
+impl z_owned_closure_reply_consolidation_t {
+    pub fn is_empty(&self) -> bool {
+        self._call.is_none() && self._drop.is_none() && self._context.is_null()
+    }
+}
+unsafe impl Send for z_owned_closure_reply_consolidation_t {}
+unsafe impl Sync for z_owned_closure_reply_consolidation_t {}
+impl Drop for z_owned_closure_reply_consolidation_t {
+    fn drop(&mut self) {
+        if let Some(drop) = self._drop {
+            drop(self._context)
+        }
+    }
+}
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a closure in its gravestone state.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_internal_closure_reply_consolidation_null(
+    this: &mut MaybeUninit<z_owned_closure_reply_consolidation_t>,
+) {
+    this.write(z_owned_closure_reply_consolidation_t::default());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if closure is valid, ``false`` if it is in gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_closure_reply_consolidation_check(
+    this: &z_owned_closure_reply_consolidation_t,
+) -> bool {
+    !this.is_empty()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Calls the closure. Calling an uninitialized closure is a no-op.
+///
+/// @return ``true`` if the candidate should replace the kept reply, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_closure_reply_consolidation_call(
+    closure: &z_loaned_closure_reply_consolidation_t,
+    kept: &mut z_loaned_reply_t,
+    candidate: &mut z_loaned_reply_t,
+) -> bool {
+    let closure = closure.as_owned_c_type_ref();
+    let mut replace = false;
+    match closure._call {
+        Some(call) => call(kept, candidate, &mut replace, closure._context),
+        None => {
+            crate::report_error!("Attempted to call an uninitialized closure!");
+        }
+    }
+    replace
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Drops the closure, resetting it to its gravestone state. Dropping an uninitialized closure is a no-op.
+#[no_mangle]
+pub extern "C" fn z_closure_reply_consolidation_drop(
+    closure_: &mut z_moved_closure_reply_consolidation_t,
+) {
+    let _ = closure_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows closure.
+#[no_mangle]
+pub extern "C" fn z_closure_reply_consolidation_loan(
+    closure: &z_owned_closure_reply_consolidation_t,
+) -> &z_loaned_closure_reply_consolidation_t {
+    closure.as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+///
+/// Closures are not guaranteed not to be called concurrently.
+///
+/// It is guaranteed that:
+///   - `call` will never be called once `drop` has started.
+///   - `drop` will only be called **once**, and **after every** `call` has ended.
+///   - The two previous guarantees imply that `call` and `drop` are never called concurrently.
+///
+/// @brief Constructs closure.
+/// @param this_: uninitialized memory location where new closure will be constructed.
+/// @param call: a closure body.
+/// @param drop: an optional function to be called once on closure drop.
+/// @param context: closure context.
+#[no_mangle]
+pub extern "C" fn z_closure_reply_consolidation(
+    this: &mut MaybeUninit<z_owned_closure_reply_consolidation_t>,
+    call: Option<
+        extern "C" fn(
+            kept: &mut z_loaned_reply_t,
+            candidate: &mut z_loaned_reply_t,
+            replace: &mut bool,
+            context: *mut c_void,
+        ),
+    >,
+    drop: Option<extern "C" fn(context: *mut c_void)>,
+    context: *mut c_void,
+) {
+    this.write(z_owned_closure_reply_consolidation_t {
+        _context: context,
+        _call: call,
+        _drop: drop,
+    });
+}
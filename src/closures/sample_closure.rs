@@ -109,6 +109,12 @@ pub extern "C" fn z_closure_sample_drop(closure_: &mut z_moved_closure_sample_t)
     let _ = closure_.take_rust_type();
 }
 
+impl From<z_owned_closure_sample_t> for z_moved_closure_sample_t {
+    fn from(this: z_owned_closure_sample_t) -> Self {
+        z_moved_closure_sample_t { _this: this }
+    }
+}
+
 impl<F: Fn(&mut z_loaned_sample_t)> From<F> for z_owned_closure_sample_t {
     fn from(f: F) -> Self {
         let this = Box::into_raw(Box::new(f)) as _;
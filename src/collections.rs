@@ -13,6 +13,8 @@
 //
 
 use core::ffi::c_void;
+#[cfg(feature = "unstable")]
+use std::collections::HashMap;
 use std::{
     hash::Hash,
     mem::MaybeUninit,
@@ -573,6 +575,12 @@ impl From<String> for CStringOwned {
     }
 }
 
+impl From<String> for CStringInner {
+    fn from(value: String) -> Self {
+        CStringInner(value.into_bytes().into())
+    }
+}
+
 impl From<CStringInner> for CSlice {
     fn from(value: CStringInner) -> Self {
         value.0
@@ -938,3 +946,362 @@ pub extern "C" fn z_string_array_clone(
     dst.as_rust_type_mut_uninit()
         .write(this_.as_rust_type_ref().clone());
 }
+
+#[cfg(feature = "unstable")]
+pub use crate::opaque_types::{
+    z_loaned_slice_array_t, z_loaned_slice_map_t, z_moved_slice_array_t, z_moved_slice_map_t,
+    z_owned_slice_array_t, z_owned_slice_map_t,
+};
+#[cfg(feature = "unstable")]
+pub type ZSliceVector = Vec<CSlice>;
+#[cfg(feature = "unstable")]
+decl_c_type!(
+    owned(z_owned_slice_array_t, ZSliceVector),
+    loaned(z_loaned_slice_array_t),
+);
+
+#[cfg(feature = "unstable")]
+impl Gravestone for ZSliceVector {
+    fn gravestone() -> Self {
+        Vec::new()
+    }
+    fn is_gravestone(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a new empty slice array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_new(this_: &mut MaybeUninit<z_owned_slice_array_t>) {
+    this_
+        .as_rust_type_mut_uninit()
+        .write(ZSliceVector::gravestone());
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs slice array in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_slice_array_null(this_: &mut MaybeUninit<z_owned_slice_array_t>) {
+    z_slice_array_new(this_)
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the slice array is valid, ``false`` if it is in a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_slice_array_check(this_: &z_owned_slice_array_t) -> bool {
+    !this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Destroys the slice array, resetting it to its gravestone value.
+#[no_mangle]
+pub extern "C" fn z_slice_array_drop(this_: &mut z_moved_slice_array_t) {
+    let _ = this_.take_rust_type();
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows slice array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_loan(this_: &z_owned_slice_array_t) -> &z_loaned_slice_array_t {
+    this_.as_rust_type_ref().as_loaned_c_type_ref()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows slice array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_loan_mut(
+    this_: &mut z_owned_slice_array_t,
+) -> &mut z_loaned_slice_array_t {
+    this_.as_rust_type_mut().as_loaned_c_type_mut()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return number of elements in the array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_len(this_: &z_loaned_slice_array_t) -> usize {
+    this_.as_rust_type_ref().len()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the array is empty, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_slice_array_is_empty(this_: &z_loaned_slice_array_t) -> bool {
+    this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return the value at the position of index in the slice array.
+///
+/// Will return `NULL` if the index is out of bounds.
+#[no_mangle]
+pub extern "C" fn z_slice_array_get(
+    this_: &z_loaned_slice_array_t,
+    index: usize,
+) -> Option<&z_loaned_slice_t> {
+    this_
+        .as_rust_type_ref()
+        .get(index)
+        .map(|s| s.as_loaned_c_type_ref())
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Appends specified value to the end of the slice array by copying.
+///
+/// @return the new length of the array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_push_by_copy(
+    this_: &mut z_loaned_slice_array_t,
+    value: &z_loaned_slice_t,
+) -> usize {
+    let this = this_.as_rust_type_mut();
+    this.push(value.as_rust_type_ref().clone_to_owned().into());
+
+    this.len()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Appends specified value to the end of the slice array by alias.
+///
+/// The value should outlive the array.
+/// @return the new length of the array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_push_by_alias(
+    this_: &mut z_loaned_slice_array_t,
+    value: &z_loaned_slice_t,
+) -> usize {
+    let this = this_.as_rust_type_mut();
+    this.push(value.as_rust_type_ref().clone_to_borrowed());
+
+    this.len()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an owned copy of a slice array.
+///
+/// All elements of the copy are owned, including those aliased by the original array.
+#[no_mangle]
+pub extern "C" fn z_slice_array_clone(
+    dst: &mut MaybeUninit<z_owned_slice_array_t>,
+    this_: &z_loaned_slice_array_t,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(this_.as_rust_type_ref().clone());
+}
+
+#[cfg(feature = "unstable")]
+pub type ZSliceMap = HashMap<CSlice, CSlice>;
+#[cfg(feature = "unstable")]
+decl_c_type!(
+    owned(z_owned_slice_map_t, ZSliceMap),
+    loaned(z_loaned_slice_map_t),
+);
+
+#[cfg(feature = "unstable")]
+impl Gravestone for ZSliceMap {
+    fn gravestone() -> Self {
+        HashMap::new()
+    }
+    fn is_gravestone(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a new empty slice map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_new(this_: &mut MaybeUninit<z_owned_slice_map_t>) {
+    this_
+        .as_rust_type_mut_uninit()
+        .write(ZSliceMap::gravestone());
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs slice map in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_slice_map_null(this_: &mut MaybeUninit<z_owned_slice_map_t>) {
+    z_slice_map_new(this_)
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the map is not in its gravestone state, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_slice_map_check(this_: &z_owned_slice_map_t) -> bool {
+    !this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Destroys the map, resetting it to its gravestone value.
+#[no_mangle]
+pub extern "C" fn z_slice_map_drop(this_: &mut z_moved_slice_map_t) {
+    let _ = this_.take_rust_type();
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows slice map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_loan(this_: &z_owned_slice_map_t) -> &z_loaned_slice_map_t {
+    this_.as_rust_type_ref().as_loaned_c_type_ref()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows slice map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_loan_mut(
+    this_: &mut z_owned_slice_map_t,
+) -> &mut z_loaned_slice_map_t {
+    this_.as_rust_type_mut().as_loaned_c_type_mut()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return number of key-value pairs in the map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_len(this_: &z_loaned_slice_map_t) -> usize {
+    this_.as_rust_type_ref().len()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the map is empty, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_slice_map_is_empty(this_: &z_loaned_slice_map_t) -> bool {
+    this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the value associated with `key`.
+///
+/// Will return `NULL` if the key is not present in the map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_get<'a>(
+    this_: &'a z_loaned_slice_map_t,
+    key: &z_loaned_slice_t,
+) -> Option<&'a z_loaned_slice_t> {
+    this_
+        .as_rust_type_ref()
+        .get(key.as_rust_type_ref())
+        .map(|v| v.as_loaned_c_type_ref())
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Associates a key with a value in the map by copying them.
+///
+/// @return ``true`` if the key was already present in the map and its value has been replaced, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_slice_map_insert_by_copy(
+    this_: &mut z_loaned_slice_map_t,
+    key: &z_loaned_slice_t,
+    value: &z_loaned_slice_t,
+) -> bool {
+    this_
+        .as_rust_type_mut()
+        .insert(
+            key.as_rust_type_ref().clone_to_owned().into(),
+            value.as_rust_type_ref().clone_to_owned().into(),
+        )
+        .is_some()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Associates a key with a value in the map by aliasing them.
+///
+/// Both key and value should outlive the map.
+/// @return ``true`` if the key was already present in the map and its value has been replaced, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_slice_map_insert_by_alias(
+    this_: &mut z_loaned_slice_map_t,
+    key: &z_loaned_slice_t,
+    value: &z_loaned_slice_t,
+) -> bool {
+    this_
+        .as_rust_type_mut()
+        .insert(
+            key.as_rust_type_ref().clone_to_borrowed(),
+            value.as_rust_type_ref().clone_to_borrowed(),
+        )
+        .is_some()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Removes the key and its associated value from the map.
+///
+/// @return ``true`` if the key was present in the map, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_slice_map_remove(
+    this_: &mut z_loaned_slice_map_t,
+    key: &z_loaned_slice_t,
+) -> bool {
+    this_
+        .as_rust_type_mut()
+        .remove(key.as_rust_type_ref())
+        .is_some()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Function called for each key-value pair of the map by `z_slice_map_iterate()`.
+///
+/// Should return ``true`` to stop the iteration, ``false`` to continue.
+pub type z_slice_map_iter_body_t =
+    extern "C" fn(key: &z_loaned_slice_t, value: &z_loaned_slice_t, context: *mut c_void) -> bool;
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Iterates over key-value pairs of the map in unspecified order.
+///
+/// @param this_: Slice map to iterate over.
+/// @param body: Function called for each key-value pair. Iteration stops as soon as it returns ``true``.
+/// @param context: An arbitrary pointer passed to `body` on each call.
+#[no_mangle]
+pub extern "C" fn z_slice_map_iterate(
+    this_: &z_loaned_slice_map_t,
+    body: z_slice_map_iter_body_t,
+    context: *mut c_void,
+) {
+    for (key, value) in this_.as_rust_type_ref() {
+        if body(
+            key.as_loaned_c_type_ref(),
+            value.as_loaned_c_type_ref(),
+            context,
+        ) {
+            break;
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an owned copy of a slice map.
+///
+/// All keys and values of the copy are owned, including those aliased by the original map.
+#[no_mangle]
+pub extern "C" fn z_slice_map_clone(
+    dst: &mut MaybeUninit<z_owned_slice_map_t>,
+    this_: &z_loaned_slice_map_t,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(this_.as_rust_type_ref().clone());
+}
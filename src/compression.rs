@@ -0,0 +1,291 @@
+//
+// Copyright (c) 2024 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+//
+
+use std::{borrow::Cow, io::Read, mem::MaybeUninit, str::FromStr};
+
+use unwrap_infallible::UnwrapInfallible;
+use zenoh::bytes::{Encoding, ZBytes};
+
+use crate::{
+    result::{self, z_result_t},
+    transmute::{RustTypeRef, RustTypeRefUninit},
+    z_loaned_bytes_t, z_loaned_encoding_t, z_owned_bytes_t, z_owned_encoding_t,
+};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Payload compression algorithm.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_compression_algorithm_t {
+    /// LZ4 block compression, optimized for speed.[Default]
+    LZ4 = 0,
+    /// Zstandard compression, optimized for compression ratio.
+    ZSTD = 1,
+}
+
+impl z_compression_algorithm_t {
+    fn schema_tag(self) -> &'static str {
+        match self {
+            z_compression_algorithm_t::LZ4 => "lz4",
+            z_compression_algorithm_t::ZSTD => "zstd",
+        }
+    }
+
+    fn from_schema_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "lz4" => Some(z_compression_algorithm_t::LZ4),
+            "zstd" => Some(z_compression_algorithm_t::ZSTD),
+            _ => None,
+        }
+    }
+
+    fn compress(self, data: &[u8], level: i32) -> Result<Vec<u8>, String> {
+        match self {
+            z_compression_algorithm_t::LZ4 => Ok(lz4_flex::compress_prepend_size(data)),
+            z_compression_algorithm_t::ZSTD => {
+                zstd::bulk::compress(data, level).map_err(|e| e.to_string())
+            }
+        }
+    }
+
+    fn decompress(self, data: &[u8], max_size: usize) -> Result<Vec<u8>, String> {
+        match self {
+            z_compression_algorithm_t::LZ4 => {
+                let size = lz4_flex::block::uncompressed_size(data)
+                    .map_err(|e| e.to_string())?
+                    .0;
+                if size > max_size {
+                    return Err(format!(
+                        "decompressed size {size} exceeds the maximum of {max_size} bytes"
+                    ));
+                }
+                lz4_flex::decompress_size_prepended(data).map_err(|e| e.to_string())
+            }
+            z_compression_algorithm_t::ZSTD => {
+                let decoder = zstd::stream::read::Decoder::new(data).map_err(|e| e.to_string())?;
+                let mut decompressed = Vec::new();
+                decoder
+                    .take(max_size as u64 + 1)
+                    .read_to_end(&mut decompressed)
+                    .map_err(|e| e.to_string())?;
+                if decompressed.len() > max_size {
+                    return Err(format!(
+                        "decompressed size exceeds the maximum of {max_size} bytes"
+                    ));
+                }
+                Ok(decompressed)
+            }
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to `z_bytes_compress()`.
+#[repr(C)]
+pub struct z_bytes_compress_options_t {
+    /// Compression algorithm to use.
+    pub algorithm: z_compression_algorithm_t,
+    /// Compression level, `0` selects the algorithm default. Ignored by `Z_COMPRESSION_ALGORITHM_LZ4`.
+    pub level: i32,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_bytes_compress_options_t`.
+#[no_mangle]
+pub extern "C" fn z_bytes_compress_options_default(
+    this_: &mut MaybeUninit<z_bytes_compress_options_t>,
+) {
+    this_.write(z_bytes_compress_options_t {
+        algorithm: z_compression_algorithm_t::LZ4,
+        level: 0,
+    });
+}
+
+/// Default maximum size of decompressed payload data: 64 MiB.
+const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 64 * 1024 * 1024;
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to `z_bytes_decompress()`.
+#[repr(C)]
+pub struct z_bytes_decompress_options_t {
+    /// Maximum size of the decompressed data in bytes, decompression fails if the data would exceed it.
+    /// Protects against payloads that expand to an excessive size. Defaults to 64 MiB.
+    pub max_size: usize,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_bytes_decompress_options_t`.
+#[no_mangle]
+pub extern "C" fn z_bytes_decompress_options_default(
+    this_: &mut MaybeUninit<z_bytes_decompress_options_t>,
+) {
+    this_.write(z_bytes_decompress_options_t {
+        max_size: DEFAULT_MAX_DECOMPRESSED_SIZE,
+    });
+}
+
+/// Splits encoding into its string prefix and its (optional) schema.
+fn split_encoding(encoding: &Encoding) -> (String, Option<String>) {
+    let s: Cow<'static, str> = encoding.into();
+    match s.split_once(';') {
+        Some((prefix, schema)) => (prefix.to_string(), Some(schema.to_string())),
+        None => (s.into_owned(), None),
+    }
+}
+
+/// Returns the compression algorithm and the original schema, if the encoding is tagged as compressed.
+fn parse_compressed_encoding(
+    encoding: &Encoding,
+) -> Option<(z_compression_algorithm_t, String, Option<String>)> {
+    let (prefix, schema) = split_encoding(encoding);
+    let schema = schema?;
+    let (tag, original) = match schema.split_once(':') {
+        Some((tag, original)) => (tag, Some(original.to_string())),
+        None => (schema.as_str(), None),
+    };
+    let algorithm = z_compression_algorithm_t::from_schema_tag(tag)?;
+    Some((algorithm, prefix, original))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Compresses payload data.
+///
+/// The resulting encoding keeps the original encoding and replaces its schema with the algorithm tag (`lz4` or `zstd`),
+/// followed by `:` and the original schema if there was one. E.g. `text/plain;utf-8` compressed with lz4 becomes `text/plain;lz4:utf-8`.
+/// Use `z_bytes_decompress()` to restore both the data and the original encoding.
+///
+/// @param dst: An uninitialized memory location where compressed payload will be constructed.
+/// @param dst_encoding: An uninitialized memory location where encoding of the compressed payload will be constructed.
+/// @param this_: Data to compress.
+/// @param encoding: Encoding of the data to compress. If NULL, `z_encoding_zenoh_bytes()` is assumed.
+/// @param options: Compression options. If NULL, default options are used.
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_bytes_compress(
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+    dst_encoding: &mut MaybeUninit<z_owned_encoding_t>,
+    this_: &z_loaned_bytes_t,
+    encoding: Option<&z_loaned_encoding_t>,
+    options: Option<&z_bytes_compress_options_t>,
+) -> z_result_t {
+    let dst = dst.as_rust_type_mut_uninit();
+    let dst_encoding = dst_encoding.as_rust_type_mut_uninit();
+    let encoding = encoding
+        .map(|e| e.as_rust_type_ref())
+        .unwrap_or(&Encoding::ZENOH_BYTES);
+    let (algorithm, level) = options
+        .map(|o| (o.algorithm, o.level))
+        .unwrap_or((z_compression_algorithm_t::LZ4, 0));
+    if parse_compressed_encoding(encoding).is_some() {
+        crate::report_error!("Payload is already compressed: {}", encoding);
+        dst.write(ZBytes::default());
+        dst_encoding.write(Encoding::default());
+        return result::Z_EINVAL;
+    }
+    match algorithm.compress(&this_.as_rust_type_ref().to_bytes(), level) {
+        Ok(compressed) => {
+            let (prefix, schema) = split_encoding(encoding);
+            let tagged = match schema {
+                Some(schema) => format!("{}:{}", algorithm.schema_tag(), schema),
+                None => algorithm.schema_tag().to_string(),
+            };
+            dst.write(ZBytes::from(compressed));
+            dst_encoding.write(
+                Encoding::from_str(&prefix)
+                    .unwrap_infallible()
+                    .with_schema(tagged),
+            );
+            result::Z_OK
+        }
+        Err(e) => {
+            crate::report_error!("Failed to compress payload: {}", e);
+            dst.write(ZBytes::default());
+            dst_encoding.write(Encoding::default());
+            result::Z_EINVAL
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Decompresses payload data previously compressed with `z_bytes_compress()`.
+///
+/// @param dst: An uninitialized memory location where decompressed payload will be constructed.
+/// @param dst_encoding: An uninitialized memory location where the original encoding of the payload will be constructed.
+/// @param this_: Data to decompress.
+/// @param encoding: Encoding of the compressed data, as produced by `z_bytes_compress()`.
+/// @param options: Decompression options. If NULL, default options are used.
+/// @return 0 in case of success, negative error code otherwise. `Z_EINVAL` is returned if encoding does not correspond to
+/// a compressed payload, if data is corrupted or if decompressed data would exceed the maximum size.
+#[no_mangle]
+pub extern "C" fn z_bytes_decompress(
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+    dst_encoding: &mut MaybeUninit<z_owned_encoding_t>,
+    this_: &z_loaned_bytes_t,
+    encoding: &z_loaned_encoding_t,
+    options: Option<&z_bytes_decompress_options_t>,
+) -> z_result_t {
+    let dst = dst.as_rust_type_mut_uninit();
+    let dst_encoding = dst_encoding.as_rust_type_mut_uninit();
+    let encoding = encoding.as_rust_type_ref();
+    let Some((algorithm, prefix, schema)) = parse_compressed_encoding(encoding) else {
+        crate::report_error!(
+            "Encoding does not correspond to a compressed payload: {}",
+            encoding
+        );
+        dst.write(ZBytes::default());
+        dst_encoding.write(Encoding::default());
+        return result::Z_EINVAL;
+    };
+    let max_size = options.map_or(DEFAULT_MAX_DECOMPRESSED_SIZE, |o| o.max_size);
+    match algorithm.decompress(&this_.as_rust_type_ref().to_bytes(), max_size) {
+        Ok(decompressed) => {
+            let original = Encoding::from_str(&prefix).unwrap_infallible();
+            dst.write(ZBytes::from(decompressed));
+            dst_encoding.write(match schema {
+                Some(schema) => original.with_schema(schema),
+                None => original,
+            });
+            result::Z_OK
+        }
+        Err(e) => {
+            crate::report_error!("Failed to decompress payload: {}", e);
+            dst.write(ZBytes::default());
+            dst_encoding.write(Encoding::default());
+            result::Z_EINVAL
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Checks if encoding corresponds to a payload compressed with `z_bytes_compress()`.
+///
+/// @param this_: Encoding to check.
+/// @param algorithm: A memory location where compression algorithm will be written, if encoding corresponds to a compressed payload. Can be NULL.
+/// @return ``true`` if encoding corresponds to a compressed payload, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_encoding_is_compressed(
+    this_: &z_loaned_encoding_t,
+    algorithm: Option<&mut MaybeUninit<z_compression_algorithm_t>>,
+) -> bool {
+    match parse_compressed_encoding(this_.as_rust_type_ref()) {
+        Some((a, _, _)) => {
+            if let Some(algorithm) = algorithm {
+                algorithm.write(a);
+            }
+            true
+        }
+        None => false,
+    }
+}
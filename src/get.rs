@@ -12,20 +12,39 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
+#[cfg(feature = "unstable")]
+use std::{collections::HashMap, ops::Deref, sync::Mutex};
 use std::{
     mem::MaybeUninit,
     ptr::{null, null_mut},
 };
 
 use libc::c_char;
+#[cfg(feature = "unstable")]
+use zenoh::cancellation::CancellationToken;
 use zenoh::{
     qos::{CongestionControl, Priority},
-    query::{ConsolidationMode, QueryConsolidation, QueryTarget, Reply, ReplyError, Selector},
+    query::{
+        ConsolidationMode, Parameters, QueryConsolidation, QueryTarget, Reply, ReplyError, Selector,
+    },
     session::SessionClosedError,
     Wait,
 };
 
+#[cfg(feature = "unstable")]
+pub use crate::opaque_types::{
+    z_loaned_reply_array_t, z_moved_reply_array_t, z_owned_reply_array_t,
+};
 pub use crate::opaque_types::{z_loaned_reply_err_t, z_moved_reply_err_t, z_owned_reply_err_t};
+#[cfg(feature = "unstable")]
+use crate::{
+    cancellation_token::{CancellationForward, FirstReplyRace},
+    reply_stream::{ReplyReassembler, REASSEMBLE_PARAMETER},
+    transmute::IntoCType,
+    z_closure_reply_consolidation_call, z_closure_reply_consolidation_loan, z_entity_global_id_t,
+    z_loaned_parameters_t, z_moved_cancellation_token_t, z_moved_closure_reply_consolidation_t,
+    z_owned_closure_reply_consolidation_t, z_owned_closure_reply_t, z_source_info_t,
+};
 use crate::{
     result::{self, Z_EINVAL},
     strlen_or_zero,
@@ -36,10 +55,6 @@ use crate::{
     z_moved_encoding_t, z_priority_t, z_query_target_t, z_reply_keyexpr_default, z_reply_keyexpr_t,
     CStringView,
 };
-#[cfg(feature = "unstable")]
-use crate::{
-    transmute::IntoCType, z_entity_global_id_t, z_moved_cancellation_token_t, z_source_info_t,
-};
 decl_c_type!(
     owned(z_owned_reply_err_t, ReplyError),
     loaned(z_loaned_reply_err_t, ReplyError),
@@ -241,6 +256,29 @@ pub struct z_get_options_t {
     ///
     /// Cancellation token to interrupt the query.
     pub cancellation_token: Option<&'static mut z_moved_cancellation_token_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, replies sent by reply streams (see `z_reply_stream_new()`) are reassembled, and each stream is handed over
+    /// to the callback as a single reply carrying the whole payload once its last chunk is received. Other replies are handed over as is.
+    /// Streams which are incomplete when the query ends are discarded, and aborted streams are handed over as their
+    /// end-of-stream marker. Setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`.
+    pub reassemble_chunks: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// An optional closure resolving conflicts between replies on the same key expression, replacing the built-in consolidation
+    /// strategies: setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`.
+    /// Successful replies are held until the query ends, and the reply kept for each key expression is then handed over to the callback,
+    /// in the order of reception of the first reply on each key expression. Error replies are handed over to the callback as soon as they are received.
+    pub consolidation_hook: Option<&'static mut z_moved_closure_reply_consolidation_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, the query is interrupted as soon as its first successful reply is handed over to the callback, and no later reply reaches it.
+    /// Error replies received before are handed over as usual. Setting it overrides `consolidation` with `Z_CONSOLIDATION_MODE_NONE`,
+    /// and can not be combined with `consolidation_hook`. The query can still be interrupted earlier with `cancellation_token`.
+    pub first_reply_only: bool,
 }
 
 impl z_get_options_t {
@@ -258,6 +296,10 @@ impl z_get_options_t {
         if let Some(ct) = self.cancellation_token.take() {
             ct.take_rust_type();
         }
+        #[cfg(feature = "unstable")]
+        if let Some(hook) = self.consolidation_hook.take() {
+            hook.take_rust_type();
+        }
     }
 }
 
@@ -280,9 +322,92 @@ pub extern "C" fn z_get_options_default(this_: &mut MaybeUninit<z_get_options_t>
         attachment: None,
         #[cfg(feature = "unstable")]
         cancellation_token: None,
+        #[cfg(feature = "unstable")]
+        reassemble_chunks: false,
+        #[cfg(feature = "unstable")]
+        consolidation_hook: None,
+        #[cfg(feature = "unstable")]
+        first_reply_only: false,
     });
 }
 
+#[cfg(feature = "unstable")]
+/// The callback of a get, holding back the successful replies to consolidate them with a user-supplied closure if any.
+struct ConsolidatingReplyCallback {
+    callback: z_owned_closure_reply_t,
+    hook: Option<z_owned_closure_reply_consolidation_t>,
+    /// The kept replies in their order of reception, and the index of the kept reply of each key expression.
+    kept: Mutex<(Vec<Reply>, HashMap<String, usize>)>,
+}
+
+#[cfg(feature = "unstable")]
+impl ConsolidatingReplyCallback {
+    fn new(
+        callback: z_owned_closure_reply_t,
+        hook: Option<z_owned_closure_reply_consolidation_t>,
+    ) -> Self {
+        ConsolidatingReplyCallback {
+            callback,
+            hook,
+            kept: Default::default(),
+        }
+    }
+
+    /// Returns the reply if it should be handed over to the callback right away.
+    fn consolidate(&self, reply: Reply) -> Option<Reply> {
+        let Some(hook) = self.hook.as_ref() else {
+            return Some(reply);
+        };
+        let key = match reply.result() {
+            Ok(sample) => sample.key_expr().as_str().to_owned(),
+            Err(_) => return Some(reply),
+        };
+        let mut kept = self.kept.lock().unwrap_or_else(|e| e.into_inner());
+        let (replies, index) = &mut *kept;
+        match index.get(&key) {
+            Some(&i) => {
+                let mut candidate = reply;
+                if z_closure_reply_consolidation_call(
+                    z_closure_reply_consolidation_loan(hook),
+                    replies[i].as_loaned_c_type_mut(),
+                    candidate.as_loaned_c_type_mut(),
+                ) {
+                    replies[i] = candidate;
+                }
+            }
+            None => {
+                index.insert(key, replies.len());
+                replies.push(reply);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Deref for ConsolidatingReplyCallback {
+    type Target = z_owned_closure_reply_t;
+    fn deref(&self) -> &Self::Target {
+        &self.callback
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Drop for ConsolidatingReplyCallback {
+    fn drop(&mut self) {
+        let (replies, _) = std::mem::take(self.kept.get_mut().unwrap_or_else(|e| e.into_inner()));
+        for reply in replies {
+            let mut owned_reply = Some(reply);
+            z_closure_reply_call(z_closure_reply_loan(&self.callback), unsafe {
+                owned_reply
+                    .as_mut()
+                    .unwrap_unchecked()
+                    .as_loaned_c_type_mut()
+            });
+        }
+    }
+}
+
 /// Query data from the matching queryables in the system.
 /// Replies are provided through a callback function.
 ///
@@ -312,6 +437,39 @@ pub unsafe extern "C" fn z_get(
     )
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Query data from the matching queryables in the system.
+/// Replies are provided through a callback function.
+///
+/// @param session: The zenoh session.
+/// @param key_expr: The key expression matching resources to query.
+/// @param parameters: The query's selector parameters.
+/// @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
+/// @param options: Additional options for the get. All owned fields will be consumed.
+///
+/// @return 0 in case of success, a negative error value upon failure.
+#[no_mangle]
+pub extern "C" fn z_get_with_parameters(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    parameters: &z_loaned_parameters_t,
+    callback: &mut z_moved_closure_reply_t,
+    options: Option<&mut z_get_options_t>,
+) -> result::z_result_t {
+    let parameters = parameters.as_rust_type_ref().as_str();
+    unsafe {
+        z_get_with_parameters_substr(
+            session,
+            key_expr,
+            parameters.as_ptr() as _,
+            parameters.len(),
+            callback,
+            options,
+        )
+    }
+}
+
 /// Query data from the matching queryables in the system.
 /// Replies are provided through a callback function.
 ///
@@ -332,6 +490,28 @@ pub unsafe extern "C" fn z_get_with_parameters_substr(
     parameters_len: usize,
     callback: &mut z_moved_closure_reply_t,
     options: Option<&mut z_get_options_t>,
+) -> result::z_result_t {
+    _get_with_parameters_substr_inner(
+        session,
+        key_expr,
+        parameters,
+        parameters_len,
+        callback,
+        options,
+        #[cfg(feature = "unstable")]
+        None,
+    )
+}
+
+/// Makes the query, which can additionally be interrupted with `internal_token` regardless of the user-supplied cancellation token.
+unsafe fn _get_with_parameters_substr_inner(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    parameters: *const c_char,
+    parameters_len: usize,
+    callback: &mut z_moved_closure_reply_t,
+    options: Option<&mut z_get_options_t>,
+    #[cfg(feature = "unstable")] internal_token: Option<CancellationToken>,
 ) -> result::z_result_t {
     let callback = callback.take_rust_type();
     let pcs = match CStringView::new_borrowed(parameters as *const c_char, parameters_len) {
@@ -357,7 +537,22 @@ pub unsafe extern "C" fn z_get_with_parameters_substr(
 
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
-    let mut get = session.get(Selector::from((key_expr, p)));
+    #[allow(unused_mut)]
+    let mut parameters = Parameters::from(p);
+    // lets reply streams know that they are reassembled, see `z_reply_stream_new()`
+    #[cfg(feature = "unstable")]
+    if options.as_ref().is_some_and(|o| o.reassemble_chunks) {
+        parameters.insert(REASSEMBLE_PARAMETER, "");
+    }
+    let mut get = session.get(Selector::from((key_expr, parameters)));
+    #[cfg(feature = "unstable")]
+    let mut reassembler = None;
+    #[cfg(feature = "unstable")]
+    let mut hook = None;
+    #[cfg(feature = "unstable")]
+    let mut cancellation_token = None;
+    #[cfg(feature = "unstable")]
+    let mut first_reply_only = false;
     if let Some(options) = options {
         if let Some(payload) = options.payload.take() {
             get = get.payload(payload.take_rust_type());
@@ -387,16 +582,83 @@ pub unsafe extern "C" fn z_get_with_parameters_substr(
         }
 
         #[cfg(feature = "unstable")]
-        if let Some(ct) = options
-            .cancellation_token
-            .take()
-            .and_then(|ct| ct.take_rust_type())
         {
-            get = get.cancellation_token(ct);
+            cancellation_token = options
+                .cancellation_token
+                .take()
+                .and_then(|ct| ct.take_rust_type());
+        }
+        #[cfg(feature = "unstable")]
+        if options.reassemble_chunks {
+            get = get.consolidation(ConsolidationMode::None);
+            reassembler = Some(ReplyReassembler::default());
+        }
+        #[cfg(feature = "unstable")]
+        if let Some(h) = options.consolidation_hook.take() {
+            get = get.consolidation(ConsolidationMode::None);
+            hook = Some(h.take_rust_type());
+        }
+        #[cfg(feature = "unstable")]
+        if options.first_reply_only {
+            if hook.is_some() {
+                crate::report_error!(
+                    "first_reply_only can not be combined with consolidation_hook"
+                );
+                return result::Z_EINVAL;
+            }
+            get = get.consolidation(ConsolidationMode::None);
+            first_reply_only = true;
         }
     }
+    #[cfg(feature = "unstable")]
+    let forward = match internal_token {
+        Some(internal_token) => {
+            let forward = cancellation_token
+                .take()
+                .and_then(|ct| CancellationForward::new(ct, internal_token.clone()));
+            cancellation_token = Some(internal_token);
+            forward
+        }
+        None => None,
+    };
+    #[cfg(feature = "unstable")]
+    let race = if first_reply_only {
+        let (race, token) = FirstReplyRace::new(cancellation_token);
+        get = get.cancellation_token(token);
+        Some(race)
+    } else {
+        if let Some(ct) = cancellation_token {
+            get = get.cancellation_token(ct);
+        }
+        None
+    };
+    #[cfg(feature = "unstable")]
+    let callback = ConsolidatingReplyCallback::new(callback, hook);
     match get
         .callback(move |response| {
+            // keeps forwarding the user-supplied cancellation token while the query is running
+            #[cfg(feature = "unstable")]
+            let _ = &forward;
+            #[cfg(feature = "unstable")]
+            let response = match reassembler.as_ref() {
+                Some(reassembler) => match reassembler.push(response) {
+                    Some(response) => response,
+                    None => return,
+                },
+                None => response,
+            };
+            #[cfg(feature = "unstable")]
+            let Some(response) = callback.consolidate(response) else {
+                return;
+            };
+            #[cfg(feature = "unstable")]
+            let _won = match race.as_ref() {
+                Some(race) => match race.admit(&response) {
+                    Some(won) => Some(won),
+                    None => return,
+                },
+                None => None,
+            };
             let mut owned_response = Some(response);
             z_closure_reply_call(
                 z_closure_reply_loan(&callback),
@@ -417,6 +679,175 @@ pub unsafe extern "C" fn z_get_with_parameters_substr(
     }
 }
 
+#[cfg(feature = "unstable")]
+pub type ZReplyVector = Vec<Reply>;
+#[cfg(feature = "unstable")]
+decl_c_type!(
+    owned(z_owned_reply_array_t, ZReplyVector),
+    loaned(z_loaned_reply_array_t),
+);
+
+#[cfg(feature = "unstable")]
+impl Gravestone for ZReplyVector {
+    fn gravestone() -> Self {
+        Vec::new()
+    }
+    fn is_gravestone(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs reply array in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_reply_array_null(this_: &mut MaybeUninit<z_owned_reply_array_t>) {
+    this_
+        .as_rust_type_mut_uninit()
+        .write(ZReplyVector::gravestone());
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the reply array is valid, ``false`` if it is in a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_reply_array_check(this_: &z_owned_reply_array_t) -> bool {
+    !this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Destroys the reply array, resetting it to its gravestone value.
+#[no_mangle]
+pub extern "C" fn z_reply_array_drop(this_: &mut z_moved_reply_array_t) {
+    let _ = this_.take_rust_type();
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows reply array.
+#[no_mangle]
+pub extern "C" fn z_reply_array_loan(this_: &z_owned_reply_array_t) -> &z_loaned_reply_array_t {
+    this_.as_rust_type_ref().as_loaned_c_type_ref()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows reply array.
+#[no_mangle]
+pub extern "C" fn z_reply_array_loan_mut(
+    this_: &mut z_owned_reply_array_t,
+) -> &mut z_loaned_reply_array_t {
+    this_.as_rust_type_mut().as_loaned_c_type_mut()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return number of replies in the array.
+#[no_mangle]
+pub extern "C" fn z_reply_array_len(this_: &z_loaned_reply_array_t) -> usize {
+    this_.as_rust_type_ref().len()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the array is empty, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_reply_array_is_empty(this_: &z_loaned_reply_array_t) -> bool {
+    this_.as_rust_type_ref().is_empty()
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return the reply at the position of index in the reply array.
+///
+/// Will return `NULL` if the index is out of bounds.
+#[no_mangle]
+pub extern "C" fn z_reply_array_get(
+    this_: &z_loaned_reply_array_t,
+    index: usize,
+) -> Option<&z_loaned_reply_t> {
+    this_
+        .as_rust_type_ref()
+        .get(index)
+        .map(|r| r.as_loaned_c_type_ref())
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a shallow copy of a reply array.
+#[no_mangle]
+pub extern "C" fn z_reply_array_clone(
+    dst: &mut MaybeUninit<z_owned_reply_array_t>,
+    this_: &z_loaned_reply_array_t,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(this_.as_rust_type_ref().clone());
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Queries data from the matching queryables in the system, blocking until all the replies are received.
+///
+/// The call returns once the query is finished, either because all the queryables have replied or because the query timed out,
+/// or as soon as `max_replies` replies have been received. In the latter case the query is cancelled and the remaining replies are discarded.
+///
+/// @param session: The zenoh session.
+/// @param key_expr: The key expression matching resources to query.
+/// @param parameters: The query's parameters null-terminated string, similar to a url's query segment.
+/// @param replies: An uninitialized location in memory where the array of received replies will be constructed, in their order of reception.
+/// @param max_replies: The number of replies after which to stop waiting, 0 meaning no limit.
+/// @param options: Additional options for the get. All owned fields will be consumed.
+///
+/// @return 0 in case of success, a negative error value upon failure.
+#[allow(clippy::missing_safety_doc)]
+#[no_mangle]
+pub unsafe extern "C" fn z_get_collect(
+    session: &z_loaned_session_t,
+    key_expr: &z_loaned_keyexpr_t,
+    parameters: *const c_char,
+    replies: &mut MaybeUninit<z_owned_reply_array_t>,
+    max_replies: usize,
+    options: Option<&mut z_get_options_t>,
+) -> result::z_result_t {
+    let replies = replies.as_rust_type_mut_uninit();
+    let token = CancellationToken::default();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut callback = z_moved_closure_reply_t {
+        _this: z_owned_closure_reply_t::from(move |reply: &mut z_loaned_reply_t| {
+            let _ = tx.send(std::mem::replace(reply.as_rust_type_mut(), Reply::empty()));
+        }),
+    };
+    let res = _get_with_parameters_substr_inner(
+        session,
+        key_expr,
+        parameters,
+        strlen_or_zero(parameters),
+        &mut callback,
+        options,
+        (max_replies != 0).then(|| token.clone()),
+    );
+    if res != result::Z_OK {
+        replies.write(ZReplyVector::gravestone());
+        return res;
+    }
+    let mut collected = ZReplyVector::new();
+    while max_replies == 0 || collected.len() < max_replies {
+        match rx.recv() {
+            Ok(reply) => collected.push(reply),
+            Err(_) => break,
+        }
+    }
+    if max_replies != 0 && collected.len() == max_replies {
+        // the remaining replies are not waited for
+        if let Err(e) = token.cancel().wait() {
+            crate::report_error!("{}", e);
+        }
+    }
+    replies.write(collected);
+    result::Z_OK
+}
+
 /// Frees reply, resetting it to its gravestone state.
 #[no_mangle]
 pub extern "C" fn z_reply_drop(this_: &mut z_moved_reply_t) {
@@ -39,6 +39,10 @@ mod commons;
 pub use crate::commons::*;
 mod zbytes;
 pub use crate::zbytes::*;
+#[cfg(feature = "unstable")]
+mod compression;
+#[cfg(feature = "unstable")]
+pub use crate::compression::*;
 mod keyexpr;
 pub use crate::keyexpr::*;
 mod info;
@@ -49,6 +53,14 @@ mod querier;
 pub use crate::querier::*;
 mod queryable;
 pub use crate::queryable::*;
+#[cfg(feature = "unstable")]
+mod parameters;
+#[cfg(feature = "unstable")]
+pub use crate::parameters::*;
+#[cfg(feature = "unstable")]
+mod reply_stream;
+#[cfg(feature = "unstable")]
+pub use crate::reply_stream::*;
 mod put;
 pub use crate::put::*;
 mod scouting;
@@ -59,12 +71,24 @@ mod subscriber;
 pub use crate::subscriber::*;
 mod publisher;
 pub use crate::publisher::*;
+#[cfg(feature = "unstable")]
+mod prefix_publisher;
+#[cfg(feature = "unstable")]
+pub use crate::prefix_publisher::*;
 mod closures;
 pub use closures::*;
 pub mod platform;
 pub use platform::*;
 mod liveliness;
 pub use liveliness::*;
+#[cfg(feature = "unstable")]
+mod liveliness_tracker;
+#[cfg(feature = "unstable")]
+pub use liveliness_tracker::*;
+#[cfg(feature = "unstable")]
+mod liveliness_coordination;
+#[cfg(feature = "unstable")]
+pub use liveliness_coordination::*;
 mod matching;
 pub use matching::*;
 #[cfg(feature = "unstable")]
@@ -12,31 +12,223 @@
 //   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+    sync::Mutex,
+};
+use std::{
+    mem::MaybeUninit,
+    sync::{atomic::AtomicBool, Arc},
+};
 
+#[cfg(feature = "unstable")]
+use zenoh::{
+    bytes::ZBytes,
+    key_expr::KeyExpr,
+    query::{ConsolidationMode, Queryable, Reply},
+    sample::SampleKind,
+    session::{WeakSession, ZenohId},
+};
 use zenoh::{
     handlers::Callback,
     liveliness::{LivelinessSubscriberBuilder, LivelinessToken},
     sample::Sample,
     Wait,
 };
+#[cfg(feature = "unstable")]
+use zenoh_runtime::ZRuntime;
 
 #[cfg(feature = "unstable")]
-use crate::opaque_types::z_moved_cancellation_token_t;
+use crate::{
+    _is_paused, opaque_types::z_moved_cancellation_token_t, z_loaned_bytes_t,
+    z_owned_closure_reply_t, z_owned_closure_sample_t,
+};
 use crate::{
     opaque_types::{z_loaned_liveliness_token_t, z_owned_liveliness_token_t},
     result,
     transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_reply_call, z_closure_reply_loan, z_closure_sample_call, z_closure_sample_loan,
     z_loaned_keyexpr_t, z_loaned_session_t, z_moved_closure_reply_t, z_moved_closure_sample_t,
-    z_moved_liveliness_token_t, z_owned_subscriber_t,
+    z_moved_liveliness_token_t, z_owned_subscriber_t, CSubscriber,
 };
 
+pub struct CLivelinessToken {
+    token: LivelinessToken,
+    #[cfg(feature = "unstable")]
+    _payload_queryable: Option<Queryable<()>>,
+}
+
 decl_c_type!(
-    owned(z_owned_liveliness_token_t, option LivelinessToken),
+    owned(z_owned_liveliness_token_t, option CLivelinessToken),
     loaned(z_loaned_liveliness_token_t),
 );
 
+#[cfg(feature = "unstable")]
+const TOKEN_PAYLOAD_PREFIX: &str = "@zc/liveliness";
+
+#[cfg(feature = "unstable")]
+/// Returns the key expression on which the session `owner` serves the payload of its liveliness token on `key_expr`.
+fn token_payload_key_expr(owner: &str, key_expr: &KeyExpr) -> zenoh::Result<KeyExpr<'static>> {
+    KeyExpr::try_from(format!("{TOKEN_PAYLOAD_PREFIX}/{owner}/{key_expr}"))
+}
+
+#[cfg(feature = "unstable")]
+/// Returns `true` if `reply_key_expr` serves the payload of a token declared on `key_expr` itself,
+/// rather than on a key expression which merely intersects it.
+fn is_token_payload_of(reply_key_expr: &KeyExpr, key_expr: &KeyExpr) -> bool {
+    reply_key_expr
+        .as_str()
+        .strip_prefix(TOKEN_PAYLOAD_PREFIX)
+        .and_then(|k| k.strip_prefix('/'))
+        .and_then(|k| k.split_once('/'))
+        .is_some_and(|(owner, k)| ZenohId::from_str(owner).is_ok() && k == key_expr.as_str())
+}
+
+#[cfg(feature = "unstable")]
+/// Calls `on_done` with the payload of a liveliness token once it is fetched.
+struct TokenPayloadFetch<F: FnOnce(Option<ZBytes>)> {
+    payload: Option<ZBytes>,
+    on_done: Option<F>,
+}
+
+#[cfg(feature = "unstable")]
+impl<F: FnOnce(Option<ZBytes>)> Drop for TokenPayloadFetch<F> {
+    fn drop(&mut self) {
+        if let Some(on_done) = self.on_done.take() {
+            on_done(self.payload.take());
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// Queries the payload of the liveliness tokens on `key_expr`, calling `on_done` with the first one received once the query ends,
+/// or with `None` if none of them has a payload.
+fn fetch_token_payload<F>(session: &WeakSession, key_expr: &KeyExpr, on_done: F)
+where
+    F: FnOnce(Option<ZBytes>) + Send + 'static,
+{
+    let fetch = TokenPayloadFetch {
+        payload: None,
+        on_done: Some(on_done),
+    };
+    let Ok(payload_key_expr) = token_payload_key_expr("*", key_expr) else {
+        return;
+    };
+    let key_expr = key_expr.clone().into_owned();
+    let session = session.clone();
+    // Liveliness callbacks may be called while holding locks the query needs, so it is sent from another task.
+    ZRuntime::Application.spawn(async move {
+        let fetch = Mutex::new(fetch);
+        let _ = session
+            .get(payload_key_expr)
+            .consolidation(ConsolidationMode::None)
+            .callback(move |reply| {
+                // The payloads of the tokens on intersecting key expressions are answered too, but belong to other tokens.
+                if let Some(sample) = reply
+                    .result()
+                    .ok()
+                    .filter(|s| is_token_payload_of(s.key_expr(), &key_expr))
+                {
+                    let mut fetch = fetch.lock().unwrap_or_else(|e| e.into_inner());
+                    if fetch.payload.is_none() {
+                        fetch.payload = Some(sample.payload().clone());
+                    }
+                }
+            })
+            .await;
+    });
+}
+
+#[cfg(feature = "unstable")]
+/// Hands the samples of a liveliness subscriber over to its callback once the payload of the declared tokens is fetched,
+/// preserving the order of the samples on each key expression.
+struct TokenPayloadFetcher {
+    session: WeakSession,
+    callback: z_owned_closure_sample_t,
+    /// The samples waiting to be handed over by key expression, the first one being a PUT whose payload is being fetched.
+    pending: Mutex<HashMap<KeyExpr<'static>, VecDeque<Sample>>>,
+}
+
+#[cfg(feature = "unstable")]
+impl TokenPayloadFetcher {
+    fn push(self: &Arc<Self>, sample: Sample) {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(queue) = pending.get_mut(sample.key_expr()) {
+            queue.push_back(sample);
+            return;
+        }
+        if sample.kind() == SampleKind::Delete {
+            drop(pending);
+            self.deliver(sample);
+            return;
+        }
+        let key_expr = sample.key_expr().clone();
+        pending.insert(key_expr.clone(), VecDeque::from([sample]));
+        drop(pending);
+        self.fetch(key_expr);
+    }
+
+    fn fetch(self: &Arc<Self>, key_expr: KeyExpr<'static>) {
+        // The fetcher is owned by the subscriber callback: the samples fetched after the subscriber is undeclared are dropped.
+        let this = Arc::downgrade(self);
+        fetch_token_payload(&self.session, &key_expr.clone(), move |payload| {
+            if let Some(this) = this.upgrade() {
+                this.on_fetched(key_expr, payload)
+            }
+        });
+    }
+
+    fn on_fetched(self: &Arc<Self>, key_expr: KeyExpr<'static>, payload: Option<ZBytes>) {
+        let mut fetched = Some(payload);
+        loop {
+            let mut ready = Vec::new();
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(queue) = pending.get_mut(&key_expr) else {
+                return;
+            };
+            if let Some(payload) = fetched.take() {
+                let mut sample = queue.pop_front().expect("the fetched sample is pending");
+                if let Some(payload) = payload {
+                    *sample.payload_mut() = payload;
+                }
+                ready.push(sample);
+            }
+            while let Some(sample) = queue.pop_front() {
+                if sample.kind() == SampleKind::Put {
+                    queue.push_front(sample);
+                    break;
+                }
+                ready.push(sample);
+            }
+            let fetch_next = !queue.is_empty();
+            if ready.is_empty() && !fetch_next {
+                // The samples received while handing the previous ones over have all been handed over.
+                pending.remove(&key_expr);
+                return;
+            }
+            drop(pending);
+            for sample in ready {
+                self.deliver(sample);
+            }
+            if fetch_next {
+                return self.fetch(key_expr);
+            }
+        }
+    }
+
+    fn deliver(&self, sample: Sample) {
+        let mut owned_sample = Some(sample);
+        z_closure_sample_call(z_closure_sample_loan(&self.callback), unsafe {
+            owned_sample
+                .as_mut()
+                .unwrap_unchecked()
+                .as_loaned_c_type_mut()
+        })
+    }
+}
+
 /// @brief Constructs liveliness token in its gravestone state.
 #[no_mangle]
 pub extern "C" fn z_internal_liveliness_token_null(
@@ -58,8 +250,19 @@ pub extern "C" fn z_liveliness_token_drop(this_: &mut z_moved_liveliness_token_t
 }
 
 /// @brief The options for `z_liveliness_declare_token()`.
+///
+/// Liveliness tokens can not be restricted to local or remote subscribers, as zenoh declares them to the whole network.
 #[repr(C)]
 pub struct z_liveliness_token_options_t {
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// An optional payload describing the token, such as an application version or capabilities. It is copied, and handed over
+    /// to the liveliness subscribers and `z_liveliness_get()` callers which set `fetch_payload` in the sample notifying the token declaration.
+    /// Only the payloads of tokens declared on that very key expression are handed over; if several sessions declare a token on it,
+    /// the payload of one of them is.
+    pub payload: Option<&'static z_loaned_bytes_t>,
+    #[cfg(not(feature = "unstable"))]
     _dummy: u8,
 }
 
@@ -68,7 +271,12 @@ pub struct z_liveliness_token_options_t {
 pub extern "C" fn z_liveliness_token_options_default(
     this: &mut MaybeUninit<z_liveliness_token_options_t>,
 ) {
-    this.write(z_liveliness_token_options_t { _dummy: 0 });
+    this.write(z_liveliness_token_options_t {
+        #[cfg(feature = "unstable")]
+        payload: None,
+        #[cfg(not(feature = "unstable"))]
+        _dummy: 0,
+    });
 }
 
 /// @brief Borrows token.
@@ -91,20 +299,51 @@ pub unsafe extern "C" fn z_liveliness_token_loan(
 /// @param session: A Zenos session to declare the liveliness token.
 /// @param token: An uninitialized memory location where liveliness token will be constructed.
 /// @param key_expr: A keyexpr to declare a liveliess token for.
-/// @param _options: Liveliness token declaration properties.
+/// @param options: Liveliness token declaration properties.
 #[no_mangle]
 pub extern "C" fn z_liveliness_declare_token(
     session: &z_loaned_session_t,
     token: &mut MaybeUninit<z_owned_liveliness_token_t>,
     key_expr: &z_loaned_keyexpr_t,
-    _options: Option<&z_liveliness_token_options_t>,
+    options: Option<&z_liveliness_token_options_t>,
 ) -> result::z_result_t {
     let this = token.as_rust_type_mut_uninit();
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
+    #[cfg(not(feature = "unstable"))]
+    let _ = options;
+    // The payload is served before the token is declared, so that it can be fetched as soon as the token is noticed.
+    #[cfg(feature = "unstable")]
+    let payload_queryable = match options.and_then(|o| o.payload) {
+        Some(payload) => {
+            let payload = payload.as_rust_type_ref().clone();
+            let owner = session.zid().to_string();
+            let queryable = token_payload_key_expr(&owner, key_expr).and_then(|payload_key_expr| {
+                session
+                    .declare_queryable(payload_key_expr.clone())
+                    .callback(move |query| {
+                        let _ = query.reply(&payload_key_expr, payload.clone()).wait();
+                    })
+                    .wait()
+            });
+            match queryable {
+                Ok(queryable) => Some(queryable),
+                Err(e) => {
+                    crate::report_error!("Failed to declare liveliness token payload: {e}");
+                    this.write(None);
+                    return result::Z_EGENERIC;
+                }
+            }
+        }
+        None => None,
+    };
     match session.liveliness().declare_token(key_expr).wait() {
         Ok(token) => {
-            this.write(Some(token));
+            this.write(Some(CLivelinessToken {
+                token,
+                #[cfg(feature = "unstable")]
+                _payload_queryable: payload_queryable,
+            }));
             result::Z_OK
         }
         Err(e) => {
@@ -121,7 +360,7 @@ pub extern "C" fn z_liveliness_undeclare_token(
     this: &mut z_moved_liveliness_token_t,
 ) -> result::z_result_t {
     if let Some(token) = this.take_rust_type() {
-        if let Err(e) = token.undeclare().wait() {
+        if let Err(e) = token.token.undeclare().wait() {
             crate::report_error!("Failed to undeclare token: {e}");
             return result::Z_EGENERIC;
         }
@@ -134,6 +373,13 @@ pub extern "C" fn z_liveliness_undeclare_token(
 pub struct z_liveliness_subscriber_options_t {
     /// If true, subscriber will receive the state change notifications for liveliness tokens that were declared before its declaration.
     pub history: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If true, the payload of the tokens (see `z_liveliness_token_options_t`) is fetched, and handed over as the payload of the PUT samples
+    /// notifying their declaration. The samples are then delayed until the payload is fetched, but remain in order for each key expression.
+    /// The samples still waiting for a payload when the subscriber is undeclared are dropped.
+    pub fetch_payload: bool,
 }
 
 /// @brief Constucts default value for `z_liveliness_declare_subscriber_options_t`.
@@ -141,7 +387,11 @@ pub struct z_liveliness_subscriber_options_t {
 pub extern "C" fn z_liveliness_subscriber_options_default(
     this: &mut MaybeUninit<z_liveliness_subscriber_options_t>,
 ) {
-    this.write(z_liveliness_subscriber_options_t { history: false });
+    this.write(z_liveliness_subscriber_options_t {
+        history: false,
+        #[cfg(feature = "unstable")]
+        fetch_payload: false,
+    });
 }
 
 fn _liveliness_declare_subscriber_inner<'a, 'b>(
@@ -149,23 +399,43 @@ fn _liveliness_declare_subscriber_inner<'a, 'b>(
     key_expr: &'b z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut z_liveliness_subscriber_options_t>,
+    paused: Option<Arc<AtomicBool>>,
 ) -> LivelinessSubscriberBuilder<'a, 'b, Callback<Sample>> {
+    #[cfg(not(feature = "unstable"))]
+    let _ = paused;
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let sub = session
         .liveliness()
         .declare_subscriber(key_expr)
-        .history(options.is_some_and(|o| o.history))
-        .callback(move |sample| {
-            let mut owned_sample = Some(sample);
-            z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
-                owned_sample
-                    .as_mut()
-                    .unwrap_unchecked()
-                    .as_loaned_c_type_mut()
-            })
+        .history(options.as_ref().is_some_and(|o| o.history));
+    #[cfg(feature = "unstable")]
+    if options.is_some_and(|o| o.fetch_payload) {
+        let fetcher = Arc::new(TokenPayloadFetcher {
+            session: session.downgrade(),
+            callback,
+            pending: Default::default(),
         });
+        return sub.callback(move |sample| {
+            if !_is_paused(&paused) {
+                fetcher.push(sample)
+            }
+        });
+    }
+    let sub = sub.callback(move |sample| {
+        #[cfg(feature = "unstable")]
+        if _is_paused(&paused) {
+            return;
+        }
+        let mut owned_sample = Some(sample);
+        z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
+            owned_sample
+                .as_mut()
+                .unwrap_unchecked()
+                .as_loaned_c_type_mut()
+        })
+    });
     sub
 }
 /// @brief Declares a subscriber on liveliness tokens that intersect `key_expr`.
@@ -186,10 +456,26 @@ pub extern "C" fn z_liveliness_declare_subscriber(
     options: Option<&mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = subscriber.as_rust_type_mut_uninit();
-    let subscriber = _liveliness_declare_subscriber_inner(session, key_expr, callback, options);
+    #[cfg(feature = "unstable")]
+    let paused = Arc::new(AtomicBool::new(false));
+    #[cfg(feature = "unstable")]
+    let subscriber = _liveliness_declare_subscriber_inner(
+        session,
+        key_expr,
+        callback,
+        options,
+        Some(paused.clone()),
+    );
+    #[cfg(not(feature = "unstable"))]
+    let subscriber =
+        _liveliness_declare_subscriber_inner(session, key_expr, callback, options, None);
     match subscriber.wait() {
         Ok(subscriber) => {
-            this.write(Some(subscriber));
+            this.write(Some(CSubscriber {
+                subscriber,
+                #[cfg(feature = "unstable")]
+                paused,
+            }));
             result::Z_OK
         }
         Err(e) => {
@@ -215,7 +501,8 @@ pub extern "C" fn z_liveliness_declare_background_subscriber(
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut z_liveliness_subscriber_options_t>,
 ) -> result::z_result_t {
-    let subscriber = _liveliness_declare_subscriber_inner(session, key_expr, callback, options);
+    let subscriber =
+        _liveliness_declare_subscriber_inner(session, key_expr, callback, options, None);
     match subscriber.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -235,6 +522,12 @@ pub struct z_liveliness_get_options_t {
     ///
     /// Cancellation token to interrupt the query.
     pub cancellation_token: Option<&'static mut z_moved_cancellation_token_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If true, the payload of the tokens (see `z_liveliness_token_options_t`) is fetched, and handed over as the payload of the replies.
+    /// The replies are then delayed until the payload of their token is fetched.
+    pub fetch_payload: bool,
 }
 
 /// @brief Constructs default value `z_liveliness_get_options_t`.
@@ -246,9 +539,22 @@ pub extern "C" fn z_liveliness_get_options_default(
         timeout_ms: 10000,
         #[cfg(feature = "unstable")]
         cancellation_token: None,
+        #[cfg(feature = "unstable")]
+        fetch_payload: false,
     });
 }
 
+#[cfg(feature = "unstable")]
+fn call_reply(callback: &z_owned_closure_reply_t, reply: Reply) {
+    let mut owned_reply = Some(reply);
+    z_closure_reply_call(z_closure_reply_loan(callback), unsafe {
+        owned_reply
+            .as_mut()
+            .unwrap_unchecked()
+            .as_loaned_c_type_mut()
+    })
+}
+
 /// @brief Queries liveliness tokens currently on the network with a key expression intersecting with `key_expr`.
 ///
 /// @param session: The Zenoh session.
@@ -265,8 +571,28 @@ pub extern "C" fn z_liveliness_get(
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let fetch_session = options
+        .as_ref()
+        .is_some_and(|o| o.fetch_payload)
+        .then(|| session.downgrade());
+    #[cfg(feature = "unstable")]
+    let callback = Arc::new(callback);
     let liveliness = session.liveliness();
     let mut builder = liveliness.get(key_expr).callback(move |response| {
+        #[cfg(feature = "unstable")]
+        if let (Some(fetch_session), Ok(sample)) = (fetch_session.as_ref(), response.result()) {
+            let key_expr = sample.key_expr().clone();
+            let callback = callback.clone();
+            let mut response = response;
+            fetch_token_payload(fetch_session, &key_expr, move |payload| {
+                if let (Some(payload), Ok(sample)) = (payload, response.result_mut()) {
+                    *sample.payload_mut() = payload;
+                }
+                call_reply(&callback, response);
+            });
+            return;
+        }
         let mut owned_response = Some(response);
         z_closure_reply_call(z_closure_reply_loan(&callback), unsafe {
             owned_response
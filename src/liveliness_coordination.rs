@@ -0,0 +1,698 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+//! Leader election and named locks built on liveliness tokens.
+//!
+//! Each contender is identified by its zid and an id unique in its process, and declares a candidate token on
+//! `@zc/<kind>/candidate/<zid>/<id>/<name>`. The winner additionally declares a leader token on `@zc/<kind>/leader/<zid>/<id>/<name>`.
+//! A candidate only takes over when no other leader token is alive and its (zid, id) is the smallest among the candidates,
+//! and when several leader tokens are alive at once, all of them but the one with the smallest (zid, id) step down.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    mem::MaybeUninit,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Arc, Condvar, Mutex, MutexGuard,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use zenoh::{
+    key_expr::KeyExpr,
+    liveliness::LivelinessToken,
+    pubsub::Subscriber,
+    sample::{Sample, SampleKind},
+    session::{WeakSession, ZenohId},
+    Wait,
+};
+use zenoh_runtime::ZRuntime;
+
+pub use crate::opaque_types::{
+    z_loaned_liveliness_election_t, z_loaned_liveliness_lock_t, z_moved_liveliness_election_t,
+    z_moved_liveliness_lock_t, z_owned_liveliness_election_t, z_owned_liveliness_lock_t,
+};
+use crate::{
+    result,
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_closure_leadership_status_call, z_closure_leadership_status_loan, z_loaned_keyexpr_t,
+    z_loaned_session_t, z_moved_closure_leadership_status_t, z_owned_closure_leadership_status_t,
+};
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A struct that indicates whether a liveliness election candidate is the leader.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct z_leadership_status_t {
+    /// True if the candidate gained leadership, false if it lost it.
+    pub is_leader: bool,
+}
+
+/// The default time a lock holder waits for concurrent holders to be noticed.
+const DEFAULT_LOCK_SETTLE_MS: u64 = 200;
+
+/// The delays between the attempts to declare the leader token of a contender which failed to.
+const LEADER_RETRY_MIN: Duration = Duration::from_millis(100);
+const LEADER_RETRY_MAX: Duration = Duration::from_secs(5);
+
+/// The source of the ids distinguishing the contenders of the sessions of this process.
+static NEXT_CONTENDER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A contender, ordered by zid and then by id.
+type ContenderId = (ZenohId, u64);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ContentionRole {
+    Candidate,
+    Leader,
+}
+
+#[derive(Default)]
+struct ContentionState {
+    candidates: BTreeSet<ContenderId>,
+    leaders: BTreeSet<ContenderId>,
+    /// The tokens notified by the subscriber while the initial query is ongoing, whose replies are then outdated.
+    notified: Option<HashSet<(ContentionRole, ContenderId)>>,
+    is_leader: bool,
+    leader_token: Option<LivelinessToken>,
+    dirty: bool,
+    /// Whether a task applying the leadership changes is running.
+    scheduled: bool,
+    /// The thread calling the callback, if any.
+    calling: Option<ThreadId>,
+    closed: bool,
+}
+
+/// Declares and undeclares the leader token of a contender as its view of the contention changes, from a runtime task
+/// scheduled whenever it does.
+struct ContentionShared {
+    state: Mutex<ContentionState>,
+    condvar: Condvar,
+    session: WeakSession,
+    own: ContenderId,
+    leader_key_expr: KeyExpr<'static>,
+    callback: Mutex<Option<z_owned_closure_leadership_status_t>>,
+}
+
+impl ContentionShared {
+    fn lock(&self) -> MutexGuard<'_, ContentionState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn parse(prefix: &str, key_expr: &KeyExpr) -> Option<(ContentionRole, ContenderId)> {
+        let mut chunks = key_expr.as_str().strip_prefix(prefix)?.splitn(4, '/');
+        let role = match chunks.next()? {
+            "candidate" => ContentionRole::Candidate,
+            "leader" => ContentionRole::Leader,
+            _ => return None,
+        };
+        let zid = ZenohId::from_str(chunks.next()?).ok()?;
+        let id = chunks.next()?.parse().ok()?;
+        Some((role, (zid, id)))
+    }
+
+    fn update(state: &mut ContentionState, role: ContentionRole, id: ContenderId, alive: bool) {
+        let set = match role {
+            ContentionRole::Candidate => &mut state.candidates,
+            ContentionRole::Leader => &mut state.leaders,
+        };
+        let changed = if alive {
+            set.insert(id)
+        } else {
+            set.remove(&id)
+        };
+        state.dirty |= changed;
+    }
+
+    fn on_sample(self: &Arc<Self>, prefix: &str, sample: Sample) {
+        let Some((role, id)) = Self::parse(prefix, sample.key_expr()) else {
+            return;
+        };
+        let mut state = self.lock();
+        if let Some(notified) = state.notified.as_mut() {
+            notified.insert((role, id));
+        }
+        Self::update(&mut state, role, id, sample.kind() == SampleKind::Put);
+        self.schedule(&mut state);
+        drop(state);
+        self.condvar.notify_all();
+    }
+
+    fn on_initial_sample(&self, prefix: &str, sample: &Sample) {
+        let Some((role, id)) = Self::parse(prefix, sample.key_expr()) else {
+            return;
+        };
+        let mut state = self.lock();
+        if !state
+            .notified
+            .as_ref()
+            .is_some_and(|n| n.contains(&(role, id)))
+        {
+            Self::update(&mut state, role, id, true);
+        }
+    }
+
+    /// Returns the leadership change the candidate `own` should make, if any.
+    fn next_leadership(state: &ContentionState, own: ContenderId) -> Option<bool> {
+        if state.is_leader {
+            state.leaders.range(..own).next().is_some().then_some(false)
+        } else {
+            (state.leaders.iter().all(|z| *z == own) && state.candidates.first() == Some(&own))
+                .then_some(true)
+        }
+    }
+
+    /// Schedules a task applying the leadership changes, unless one is running or the contenders are still being queried.
+    fn schedule(self: &Arc<Self>, state: &mut ContentionState) {
+        if state.dirty && !state.scheduled && !state.closed && state.notified.is_none() {
+            state.scheduled = true;
+            ZRuntime::Application.spawn(self.clone().run());
+        }
+    }
+
+    async fn run(self: Arc<Self>) {
+        let mut retry_delay = LEADER_RETRY_MIN;
+        loop {
+            let is_leader = {
+                let mut state = self.lock();
+                if state.closed || !state.dirty {
+                    state.scheduled = false;
+                    return;
+                }
+                state.dirty = false;
+                match Self::next_leadership(&state, self.own) {
+                    Some(is_leader) => is_leader,
+                    None => continue,
+                }
+            };
+            let leader_token = if is_leader {
+                match self
+                    .session
+                    .liveliness()
+                    .declare_token(&self.leader_key_expr)
+                    .await
+                {
+                    Ok(token) => Some(token),
+                    Err(e) => {
+                        crate::report_error!("Failed to declare leader token: {e}");
+                        // The contenders may not change anymore, so the declaration is retried.
+                        self.lock().dirty = true;
+                        tokio::time::sleep(retry_delay).await;
+                        retry_delay = (retry_delay * 2).min(LEADER_RETRY_MAX);
+                        continue;
+                    }
+                }
+            } else {
+                None
+            };
+            retry_delay = LEADER_RETRY_MIN;
+            let (stale_token, closed) = {
+                let mut state = self.lock();
+                if state.closed {
+                    state.scheduled = false;
+                    (leader_token, true)
+                } else {
+                    state.is_leader = is_leader;
+                    (
+                        std::mem::replace(&mut state.leader_token, leader_token),
+                        false,
+                    )
+                }
+            };
+            self.condvar.notify_all();
+            if let Some(token) = stale_token {
+                if let Err(e) = token.undeclare().await {
+                    crate::report_error!("Failed to undeclare leader token: {e}");
+                }
+            }
+            if closed {
+                return;
+            }
+            let this = self.clone();
+            // The callback may block, so it is called from the blocking pool rather than from a runtime worker.
+            let _ = ZRuntime::Application
+                .spawn_blocking(move || this.call(is_leader))
+                .await;
+        }
+    }
+
+    fn call(&self, is_leader: bool) {
+        let callback = self.callback.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(callback) = callback.as_ref() {
+            self.lock().calling = Some(thread::current().id());
+            z_closure_leadership_status_call(
+                z_closure_leadership_status_loan(callback),
+                &z_leadership_status_t { is_leader },
+            );
+            self.lock().calling = None;
+        }
+    }
+}
+
+/// Stops the leadership changes of a contender once dropped, and undeclares its leader token.
+struct ContentionWorker {
+    shared: Arc<ContentionShared>,
+}
+
+impl Drop for ContentionWorker {
+    fn drop(&mut self) {
+        let (leader_token, is_calling) = {
+            let mut state = self.shared.lock();
+            state.closed = true;
+            (
+                state.leader_token.take(),
+                state.calling == Some(thread::current().id()),
+            )
+        };
+        self.shared.condvar.notify_all();
+        // Waits for the ongoing callback call to return, unless the election is undeclared from its own callback.
+        if !is_calling {
+            drop(
+                self.shared
+                    .callback
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .take(),
+            );
+        }
+        if let Some(token) = leader_token {
+            if let Err(e) = token.undeclare().wait() {
+                crate::report_error!("Failed to undeclare leader token: {e}");
+            }
+        }
+    }
+}
+
+pub struct CLivelinessContention {
+    own: ContenderId,
+    subscriber: Subscriber<()>,
+    token: LivelinessToken,
+    worker: ContentionWorker,
+}
+
+impl CLivelinessContention {
+    /// Declares a contender for `name` and waits until the current contenders are known.
+    fn declare(
+        session: &z_loaned_session_t,
+        kind: &str,
+        name: &z_loaned_keyexpr_t,
+        callback: Option<z_owned_closure_leadership_status_t>,
+    ) -> Result<Self, result::z_result_t> {
+        let session = session.as_rust_type_ref();
+        let name = name.as_rust_type_ref();
+        if name.as_str().contains('*') || name.as_str().contains('$') {
+            crate::report_error!("The name of a liveliness {kind} must not contain wildcards");
+            return Err(result::Z_EINVAL);
+        }
+        let own = (
+            session.zid(),
+            NEXT_CONTENDER_ID.fetch_add(1, Ordering::Relaxed),
+        );
+        let prefix = format!("@zc/{kind}/");
+        let key_expr = |role: &str| {
+            KeyExpr::try_from(format!("{prefix}{role}/{name}")).map_err(|e| {
+                crate::report_error!("Invalid liveliness {kind} name: {e}");
+                result::Z_EINVAL
+            })
+        };
+        let candidate_key_expr = key_expr(&format!("candidate/{}/{}", own.0, own.1))?;
+        let leader_key_expr = key_expr(&format!("leader/{}/{}", own.0, own.1))?;
+        let contenders_key_expr = key_expr("*/*/*")?;
+
+        let shared = Arc::new(ContentionShared {
+            state: Mutex::new(ContentionState {
+                candidates: BTreeSet::from([own]),
+                notified: Some(HashSet::new()),
+                ..Default::default()
+            }),
+            condvar: Condvar::new(),
+            session: session.downgrade(),
+            own,
+            leader_key_expr,
+            callback: Mutex::new(callback),
+        });
+        let worker = ContentionWorker {
+            shared: shared.clone(),
+        };
+        let s = shared.clone();
+        let p = prefix.clone();
+        let subscriber = session
+            .liveliness()
+            .declare_subscriber(&contenders_key_expr)
+            .callback(move |sample| s.on_sample(&p, sample))
+            .wait()
+            .map_err(|e| {
+                crate::report_error!("Failed to subscribe to liveliness {kind}: {e}");
+                result::Z_EGENERIC
+            })?;
+        let token = session
+            .liveliness()
+            .declare_token(&candidate_key_expr)
+            .wait()
+            .map_err(|e| {
+                crate::report_error!("Failed to declare candidate token: {e}");
+                result::Z_EGENERIC
+            })?;
+        // The sender is dropped along with the query callback once the query is complete.
+        let (tx, rx) = mpsc::channel::<()>();
+        let s = shared.clone();
+        session
+            .liveliness()
+            .get(&contenders_key_expr)
+            .callback(move |reply| {
+                let _tx = &tx;
+                if let Ok(sample) = reply.result() {
+                    s.on_initial_sample(&prefix, sample);
+                }
+            })
+            .wait()
+            .map_err(|e| {
+                crate::report_error!("Failed to query liveliness {kind}: {e}");
+                result::Z_EGENERIC
+            })?;
+        let _ = rx.recv();
+        {
+            let mut state = shared.lock();
+            state.notified = None;
+            state.dirty = true;
+            shared.schedule(&mut state);
+        }
+        Ok(CLivelinessContention {
+            own,
+            subscriber,
+            token,
+            worker,
+        })
+    }
+
+    fn is_leader(&self) -> bool {
+        self.worker.shared.lock().is_leader
+    }
+
+    /// Waits until the contender is the leader, and is still the leader `settle` after its leader token was noticed,
+    /// so that the concurrent leaders had the time to notice it and step down. Returns `false` if `timeout` expires first.
+    fn wait_settled_leader(&self, timeout: Option<Duration>, settle: Duration) -> bool {
+        let deadline = timeout.map(|t| Instant::now() + t);
+        let own = self.own;
+        let shared = &self.worker.shared;
+        let mut state = shared.lock();
+        loop {
+            let is_unsettled = |s: &mut ContentionState| !(s.is_leader && s.leaders.contains(&own));
+            state = match deadline {
+                Some(deadline) => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    shared
+                        .condvar
+                        .wait_timeout_while(state, timeout, is_unsettled)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+                None => shared
+                    .condvar
+                    .wait_while(state, is_unsettled)
+                    .unwrap_or_else(|e| e.into_inner()),
+            };
+            if is_unsettled(&mut state) {
+                return false;
+            }
+            state = shared
+                .condvar
+                .wait_timeout_while(state, settle, |s| s.is_leader)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+            if state.is_leader {
+                return true;
+            }
+        }
+    }
+
+    fn undeclare(self) -> result::z_result_t {
+        let CLivelinessContention {
+            own: _,
+            subscriber,
+            token,
+            worker,
+        } = self;
+        let mut res = result::Z_OK;
+        if let Err(e) = subscriber.undeclare().wait() {
+            crate::report_error!("Failed to undeclare liveliness subscriber: {e}");
+            res = result::Z_EGENERIC;
+        }
+        // Stopping the worker undeclares the leader token.
+        drop(worker);
+        if let Err(e) = token.undeclare().wait() {
+            crate::report_error!("Failed to undeclare candidate token: {e}");
+            res = result::Z_EGENERIC;
+        }
+        res
+    }
+}
+
+pub struct CLivelinessElection(CLivelinessContention);
+
+decl_c_type!(
+    owned(z_owned_liveliness_election_t, option CLivelinessElection),
+    loaned(z_loaned_liveliness_election_t),
+);
+
+pub struct CLivelinessLock(CLivelinessContention);
+
+decl_c_type!(
+    owned(z_owned_liveliness_lock_t, option CLivelinessLock),
+    loaned(z_loaned_liveliness_lock_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs and declares a candidate to the leader election `name`.
+///
+/// The leader is the candidate holding the election's leader token. Candidates are ordered by the zid of their session, and then by
+/// their order of declaration for the candidates of the same process. When no leader is alive, the smallest candidate takes over,
+/// and a candidate joining while a leader is alive does not take over even if it is smaller. If several candidates became leaders concurrently,
+/// all of them but the smallest one step down, so the leadership gained by a candidate may be shortly lost.
+///
+/// Leadership is bound to the liveliness of the leader's session: if the leader node fails, its tokens expire with the session lease
+/// (`transport/link/tx/lease` in zenoh configuration), and the next candidate takes over.
+///
+/// @param session: The Zenoh session.
+/// @param election: An uninitialized memory location where the election candidate will be constructed.
+/// @param name: The name of the election. Must not contain wildcards.
+/// @param callback: The callback called whenever the candidate gains or loses leadership.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_declare_election(
+    session: &z_loaned_session_t,
+    election: &mut MaybeUninit<z_owned_liveliness_election_t>,
+    name: &z_loaned_keyexpr_t,
+    callback: &mut z_moved_closure_leadership_status_t,
+) -> result::z_result_t {
+    let this = election.as_rust_type_mut_uninit();
+    let callback = callback.take_rust_type();
+    match CLivelinessContention::declare(session, "election", name, Some(callback)) {
+        Ok(contention) => {
+            this.write(Some(CLivelinessElection(contention)));
+            result::Z_OK
+        }
+        Err(e) => {
+            this.write(None);
+            e
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs liveliness election candidate in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_election_null(
+    this_: &mut MaybeUninit<z_owned_liveliness_election_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if liveliness election candidate is valid, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_election_check(
+    this_: &z_owned_liveliness_election_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows liveliness election candidate.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_liveliness_election_loan(
+    this_: &z_owned_liveliness_election_t,
+) -> &z_loaned_liveliness_election_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the candidate is currently the leader of the election, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_election_is_leader(this_: &z_loaned_liveliness_election_t) -> bool {
+    this_.as_rust_type_ref().0.is_leader()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares liveliness election candidate, giving up leadership if it holds it.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_undeclare_election(
+    this_: &mut z_moved_liveliness_election_t,
+) -> result::z_result_t {
+    match this_.take_rust_type() {
+        Some(election) => election.0.undeclare(),
+        None => result::Z_OK,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares liveliness election candidate, frees memory and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_liveliness_election_drop(this_: &mut z_moved_liveliness_election_t) {
+    let _ = z_liveliness_undeclare_election(this_);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The options for `z_liveliness_lock_acquire()`.
+#[repr(C)]
+pub struct z_liveliness_lock_options_t {
+    /// The maximum time to wait for the lock, in milliseconds. 0 means waiting indefinitely.
+    pub timeout_ms: u64,
+    /// The time in milliseconds the lock must remain held once acquired, before `z_liveliness_lock_acquire()` returns.
+    /// It should exceed the time needed for liveliness tokens to propagate in the network.
+    pub settle_ms: u64,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_liveliness_lock_options_t`.
+#[no_mangle]
+pub extern "C" fn z_liveliness_lock_options_default(
+    this_: &mut MaybeUninit<z_liveliness_lock_options_t>,
+) {
+    this_.write(z_liveliness_lock_options_t {
+        timeout_ms: 0,
+        settle_ms: DEFAULT_LOCK_SETTLE_MS,
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Acquires the distributed lock `name`, blocking until it is held.
+///
+/// The lock is an election whose leader holds the lock: it is granted to waiters in the order of the election candidates once released,
+/// and is released when the holder's session fails (after the session lease). When several contenders acquire the lock concurrently,
+/// all of them but one give it up as soon as they notice each other, so the lock is only reported as acquired once it remained held
+/// for `z_liveliness_lock_options_t::settle_ms`. Since the holders are only known through liveliness, this relies on the liveliness tokens
+/// propagating within this time: use `z_liveliness_lock_is_held()` to check that the lock is still held.
+///
+/// @param session: The Zenoh session.
+/// @param lock: An uninitialized memory location where the lock will be constructed.
+/// @param name: The name of the lock. Must not contain wildcards.
+/// @param options: Additional options for the lock.
+///
+/// @return 0 in case of success, ``Z_ETIMEDOUT`` if the lock could not be acquired before the timeout, other negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_lock_acquire(
+    session: &z_loaned_session_t,
+    lock: &mut MaybeUninit<z_owned_liveliness_lock_t>,
+    name: &z_loaned_keyexpr_t,
+    options: Option<&z_liveliness_lock_options_t>,
+) -> result::z_result_t {
+    let this = lock.as_rust_type_mut_uninit();
+    let timeout = options
+        .filter(|o| o.timeout_ms != 0)
+        .map(|o| Duration::from_millis(o.timeout_ms));
+    let settle = Duration::from_millis(options.map_or(DEFAULT_LOCK_SETTLE_MS, |o| o.settle_ms));
+    let contention = match CLivelinessContention::declare(session, "lock", name, None) {
+        Ok(contention) => contention,
+        Err(e) => {
+            this.write(None);
+            return e;
+        }
+    };
+    if !contention.wait_settled_leader(timeout, settle) {
+        contention.undeclare();
+        this.write(None);
+        return result::Z_ETIMEDOUT;
+    }
+    this.write(Some(CLivelinessLock(contention)));
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs liveliness lock in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_lock_null(
+    this_: &mut MaybeUninit<z_owned_liveliness_lock_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if liveliness lock is valid, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_lock_check(this_: &z_owned_liveliness_lock_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows liveliness lock.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_liveliness_lock_loan(
+    this_: &z_owned_liveliness_lock_t,
+) -> &z_loaned_liveliness_lock_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the lock is still held, ``false`` if it was lost to a concurrent holder.
+#[no_mangle]
+pub extern "C" fn z_liveliness_lock_is_held(this_: &z_loaned_liveliness_lock_t) -> bool {
+    this_.as_rust_type_ref().0.is_leader()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Releases liveliness lock.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_lock_release(
+    this_: &mut z_moved_liveliness_lock_t,
+) -> result::z_result_t {
+    match this_.take_rust_type() {
+        Some(lock) => lock.0.undeclare(),
+        None => result::Z_OK,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Releases liveliness lock, frees memory and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_liveliness_lock_drop(this_: &mut z_moved_liveliness_lock_t) {
+    let _ = z_liveliness_lock_release(this_);
+}
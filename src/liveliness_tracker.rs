@@ -0,0 +1,366 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    ffi::c_void,
+    mem::MaybeUninit,
+    sync::{mpsc, Arc, Mutex},
+    time::Duration,
+};
+
+use zenoh::{
+    key_expr::KeyExpr,
+    pubsub::Subscriber,
+    query::Reply,
+    sample::{Sample, SampleKind},
+    Wait,
+};
+
+pub use crate::opaque_types::{
+    z_loaned_liveliness_tracker_t, z_moved_liveliness_tracker_t, z_owned_liveliness_tracker_t,
+};
+use crate::{
+    result,
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_closure_sample_call, z_closure_sample_loan, z_loaned_keyexpr_t, z_loaned_session_t,
+    z_moved_closure_sample_t, z_owned_closure_sample_t, z_owned_string_array_t, CStringInner,
+    ZVector,
+};
+
+#[derive(Default)]
+struct TrackerState {
+    /// The alive token key expressions, sorted by their string representation.
+    alive: BTreeMap<String, KeyExpr<'static>>,
+    /// The key expressions notified by the subscriber while the initial query is ongoing, whose replies are then outdated.
+    notified: Option<HashSet<String>>,
+}
+
+/// The members of a liveliness tracker, updated by both its subscriber and its initial query.
+struct TrackerMembers {
+    state: Mutex<TrackerState>,
+    callback: Option<z_owned_closure_sample_t>,
+}
+
+impl TrackerMembers {
+    fn on_sample(&self, sample: Sample) {
+        let changed = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let key = sample.key_expr().as_str();
+            if let Some(notified) = state.notified.as_mut() {
+                notified.insert(key.to_owned());
+            }
+            match sample.kind() {
+                SampleKind::Put => state
+                    .alive
+                    .insert(key.to_owned(), sample.key_expr().clone())
+                    .is_none(),
+                SampleKind::Delete => state.alive.remove(key).is_some(),
+            }
+        };
+        if changed {
+            self.notify(sample);
+        }
+    }
+
+    fn on_reply(&self, reply: Reply) {
+        let Ok(sample) = reply.into_result() else {
+            return;
+        };
+        let changed = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let key = sample.key_expr().as_str();
+            if state.notified.as_ref().is_some_and(|n| n.contains(key)) {
+                false
+            } else {
+                state
+                    .alive
+                    .insert(key.to_owned(), sample.key_expr().clone())
+                    .is_none()
+            }
+        };
+        if changed {
+            self.notify(sample);
+        }
+    }
+
+    fn notify(&self, sample: Sample) {
+        if let Some(callback) = self.callback.as_ref() {
+            let mut owned_sample = Some(sample);
+            z_closure_sample_call(z_closure_sample_loan(callback), unsafe {
+                owned_sample
+                    .as_mut()
+                    .unwrap_unchecked()
+                    .as_loaned_c_type_mut()
+            })
+        }
+    }
+
+    fn snapshot(&self) -> Vec<KeyExpr<'static>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .alive
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+pub struct CLivelinessTracker {
+    subscriber: Subscriber<()>,
+    members: Arc<TrackerMembers>,
+}
+
+decl_c_type!(
+    owned(z_owned_liveliness_tracker_t, option CLivelinessTracker),
+    loaned(z_loaned_liveliness_tracker_t),
+);
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The options for `z_liveliness_declare_tracker()`.
+#[repr(C)]
+pub struct z_liveliness_tracker_options_t {
+    /// An optional closure called with a PUT sample when a token joins the tracked set, and with a DELETE sample when it leaves it.
+    /// The set is updated before the closure is called.
+    pub callback: Option<&'static mut z_moved_closure_sample_t>,
+    /// The timeout for the liveliness query retrieving the initially alive tokens, in milliseconds. 0 means default query timeout from zenoh configuration.
+    pub timeout_ms: u64,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_liveliness_tracker_options_t`.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_options_default(
+    this_: &mut MaybeUninit<z_liveliness_tracker_options_t>,
+) {
+    this_.write(z_liveliness_tracker_options_t {
+        callback: None,
+        timeout_ms: 0,
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs and declares a liveliness tracker, keeping the set of the alive liveliness tokens intersecting `key_expr`.
+///
+/// The tracker subscribes to liveliness changes before querying the tokens which are already alive, and only returns once the query is complete,
+/// so that the set is consistent as soon as it is declared. Replies to the query on key expressions whose liveliness changed in the meantime are discarded.
+///
+/// @param session: The Zenoh session.
+/// @param tracker: An uninitialized memory location where the tracker will be constructed.
+/// @param key_expr: The key expression of the tokens to track.
+/// @param options: Additional options for the tracker. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_declare_tracker(
+    session: &z_loaned_session_t,
+    tracker: &mut MaybeUninit<z_owned_liveliness_tracker_t>,
+    key_expr: &z_loaned_keyexpr_t,
+    options: Option<&mut z_liveliness_tracker_options_t>,
+) -> result::z_result_t {
+    let this = tracker.as_rust_type_mut_uninit();
+    let session = session.as_rust_type_ref();
+    let key_expr = key_expr.as_rust_type_ref();
+    let (callback, timeout_ms) = match options {
+        Some(options) => (
+            options.callback.take().map(|c| c.take_rust_type()),
+            options.timeout_ms,
+        ),
+        None => (None, 0),
+    };
+    let members = Arc::new(TrackerMembers {
+        state: Mutex::new(TrackerState {
+            alive: BTreeMap::new(),
+            notified: Some(HashSet::new()),
+        }),
+        callback,
+    });
+    let m = members.clone();
+    let subscriber = match session
+        .liveliness()
+        .declare_subscriber(key_expr)
+        .callback(move |sample| m.on_sample(sample))
+        .wait()
+    {
+        Ok(subscriber) => subscriber,
+        Err(e) => {
+            crate::report_error!("Failed to subscribe to liveliness: {e}");
+            this.write(None);
+            return result::Z_EGENERIC;
+        }
+    };
+    // The sender is dropped along with the query callback once the query is complete.
+    let (tx, rx) = mpsc::channel::<()>();
+    let m = members.clone();
+    let liveliness = session.liveliness();
+    let mut get = liveliness.get(key_expr).callback(move |reply| {
+        let _tx = &tx;
+        m.on_reply(reply)
+    });
+    if timeout_ms != 0 {
+        get = get.timeout(Duration::from_millis(timeout_ms));
+    }
+    if let Err(e) = get.wait() {
+        crate::report_error!("Failed to query liveliness: {e}");
+        this.write(None);
+        return result::Z_EGENERIC;
+    }
+    let _ = rx.recv();
+    members
+        .state
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .notified = None;
+    this.write(Some(CLivelinessTracker {
+        subscriber,
+        members,
+    }));
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs liveliness tracker in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_tracker_null(
+    this_: &mut MaybeUninit<z_owned_liveliness_tracker_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if liveliness tracker is valid, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_liveliness_tracker_check(
+    this_: &z_owned_liveliness_tracker_t,
+) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows liveliness tracker.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_liveliness_tracker_loan(
+    this_: &z_owned_liveliness_tracker_t,
+) -> &z_loaned_liveliness_tracker_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares liveliness tracker.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_undeclare_tracker(
+    this_: &mut z_moved_liveliness_tracker_t,
+) -> result::z_result_t {
+    if let Some(tracker) = this_.take_rust_type() {
+        if let Err(e) = tracker.subscriber.undeclare().wait() {
+            crate::report_error!("Failed to undeclare liveliness tracker: {e}");
+            return result::Z_EGENERIC;
+        }
+    }
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares liveliness tracker, frees memory and resets it to a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_drop(this_: &mut z_moved_liveliness_tracker_t) {
+    let _ = z_liveliness_undeclare_tracker(this_);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the number of alive tokens.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_len(this_: &z_loaned_liveliness_tracker_t) -> usize {
+    this_
+        .as_rust_type_ref()
+        .members
+        .state
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .alive
+        .len()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if a token on `key_expr` is alive, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_contains(
+    this_: &z_loaned_liveliness_tracker_t,
+    key_expr: &z_loaned_keyexpr_t,
+) -> bool {
+    this_
+        .as_rust_type_ref()
+        .members
+        .state
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .alive
+        .contains_key(key_expr.as_rust_type_ref().as_str())
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an array with the key expressions of the alive tokens, in lexicographic order.
+///
+/// @param this_: The liveliness tracker.
+/// @param members: An uninitialized memory location where the array will be constructed.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_snapshot(
+    this_: &z_loaned_liveliness_tracker_t,
+    members: &mut MaybeUninit<z_owned_string_array_t>,
+) {
+    let snapshot: ZVector = this_
+        .as_rust_type_ref()
+        .members
+        .snapshot()
+        .into_iter()
+        .map(|k| CStringInner::from(k.as_str().to_owned()))
+        .collect();
+    members.as_rust_type_mut_uninit().write(snapshot);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Function called for each alive token by `z_liveliness_tracker_iterate()`.
+///
+/// Should return ``true`` to stop the iteration, ``false`` to continue.
+pub type z_liveliness_tracker_iter_body_t =
+    extern "C" fn(key_expr: &z_loaned_keyexpr_t, context: *mut c_void) -> bool;
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Iterates over the key expressions of the alive tokens, in lexicographic order.
+///
+/// The iteration runs over a snapshot of the set, so `body` may safely call other functions on the tracker.
+///
+/// @param this_: The liveliness tracker.
+/// @param body: Function called for each key expression. Iteration stops as soon as it returns ``true``.
+/// @param context: An arbitrary pointer passed to `body` on each call.
+#[no_mangle]
+pub extern "C" fn z_liveliness_tracker_iterate(
+    this_: &z_loaned_liveliness_tracker_t,
+    body: z_liveliness_tracker_iter_body_t,
+    context: *mut c_void,
+) {
+    for key_expr in this_.as_rust_type_ref().members.snapshot() {
+        if body(key_expr.as_loaned_c_type_ref(), context) {
+            break;
+        }
+    }
+}
@@ -41,7 +41,7 @@ pub extern "C" fn z_internal_matching_listener_check(this_: &z_owned_matching_li
 
 /// @brief A struct that indicates if there exist Subscribers matching the Publisher's key expression or Queryables matching Querier's key expression and target.
 #[repr(C)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct z_matching_status_t {
     /// True if there exist matching Zenoh entities, false otherwise.
     pub matching: bool,
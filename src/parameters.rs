@@ -0,0 +1,446 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    ffi::{c_char, c_void, CStr},
+    mem::MaybeUninit,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use zenoh::query::{Parameters, TimeBound, TimeRange, ZenohParameters};
+
+pub use crate::opaque_types::{z_loaned_parameters_t, z_moved_parameters_t, z_owned_parameters_t};
+use crate::{
+    result::{self, z_result_t},
+    transmute::{Gravestone, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_string_t, z_timestamp_t, z_view_string_t, CStringInner, CStringView,
+};
+
+decl_c_type!(
+    owned(z_owned_parameters_t, Parameters<'static>),
+    loaned(z_loaned_parameters_t),
+);
+
+impl Gravestone for Parameters<'static> {
+    fn gravestone() -> Self {
+        Parameters::empty()
+    }
+    fn is_gravestone(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+/// Separates the parameters from each other.
+const LIST_SEPARATOR: char = ';';
+/// Separates the key of a parameter from its value.
+const FIELD_SEPARATOR: char = '=';
+
+unsafe fn parameter_str<'a>(s: *const c_char, what: &str) -> Result<&'a str, z_result_t> {
+    if s.is_null() {
+        crate::report_error!("Parameter {} should not be null", what);
+        return Err(result::Z_EINVAL);
+    }
+    CStr::from_ptr(s).to_str().map_err(|e| {
+        crate::report_error!("Parameter {} is not a valid utf-8 string: {}", what, e);
+        result::Z_EUTF8
+    })
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs empty selector parameters.
+#[no_mangle]
+pub extern "C" fn z_parameters_new(this_: &mut MaybeUninit<z_owned_parameters_t>) {
+    this_
+        .as_rust_type_mut_uninit()
+        .write(Parameters::gravestone());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs selector parameters from a string, e.g. ``"a=1;b=2|3;_time=[now(-1h)..]"``.
+///
+/// @param this_: An uninitialized location in memory where the parameters will be constructed.
+/// @param s: A null-terminated string.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_parameters_from_str(
+    this_: &mut MaybeUninit<z_owned_parameters_t>,
+    s: *const c_char,
+) -> z_result_t {
+    z_parameters_from_substr(this_, s, crate::strlen_or_zero(s))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs selector parameters from a substring.
+///
+/// @param this_: An uninitialized location in memory where the parameters will be constructed.
+/// @param s: A string, which does not need to be null-terminated.
+/// @param len: The length of the substring.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_parameters_from_substr(
+    this_: &mut MaybeUninit<z_owned_parameters_t>,
+    s: *const c_char,
+    len: usize,
+) -> z_result_t {
+    let this = this_.as_rust_type_mut_uninit();
+    let view = match CStringView::new_borrowed(s, len) {
+        Ok(view) => view,
+        Err(e) => {
+            this.write(Parameters::gravestone());
+            return e;
+        }
+    };
+    match <&str>::try_from(&view) {
+        Ok(s) => {
+            this.write(Parameters::from(s.to_owned()));
+            result::Z_OK
+        }
+        Err(e) => {
+            crate::report_error!("Parameters are not a valid utf-8 string: {}", e);
+            this.write(Parameters::gravestone());
+            result::Z_EUTF8
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs selector parameters in their gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_parameters_null(this_: &mut MaybeUninit<z_owned_parameters_t>) {
+    z_parameters_new(this_)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the parameters are not in their gravestone state, i.e. not empty, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_parameters_check(this_: &z_owned_parameters_t) -> bool {
+    !this_.as_rust_type_ref().is_gravestone()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Destroys the parameters, resetting them to their gravestone value.
+#[no_mangle]
+pub extern "C" fn z_parameters_drop(this_: &mut z_moved_parameters_t) {
+    let _ = this_.take_rust_type();
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows selector parameters.
+#[no_mangle]
+pub extern "C" fn z_parameters_loan(this_: &z_owned_parameters_t) -> &z_loaned_parameters_t {
+    this_.as_rust_type_ref().as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows selector parameters.
+#[no_mangle]
+pub extern "C" fn z_parameters_loan_mut(
+    this_: &mut z_owned_parameters_t,
+) -> &mut z_loaned_parameters_t {
+    this_.as_rust_type_mut().as_loaned_c_type_mut()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs an owned copy of selector parameters.
+#[no_mangle]
+pub extern "C" fn z_parameters_clone(
+    dst: &mut MaybeUninit<z_owned_parameters_t>,
+    this_: &z_loaned_parameters_t,
+) {
+    dst.as_rust_type_mut_uninit()
+        .write(this_.as_rust_type_ref().clone());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the string representation of the parameters, as sent in a selector.
+#[no_mangle]
+pub extern "C" fn z_parameters_as_str(
+    this_: &z_loaned_parameters_t,
+    str: &mut MaybeUninit<z_view_string_t>,
+) {
+    str.as_rust_type_mut_uninit()
+        .write(CStringView::new_borrowed_from_slice(
+            this_.as_rust_type_ref().as_str().as_bytes(),
+        ));
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if there are no parameters, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_parameters_is_empty(this_: &z_loaned_parameters_t) -> bool {
+    this_.as_rust_type_ref().is_empty()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the value of a parameter.
+///
+/// If the parameter is present several times, the first value is returned.
+/// A parameter without ``=`` has an empty value.
+///
+/// @param this_: The parameters.
+/// @param key: A null-terminated string, the name of the parameter.
+/// @param value: An uninitialized memory location where the view on the value will be constructed.
+/// It is left unchanged if the parameter is not present.
+///
+/// @return ``true`` if the parameter is present, ``false`` otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_parameters_get(
+    this_: &z_loaned_parameters_t,
+    key: *const c_char,
+    value: &mut MaybeUninit<z_view_string_t>,
+) -> bool {
+    let Ok(key) = parameter_str(key, "key") else {
+        return false;
+    };
+    match this_.as_rust_type_ref().get(key) {
+        Some(v) => {
+            value
+                .as_rust_type_mut_uninit()
+                .write(CStringView::new_borrowed_from_slice(v.as_bytes()));
+            true
+        }
+        None => false,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Function called for each parameter by `z_parameters_iterate()`.
+///
+/// Should return ``true`` to stop the iteration, ``false`` to continue.
+pub type z_parameters_iter_body_t =
+    extern "C" fn(key: &z_loaned_string_t, value: &z_loaned_string_t, context: *mut c_void) -> bool;
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Iterates over the parameters in their order of appearance.
+///
+/// @param this_: Parameters to iterate over.
+/// @param body: Function called for each key-value pair. Iteration stops as soon as it returns ``true``.
+/// @param context: An arbitrary pointer passed to `body` on each call.
+#[no_mangle]
+pub extern "C" fn z_parameters_iterate(
+    this_: &z_loaned_parameters_t,
+    body: z_parameters_iter_body_t,
+    context: *mut c_void,
+) {
+    for (key, value) in this_.as_rust_type_ref().iter() {
+        let key = CStringInner::new_borrowed_from_slice(key.as_bytes());
+        let value = CStringInner::new_borrowed_from_slice(value.as_bytes());
+        if body(
+            key.as_loaned_c_type_ref(),
+            value.as_loaned_c_type_ref(),
+            context,
+        ) {
+            break;
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sets the value of a parameter, replacing its previous value if any.
+///
+/// Several elements can be given in the value by separating them with ``|``.
+///
+/// @param this_: The parameters.
+/// @param key: A non-empty null-terminated string, which should contain neither ``;`` nor ``=``.
+/// @param value: A null-terminated string, which should not contain ``;``.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_parameters_insert(
+    this_: &mut z_loaned_parameters_t,
+    key: *const c_char,
+    value: *const c_char,
+) -> z_result_t {
+    let key = match parameter_str(key, "key") {
+        Ok(key) => key,
+        Err(e) => return e,
+    };
+    let value = match parameter_str(value, "value") {
+        Ok(value) => value,
+        Err(e) => return e,
+    };
+    if key.is_empty() || key.contains([LIST_SEPARATOR, FIELD_SEPARATOR]) {
+        crate::report_error!("Invalid parameter key: '{}'", key);
+        return result::Z_EINVAL;
+    }
+    if value.contains(LIST_SEPARATOR) {
+        crate::report_error!("Invalid value for parameter {}: '{}'", key, value);
+        return result::Z_EINVAL;
+    }
+    this_.as_rust_type_mut().insert(key, value);
+    result::Z_OK
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Removes all the occurences of a parameter.
+///
+/// @return ``true`` if the parameter was present, ``false`` otherwise.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_parameters_remove(
+    this_: &mut z_loaned_parameters_t,
+    key: *const c_char,
+) -> bool {
+    let Ok(key) = parameter_str(key, "key") else {
+        return false;
+    };
+    this_.as_rust_type_mut().remove(key).is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The kind of a time range bound.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_time_bound_kind_t {
+    /// The range is not bounded in this direction.
+    UNBOUNDED = 0,
+    /// The bound belongs to the range.
+    INCLUSIVE = 1,
+    /// The bound does not belong to the range.
+    EXCLUSIVE = 2,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A bound of a time range.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct z_time_bound_t {
+    /// The kind of the bound.
+    pub kind: z_time_bound_kind_t,
+    /// The time of the bound in milliseconds since the UNIX epoch, ignored for unbounded bounds.
+    pub time_ms: u64,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A time range, as carried by the ``_time`` selector parameter.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct z_time_range_t {
+    /// The start of the range.
+    pub start: z_time_bound_t,
+    /// The end of the range.
+    pub end: z_time_bound_t,
+}
+
+impl From<TimeBound<SystemTime>> for z_time_bound_t {
+    fn from(value: TimeBound<SystemTime>) -> Self {
+        let ms = |t: SystemTime| {
+            t.duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0)
+        };
+        match value {
+            TimeBound::Inclusive(t) => z_time_bound_t {
+                kind: z_time_bound_kind_t::INCLUSIVE,
+                time_ms: ms(t),
+            },
+            TimeBound::Exclusive(t) => z_time_bound_t {
+                kind: z_time_bound_kind_t::EXCLUSIVE,
+                time_ms: ms(t),
+            },
+            TimeBound::Unbounded => z_time_bound_t {
+                kind: z_time_bound_kind_t::UNBOUNDED,
+                time_ms: 0,
+            },
+        }
+    }
+}
+
+impl From<&z_time_bound_t> for TimeBound<SystemTime> {
+    fn from(value: &z_time_bound_t) -> Self {
+        let t = UNIX_EPOCH + Duration::from_millis(value.time_ms);
+        match value.kind {
+            z_time_bound_kind_t::UNBOUNDED => TimeBound::Unbounded,
+            z_time_bound_kind_t::INCLUSIVE => TimeBound::Inclusive(t),
+            z_time_bound_kind_t::EXCLUSIVE => TimeBound::Exclusive(t),
+        }
+    }
+}
+
+impl From<&z_time_range_t> for TimeRange<SystemTime> {
+    fn from(value: &z_time_range_t) -> Self {
+        TimeRange {
+            start: (&value.start).into(),
+            end: (&value.end).into(),
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Parses the time range carried by the ``_time`` parameter.
+///
+/// Bounds relative to the current time, like ``now(-1h)``, are resolved at the time of the call.
+///
+/// @param this_: The parameters.
+/// @param time_range: An uninitialized memory location where the time range will be written.
+///
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if there is no ``_time`` parameter, `Z_EINVAL` if its value is not a valid time range.
+#[no_mangle]
+pub extern "C" fn z_parameters_time_range(
+    this_: &z_loaned_parameters_t,
+    time_range: &mut MaybeUninit<z_time_range_t>,
+) -> z_result_t {
+    match this_.as_rust_type_ref().time_range() {
+        Some(Ok(range)) => {
+            let range = range.resolve();
+            time_range.write(z_time_range_t {
+                start: range.start.into(),
+                end: range.end.into(),
+            });
+            result::Z_OK
+        }
+        Some(Err(e)) => {
+            crate::report_error!("Invalid time range: {}", e);
+            result::Z_EINVAL
+        }
+        None => result::Z_EUNAVAILABLE,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sets the ``_time`` parameter to the given time range, or removes it if `time_range` is ``NULL``.
+#[no_mangle]
+pub extern "C" fn z_parameters_set_time_range(
+    this_: &mut z_loaned_parameters_t,
+    time_range: Option<&z_time_range_t>,
+) {
+    this_
+        .as_rust_type_mut()
+        .set_time_range(time_range.map(|r| TimeRange::<SystemTime>::from(r).into()));
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the time, in milliseconds since the UNIX epoch, belongs to the time range, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_time_range_contains(this_: &z_time_range_t, time_ms: u64) -> bool {
+    TimeRange::<SystemTime>::from(this_).contains(UNIX_EPOCH + Duration::from_millis(time_ms))
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @return ``true`` if the time of the timestamp belongs to the time range, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_time_range_contains_timestamp(
+    this_: &z_time_range_t,
+    timestamp: &z_timestamp_t,
+) -> bool {
+    TimeRange::<SystemTime>::from(this_)
+        .contains(timestamp.as_rust_type_ref().get_time().to_system_time())
+}
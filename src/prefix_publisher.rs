@@ -0,0 +1,342 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    ffi::{c_char, CStr},
+    mem::MaybeUninit,
+};
+
+use zenoh::{
+    bytes::Encoding,
+    key_expr::KeyExpr,
+    qos::{CongestionControl, Priority, Reliability},
+    sample::Locality,
+    session::{SessionClosedError, WeakSession},
+    Wait,
+};
+
+use crate::{
+    _apply_pubisher_delete_options, _apply_pubisher_put_options, _drop_publisher_put_options,
+    opaque_types::{
+        z_loaned_prefix_publisher_t, z_moved_prefix_publisher_t, z_owned_prefix_publisher_t,
+    },
+    result::{self},
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_congestion_control_t, z_loaned_keyexpr_t, z_loaned_session_t, z_locality_default,
+    z_locality_t, z_moved_bytes_t, z_moved_encoding_t, z_priority_t, z_publisher_delete_options_t,
+    z_publisher_put_options_t, z_reliability_default, z_reliability_t,
+};
+
+pub struct CPrefixPublisher {
+    session: WeakSession,
+    prefix: KeyExpr<'static>,
+    encoding: Encoding,
+    congestion_control: CongestionControl,
+    priority: Priority,
+    is_express: bool,
+    reliability: Reliability,
+    allowed_destination: Locality,
+}
+
+decl_c_type!(
+    owned(z_owned_prefix_publisher_t, option CPrefixPublisher),
+    loaned(z_loaned_prefix_publisher_t),
+);
+
+impl CPrefixPublisher {
+    fn keyexpr(&self, suffix: *const c_char) -> Result<KeyExpr<'static>, result::z_result_t> {
+        if suffix.is_null() {
+            crate::report_error!("Suffix should not be null");
+            return Err(result::Z_EINVAL);
+        }
+        let suffix = match unsafe { CStr::from_ptr(suffix) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                crate::report_error!("Suffix is not a valid utf-8 string: {}", e);
+                return Err(result::Z_EUTF8);
+            }
+        };
+        self.prefix.join(suffix).map_err(|e| {
+            crate::report_error!("Invalid key expression suffix {}: {}", suffix, e);
+            result::Z_EINVAL
+        })
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to the `z_declare_prefix_publisher()` function.
+#[repr(C)]
+pub struct z_prefix_publisher_options_t {
+    /// Default encoding for messages put by this prefix publisher.
+    pub encoding: Option<&'static mut z_moved_encoding_t>,
+    /// The congestion control to apply when routing messages from this prefix publisher.
+    pub congestion_control: z_congestion_control_t,
+    /// The priority of messages from this prefix publisher.
+    pub priority: z_priority_t,
+    /// If set to ``true``, messages will not be batched. This usually has a positive impact on latency but negative impact on throughput.
+    pub is_express: bool,
+    /// The prefix publisher reliability.
+    pub reliability: z_reliability_t,
+    /// The allowed destination for this prefix publisher.
+    pub allowed_destination: z_locality_t,
+}
+
+impl Default for z_prefix_publisher_options_t {
+    fn default() -> Self {
+        Self {
+            encoding: None,
+            congestion_control: CongestionControl::DEFAULT_PUSH.into(),
+            priority: Priority::default().into(),
+            is_express: false,
+            reliability: z_reliability_default(),
+            allowed_destination: z_locality_default(),
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_prefix_publisher_options_t`.
+#[no_mangle]
+pub extern "C" fn z_prefix_publisher_options_default(
+    this_: &mut MaybeUninit<z_prefix_publisher_options_t>,
+) {
+    this_.write(z_prefix_publisher_options_t::default());
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs and declares a prefix publisher for the given key expression.
+///
+/// Unlike `z_owned_publisher_t`, a prefix publisher is not bound to a single key expression: each put or delete
+/// takes a suffix which is joined to the prefix. The prefix is declared once on the network, so that messages only carry
+/// its numerical id along with the suffix, and no new declaration is made per suffix.
+///
+/// @param session: The Zenoh session.
+/// @param publisher: An uninitialized location in memory where prefix publisher will be constructed.
+/// @param prefix: The key expression prefix to publish on. Should not contain wildcards.
+/// @param options: Additional options for the prefix publisher.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_declare_prefix_publisher(
+    session: &'static z_loaned_session_t,
+    publisher: &mut MaybeUninit<z_owned_prefix_publisher_t>,
+    prefix: &z_loaned_keyexpr_t,
+    options: Option<&mut z_prefix_publisher_options_t>,
+) -> result::z_result_t {
+    let this = publisher.as_rust_type_mut_uninit();
+    let session = session.as_rust_type_ref();
+    let prefix = prefix.as_rust_type_ref();
+    if prefix.is_wild() {
+        crate::report_error!("Prefix should not contain wildcards: {}", prefix);
+        this.write(None);
+        return result::Z_EINVAL;
+    }
+    let mut default_options = z_prefix_publisher_options_t::default();
+    let options = options.unwrap_or(&mut default_options);
+    let encoding = options
+        .encoding
+        .take()
+        .map(|e| e.take_rust_type())
+        .unwrap_or_default();
+    match session.declare_keyexpr(prefix.clone().into_owned()).wait() {
+        Ok(prefix) => {
+            this.write(Some(CPrefixPublisher {
+                session: session.downgrade(),
+                prefix,
+                encoding,
+                congestion_control: options.congestion_control.into(),
+                priority: options.priority.into(),
+                is_express: options.is_express,
+                reliability: options.reliability.into(),
+                allowed_destination: options.allowed_destination.into(),
+            }));
+            result::Z_OK
+        }
+        Err(e) => {
+            crate::report_error!("{}", e);
+            this.write(None);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a prefix publisher in a gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_prefix_publisher_null(
+    this_: &mut MaybeUninit<z_owned_prefix_publisher_t>,
+) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if prefix publisher is valid, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_prefix_publisher_check(this_: &z_owned_prefix_publisher_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows prefix publisher.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_prefix_publisher_loan(
+    this_: &z_owned_prefix_publisher_t,
+) -> &z_loaned_prefix_publisher_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows prefix publisher.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_prefix_publisher_loan_mut(
+    this_: &mut z_owned_prefix_publisher_t,
+) -> &mut z_loaned_prefix_publisher_t {
+    this_
+        .as_rust_type_mut()
+        .as_mut()
+        .unwrap_unchecked()
+        .as_loaned_c_type_mut()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sends a `PUT` message onto the key expression obtained by joining the publisher's prefix with `suffix`,
+/// transfering the payload ownership.
+///
+/// The payload and all owned options fields are consumed upon function return.
+///
+/// @param this_: The prefix publisher.
+/// @param suffix: A null-terminated string to join to the publisher's prefix, e.g. ``"42/pose"``.
+/// @param payload: The data to publish. Will be consumed.
+/// @param options: The publisher put options. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error values in case of failure.
+#[no_mangle]
+pub extern "C" fn z_prefix_publisher_put(
+    this_: &z_loaned_prefix_publisher_t,
+    suffix: *const c_char,
+    payload: &mut z_moved_bytes_t,
+    options: Option<&mut z_publisher_put_options_t>,
+) -> result::z_result_t {
+    let publisher = this_.as_rust_type_ref();
+    let payload = payload.take_rust_type();
+    let key_expr = match publisher.keyexpr(suffix) {
+        Ok(key_expr) => key_expr,
+        Err(e) => {
+            if let Some(options) = options {
+                _drop_publisher_put_options(options);
+            }
+            return e;
+        }
+    };
+    let mut put = publisher
+        .session
+        .put(key_expr, payload)
+        .encoding(publisher.encoding.clone())
+        .congestion_control(publisher.congestion_control)
+        .priority(publisher.priority)
+        .express(publisher.is_express)
+        .reliability(publisher.reliability)
+        .allowed_destination(publisher.allowed_destination);
+    if let Some(options) = options {
+        put = _apply_pubisher_put_options(put, options);
+    }
+    match put.wait() {
+        Ok(_) => result::Z_OK,
+        Err(e) if e.downcast_ref::<SessionClosedError>().is_some() => result::Z_ESESSION_CLOSED,
+        Err(e) => {
+            crate::report_error!("{}", e);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sends a `DELETE` message onto the key expression obtained by joining the publisher's prefix with `suffix`.
+///
+/// @param this_: The prefix publisher.
+/// @param suffix: A null-terminated string to join to the publisher's prefix.
+/// @param options: The publisher delete options.
+///
+/// @return 0 in case of success, negative error code in case of failure.
+#[no_mangle]
+pub extern "C" fn z_prefix_publisher_delete(
+    this_: &z_loaned_prefix_publisher_t,
+    suffix: *const c_char,
+    options: Option<&mut z_publisher_delete_options_t>,
+) -> result::z_result_t {
+    let publisher = this_.as_rust_type_ref();
+    let key_expr = match publisher.keyexpr(suffix) {
+        Ok(key_expr) => key_expr,
+        Err(e) => return e,
+    };
+    let mut del = publisher
+        .session
+        .delete(key_expr)
+        .congestion_control(publisher.congestion_control)
+        .priority(publisher.priority)
+        .express(publisher.is_express)
+        .reliability(publisher.reliability)
+        .allowed_destination(publisher.allowed_destination);
+    if let Some(options) = options {
+        del = _apply_pubisher_delete_options(del, options);
+    }
+    match del.wait() {
+        Ok(_) => result::Z_OK,
+        Err(e) if e.downcast_ref::<SessionClosedError>().is_some() => result::Z_ESESSION_CLOSED,
+        Err(e) => {
+            crate::report_error!("{}", e);
+            result::Z_EGENERIC
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the key expression prefix of the prefix publisher.
+#[no_mangle]
+pub extern "C" fn z_prefix_publisher_keyexpr(
+    this_: &z_loaned_prefix_publisher_t,
+) -> &z_loaned_keyexpr_t {
+    this_.as_rust_type_ref().prefix.as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Frees memory and resets prefix publisher to its gravestone state.
+/// This is equivalent to calling `z_undeclare_prefix_publisher()` and discarding its return value.
+#[no_mangle]
+pub extern "C" fn z_prefix_publisher_drop(this_: &mut z_moved_prefix_publisher_t) {
+    let _ = z_undeclare_prefix_publisher(this_);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Undeclares the given prefix publisher, along with the declaration of its key expression prefix.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_undeclare_prefix_publisher(
+    this_: &mut z_moved_prefix_publisher_t,
+) -> result::z_result_t {
+    if let Some(p) = this_.take_rust_type() {
+        if let Err(e) = p.session.undeclare(p.prefix).wait() {
+            crate::report_error!("{}", e);
+            return result::Z_ENETWORK;
+        }
+    }
+    result::Z_OK
+}
@@ -12,7 +12,15 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, ops::Deref};
+#[cfg(feature = "unstable")]
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use zenoh::{
     handlers::Callback,
@@ -37,6 +45,184 @@ use crate::{
     transmute::IntoCType, z_entity_global_id_t, z_reliability_default, z_reliability_t,
     z_source_info_t,
 };
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The action taken by a publisher when a message exceeds its rate limit.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_rate_limit_action_t {
+    /// The message is dropped and the operation returns `Z_EUNAVAILABLE`.[Default]
+    DROP = 0,
+    /// The operation blocks until the message fits into the rate limit. If this would take longer than the configured timeout,
+    /// the message is dropped immediately and the operation returns `Z_EUNAVAILABLE`.
+    BLOCK = 1,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The rate limit applied by a publisher to its puts and deletes.
+///
+/// Limits are enforced with token buckets allowing bursts of up to one second worth of traffic.
+/// A message larger than `max_bytes_per_sec` is let through once the bytes bucket is full.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct z_publisher_rate_limit_options_t {
+    /// The maximum number of messages per second, ``0`` means unlimited.
+    pub max_messages_per_sec: u64,
+    /// The maximum number of payload bytes per second, ``0`` means unlimited.
+    pub max_bytes_per_sec: u64,
+    /// The action taken when a message exceeds the rate limit.
+    pub action: z_rate_limit_action_t,
+    /// The maximum time in milliseconds an operation can block when `action` is `Z_RATE_LIMIT_ACTION_BLOCK`.
+    /// ``0`` means no timeout: the operation blocks until the message fits into the rate limit, which takes at most one second,
+    /// unless other threads keep consuming the rate limit of the same publisher concurrently.
+    pub block_timeout_ms: u64,
+}
+
+#[cfg(feature = "unstable")]
+impl Default for z_publisher_rate_limit_options_t {
+    fn default() -> Self {
+        Self {
+            max_messages_per_sec: 0,
+            max_bytes_per_sec: 0,
+            action: z_rate_limit_action_t::DROP,
+            block_timeout_ms: 0,
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_publisher_rate_limit_options_t`, which does not limit the publisher.
+#[no_mangle]
+pub extern "C" fn z_publisher_rate_limit_options_default(
+    this_: &mut MaybeUninit<z_publisher_rate_limit_options_t>,
+) {
+    this_.write(z_publisher_rate_limit_options_t::default());
+}
+
+#[cfg(feature = "unstable")]
+struct RateLimiterState {
+    messages: f64,
+    bytes: f64,
+    last_refill: Instant,
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) struct PublisherRateLimiter {
+    max_messages_per_sec: f64,
+    max_bytes_per_sec: f64,
+    action: z_rate_limit_action_t,
+    block_timeout: Option<Duration>,
+    state: Mutex<RateLimiterState>,
+    throttled: AtomicU64,
+}
+
+#[cfg(feature = "unstable")]
+impl PublisherRateLimiter {
+    fn new(options: &z_publisher_rate_limit_options_t) -> Option<Self> {
+        if options.max_messages_per_sec == 0 && options.max_bytes_per_sec == 0 {
+            return None;
+        }
+        Some(Self {
+            max_messages_per_sec: options.max_messages_per_sec as f64,
+            max_bytes_per_sec: options.max_bytes_per_sec as f64,
+            action: options.action,
+            block_timeout: (options.block_timeout_ms > 0)
+                .then(|| Duration::from_millis(options.block_timeout_ms)),
+            state: Mutex::new(RateLimiterState {
+                messages: options.max_messages_per_sec as f64,
+                bytes: options.max_bytes_per_sec as f64,
+                last_refill: Instant::now(),
+            }),
+            throttled: AtomicU64::new(0),
+        })
+    }
+
+    /// Consumes the tokens for a message of `len` bytes if it fits into the limit,
+    /// otherwise returns the time to wait until it does.
+    fn try_acquire(&self, len: usize) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.last_refill = now;
+        let mut wait: f64 = 0.0;
+        if self.max_messages_per_sec > 0.0 {
+            state.messages = (state.messages + elapsed * self.max_messages_per_sec)
+                .min(self.max_messages_per_sec);
+            if state.messages < 1.0 {
+                wait = wait.max((1.0 - state.messages) / self.max_messages_per_sec);
+            }
+        }
+        if self.max_bytes_per_sec > 0.0 {
+            state.bytes =
+                (state.bytes + elapsed * self.max_bytes_per_sec).min(self.max_bytes_per_sec);
+            let needed = (len as f64).min(self.max_bytes_per_sec);
+            if state.bytes < needed {
+                wait = wait.max((needed - state.bytes) / self.max_bytes_per_sec);
+            }
+        }
+        if wait > 0.0 {
+            return Some(Duration::from_secs_f64(wait));
+        }
+        if self.max_messages_per_sec > 0.0 {
+            state.messages -= 1.0;
+        }
+        if self.max_bytes_per_sec > 0.0 {
+            state.bytes -= len as f64;
+        }
+        None
+    }
+
+    /// Returns ``true`` if a message of `len` bytes can be sent, blocking if required by the configured action.
+    /// Messages which are delayed or dropped are counted as throttled.
+    fn acquire(&self, len: usize) -> bool {
+        let Some(mut wait) = self.try_acquire(len) else {
+            return true;
+        };
+        self.throttled.fetch_add(1, Ordering::Relaxed);
+        let deadline = self.block_timeout.map(|t| Instant::now() + t);
+        loop {
+            let can_wait = self.action == z_rate_limit_action_t::BLOCK
+                && deadline.map_or(true, |d| Instant::now() + wait <= d);
+            if !can_wait {
+                return false;
+            }
+            std::thread::sleep(wait);
+            match self.try_acquire(len) {
+                Some(w) => wait = w,
+                None => return true,
+            }
+        }
+    }
+}
+
+pub struct CPublisher {
+    publisher: Publisher<'static>,
+    #[cfg(feature = "unstable")]
+    rate_limiter: Option<Box<PublisherRateLimiter>>,
+}
+
+impl Deref for CPublisher {
+    type Target = Publisher<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.publisher
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl CPublisher {
+    /// Returns ``true`` if a message of `len` bytes can be sent according to the publisher rate limit.
+    pub(crate) fn acquire_rate_limit(&self, len: usize) -> bool {
+        self.rate_limiter
+            .as_ref()
+            .map_or(true, |limiter| limiter.acquire(len))
+    }
+}
+
 /// Options passed to the `z_declare_publisher()` function.
 #[repr(C)]
 pub struct z_publisher_options_t {
@@ -55,6 +241,11 @@ pub struct z_publisher_options_t {
     pub reliability: z_reliability_t,
     /// The allowed destination for this publisher.
     pub allowed_destination: z_locality_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The rate limit applied to puts and deletes of this publisher. Not limited by default. Ignored by advanced publishers.
+    pub rate_limit: z_publisher_rate_limit_options_t,
 }
 
 impl Default for z_publisher_options_t {
@@ -67,6 +258,8 @@ impl Default for z_publisher_options_t {
             #[cfg(feature = "unstable")]
             reliability: z_reliability_default(),
             allowed_destination: z_locality_default(),
+            #[cfg(feature = "unstable")]
+            rate_limit: z_publisher_rate_limit_options_t::default(),
         }
     }
 }
@@ -79,7 +272,7 @@ pub extern "C" fn z_publisher_options_default(this_: &mut MaybeUninit<z_publishe
 
 pub use crate::opaque_types::{z_loaned_publisher_t, z_moved_publisher_t, z_owned_publisher_t};
 decl_c_type!(
-    owned(z_owned_publisher_t, option Publisher<'static>),
+    owned(z_owned_publisher_t, option CPublisher),
     loaned(z_loaned_publisher_t),
 );
 
@@ -127,6 +320,11 @@ pub extern "C" fn z_declare_publisher(
     options: Option<&'static mut z_publisher_options_t>,
 ) -> result::z_result_t {
     let this = publisher.as_rust_type_mut_uninit();
+    #[cfg(feature = "unstable")]
+    let rate_limiter = options
+        .as_ref()
+        .and_then(|o| PublisherRateLimiter::new(&o.rate_limit))
+        .map(Box::new);
     let p = _declare_publisher_inner(session, key_expr, options);
     match p.wait() {
         Err(e) => {
@@ -135,7 +333,11 @@ pub extern "C" fn z_declare_publisher(
             result::Z_EGENERIC
         }
         Ok(publisher) => {
-            this.write(Some(publisher));
+            this.write(Some(CPublisher {
+                publisher,
+                #[cfg(feature = "unstable")]
+                rate_limiter,
+            }));
             result::Z_OK
         }
     }
@@ -231,6 +433,12 @@ pub(crate) fn _apply_pubisher_put_options<
     builder
 }
 
+#[cfg(feature = "unstable")]
+pub(crate) fn _drop_publisher_put_options(options: &mut z_publisher_put_options_t) {
+    let _ = options.encoding.take().map(|e| e.take_rust_type());
+    let _ = options.attachment.take().map(|a| a.take_rust_type());
+}
+
 /// Sends a `PUT` message onto the publisher's key expression, transfering the payload ownership.
 ///
 ///
@@ -240,7 +448,8 @@ pub(crate) fn _apply_pubisher_put_options<
 /// @param payload: The data to publish. Will be consumed.
 /// @param options: The publisher put options. All owned fields will be consumed.
 ///
-/// @return 0 in case of success, negative error values in case of failure.
+/// @return 0 in case of success, negative error values in case of failure. `Z_EUNAVAILABLE` is returned if the message was dropped
+/// due to the publisher rate limit.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe extern "C" fn z_publisher_put(
@@ -250,10 +459,16 @@ pub unsafe extern "C" fn z_publisher_put(
 ) -> result::z_result_t {
     let publisher = this.as_rust_type_ref();
     let payload = payload.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let len = payload.len();
     let mut put = publisher.put(payload);
     if let Some(options) = options {
         put = _apply_pubisher_put_options(put, options);
     }
+    #[cfg(feature = "unstable")]
+    if !publisher.acquire_rate_limit(len) {
+        return result::Z_EUNAVAILABLE;
+    }
 
     match put.wait() {
         Ok(_) => result::Z_OK,
@@ -296,7 +511,8 @@ pub(crate) fn _apply_pubisher_delete_options<T: TimestampBuilderTrait>(
 
 /// Sends a `DELETE` message onto the publisher's key expression.
 ///
-/// @return 0 in case of success, negative error code in case of failure.
+/// @return 0 in case of success, negative error code in case of failure. `Z_EUNAVAILABLE` is returned if the message was dropped
+/// due to the publisher rate limit.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
 pub extern "C" fn z_publisher_delete(
@@ -308,6 +524,10 @@ pub extern "C" fn z_publisher_delete(
     if let Some(options) = options {
         del = _apply_pubisher_delete_options(del, options);
     }
+    #[cfg(feature = "unstable")]
+    if !publisher.acquire_rate_limit(0) {
+        return result::Z_EUNAVAILABLE;
+    }
     if let Err(e) = del.wait() {
         crate::report_error!("{}", e);
         result::Z_EGENERIC
@@ -323,6 +543,20 @@ pub extern "C" fn z_publisher_id(publisher: &z_loaned_publisher_t) -> z_entity_g
     publisher.as_rust_type_ref().id().into_c_type()
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the number of puts and deletes throttled by the publisher because they exceeded its rate limit.
+///
+/// Both messages dropped and messages delayed by `Z_RATE_LIMIT_ACTION_BLOCK` are counted.
+#[no_mangle]
+pub extern "C" fn z_publisher_throttled_count(publisher: &z_loaned_publisher_t) -> u64 {
+    publisher
+        .as_rust_type_ref()
+        .rate_limiter
+        .as_ref()
+        .map_or(0, |limiter| limiter.throttled.load(Ordering::Relaxed))
+}
+
 /// Returns the key expression of the publisher.
 #[no_mangle]
 pub extern "C" fn z_publisher_keyexpr(publisher: &z_loaned_publisher_t) -> &z_loaned_keyexpr_t {
@@ -436,7 +670,7 @@ pub extern "C" fn z_publisher_drop(this: &mut z_moved_publisher_t) {
 /// @return 0 in case of success, negative error code otherwise.
 pub extern "C" fn z_undeclare_publisher(this_: &mut z_moved_publisher_t) -> result::z_result_t {
     if let Some(p) = this_.take_rust_type() {
-        if let Err(e) = p.undeclare().wait_callbacks().wait() {
+        if let Err(e) = p.publisher.undeclare().wait_callbacks().wait() {
             crate::report_error!("{}", e);
             return result::Z_ENETWORK;
         }
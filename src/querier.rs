@@ -12,18 +12,36 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+use std::{mem::MaybeUninit, ops::Deref};
+#[cfg(feature = "unstable")]
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use libc::c_char;
 use zenoh::{
     handlers::Callback,
     matching::MatchingStatus,
     qos::{CongestionControl, Priority},
-    query::{Querier, QueryConsolidation, QueryTarget},
+    query::{Parameters, Querier, QueryConsolidation, QueryTarget},
     session::SessionClosedError,
     Wait,
 };
+#[cfg(feature = "unstable")]
+use zenoh::{query::Reply, session::EntityGlobalId};
 
+#[cfg(feature = "unstable")]
+use crate::{
+    cancellation_token::FirstReplyRace,
+    reply_stream::{ReplyReassembler, REASSEMBLE_PARAMETER},
+    transmute::IntoCType,
+    z_entity_global_id_t, z_loaned_parameters_t, z_moved_cancellation_token_t,
+    z_owned_closure_reply_t, z_source_info_t,
+};
 use crate::{
     result, strlen_or_zero,
     transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
@@ -34,10 +52,6 @@ use crate::{
     z_moved_querier_t, z_owned_matching_listener_t, z_owned_querier_t, z_priority_t,
     z_query_consolidation_t, z_query_target_t, z_reply_keyexpr_default, z_reply_keyexpr_t,
 };
-#[cfg(feature = "unstable")]
-use crate::{
-    transmute::IntoCType, z_entity_global_id_t, z_moved_cancellation_token_t, z_source_info_t,
-};
 
 /// @brief Options passed to the `z_declare_querier()` function.
 #[repr(C)]
@@ -75,11 +89,184 @@ pub extern "C" fn z_querier_options_default(this_: &mut MaybeUninit<z_querier_op
     });
 }
 
+pub struct CQuerier {
+    querier: Querier<'static>,
+    #[cfg(feature = "unstable")]
+    stats: Arc<QuerierStats>,
+    /// The timeout of the queries, after which zenoh ends them.
+    #[cfg(feature = "unstable")]
+    timeout: Duration,
+}
+
+impl Deref for CQuerier {
+    type Target = Querier<'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.querier
+    }
+}
+
 decl_c_type!(
-    owned(z_owned_querier_t, option Querier<'static>),
+    owned(z_owned_querier_t, option CQuerier),
     loaned(z_loaned_querier_t),
 );
 
+#[cfg(feature = "unstable")]
+/// The number of latest reply latencies the percentiles of the querier statistics are computed on.
+const QUERIER_LATENCY_SAMPLES: usize = 1024;
+
+#[cfg(feature = "unstable")]
+#[derive(Default)]
+struct QuerierStats {
+    queries_sent: AtomicU64,
+    replies: AtomicU64,
+    errors: AtomicU64,
+    timeouts: AtomicU64,
+    /// The latest reply latencies in microseconds, along with the position of the next one to overwrite.
+    latencies: Mutex<(Vec<u64>, usize)>,
+}
+
+#[cfg(feature = "unstable")]
+impl QuerierStats {
+    fn record(&self, reply: &Reply, sent_at: Instant) {
+        match reply.result() {
+            Ok(_) => self.replies.fetch_add(1, Ordering::Relaxed),
+            Err(_) => self.errors.fetch_add(1, Ordering::Relaxed),
+        };
+        let latency = sent_at.elapsed().as_micros().min(u64::MAX as u128) as u64;
+        let mut latencies = self.latencies.lock().unwrap_or_else(|e| e.into_inner());
+        let (samples, next) = &mut *latencies;
+        if samples.len() < QUERIER_LATENCY_SAMPLES {
+            samples.push(latency);
+        } else {
+            samples[*next] = latency;
+        }
+        *next = (*next + 1) % QUERIER_LATENCY_SAMPLES;
+    }
+
+    fn snapshot(&self) -> z_querier_stats_t {
+        let mut samples = self
+            .latencies
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .0
+            .clone();
+        samples.sort_unstable();
+        let percentile = |p: usize| match samples.len() {
+            0 => 0,
+            len => samples[((len * p).div_ceil(100)).saturating_sub(1)],
+        };
+        z_querier_stats_t {
+            queries_sent: self.queries_sent.load(Ordering::Relaxed),
+            replies: self.replies.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            latency_p50_us: percentile(50),
+            latency_p90_us: percentile(90),
+            latency_p99_us: percentile(99),
+        }
+    }
+
+    fn reset(&self) {
+        self.queries_sent.store(0, Ordering::Relaxed);
+        self.replies.store(0, Ordering::Relaxed);
+        self.errors.store(0, Ordering::Relaxed);
+        self.timeouts.store(0, Ordering::Relaxed);
+        *self.latencies.lock().unwrap_or_else(|e| e.into_inner()) = Default::default();
+    }
+}
+
+#[cfg(feature = "unstable")]
+type ReplierReplies = (Option<EntityGlobalId>, Vec<Reply>);
+
+#[cfg(feature = "unstable")]
+/// The callback of a querier get, updating the querier statistics and holding back the replies to group them by replier if requested.
+struct QuerierReplyCallback {
+    callback: z_owned_closure_reply_t,
+    stats: Arc<QuerierStats>,
+    sent_at: Instant,
+    /// The moment zenoh ends the query with a timeout reply if it is still running.
+    deadline: Instant,
+    /// The replies of each replier in the order of their first reply, if grouping is requested.
+    groups: Option<Mutex<Vec<ReplierReplies>>>,
+}
+
+#[cfg(feature = "unstable")]
+impl QuerierReplyCallback {
+    fn new(
+        callback: z_owned_closure_reply_t,
+        stats: Arc<QuerierStats>,
+        timeout: Duration,
+        group: bool,
+    ) -> Self {
+        let sent_at = Instant::now();
+        QuerierReplyCallback {
+            callback,
+            stats,
+            sent_at,
+            deadline: sent_at + timeout,
+            groups: group.then(Default::default),
+        }
+    }
+
+    fn timed_out(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Returns the reply if it should be handed over to the callback right away.
+    fn handle(&self, reply: Reply) -> Option<Reply> {
+        // Once the timeout has elapsed, the only reply zenoh delivers is its timeout error, counted when the query ends.
+        if !(reply.result().is_err() && self.timed_out()) {
+            self.stats.record(&reply, self.sent_at);
+        }
+        let Some(groups) = self.groups.as_ref() else {
+            return Some(reply);
+        };
+        let replier_id = reply.replier_id();
+        let mut groups = groups.lock().unwrap_or_else(|e| e.into_inner());
+        match groups.iter_mut().find(|(id, _)| *id == replier_id) {
+            Some((_, replies)) => replies.push(reply),
+            None => groups.push((replier_id, vec![reply])),
+        }
+        None
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Deref for QuerierReplyCallback {
+    type Target = z_owned_closure_reply_t;
+    fn deref(&self) -> &Self::Target {
+        &self.callback
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Drop for QuerierReplyCallback {
+    fn drop(&mut self) {
+        // The callback is dropped when the query ends, which happens past the deadline only if it timed out.
+        if self.timed_out() {
+            self.stats.timeouts.fetch_add(1, Ordering::Relaxed);
+        }
+        let Some(groups) = self.groups.take() else {
+            return;
+        };
+        for reply in groups
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+            .into_iter()
+            .flat_map(|(_, r)| r)
+        {
+            let mut owned_reply = Some(reply);
+            z_closure_reply_call(z_closure_reply_loan(&self.callback), unsafe {
+                owned_reply
+                    .as_mut()
+                    .unwrap_unchecked()
+                    .as_loaned_c_type_mut()
+            });
+        }
+    }
+}
+
 /// @brief Constructs and declares a querier on the given key expression.
 ///
 /// The queries can be send with the help of the `z_querier_get()` function.
@@ -102,6 +289,8 @@ pub extern "C" fn z_declare_querier(
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref().clone().into_owned();
     let mut q = session.declare_querier(key_expr);
+    #[cfg(feature = "unstable")]
+    let mut timeout = Duration::from_millis(session.config().queries_default_timeout_ms());
     if let Some(options) = options {
         q = q
             .congestion_control(options.congestion_control.into())
@@ -113,6 +302,10 @@ pub extern "C" fn z_declare_querier(
             .accept_replies(options.accept_replies.into());
         if options.timeout_ms != 0 {
             q = q.timeout(std::time::Duration::from_millis(options.timeout_ms));
+            #[cfg(feature = "unstable")]
+            {
+                timeout = Duration::from_millis(options.timeout_ms);
+            }
         }
     }
     match q.wait() {
@@ -122,7 +315,13 @@ pub extern "C" fn z_declare_querier(
             result::Z_EGENERIC
         }
         Ok(querier) => {
-            this.write(Some(querier));
+            this.write(Some(CQuerier {
+                querier,
+                #[cfg(feature = "unstable")]
+                stats: Default::default(),
+                #[cfg(feature = "unstable")]
+                timeout,
+            }));
             result::Z_OK
         }
     }
@@ -184,6 +383,28 @@ pub struct z_querier_get_options_t {
     ///
     /// Cancellation token to interrupt the query.
     pub cancellation_token: Option<&'static mut z_moved_cancellation_token_t>,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, the replies are held back until the query is complete and then handed over grouped by replier,
+    /// in the order of the first reply of each replier (see `z_reply_replier_id()`).
+    pub group_by_replier: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, the query is interrupted as soon as its first successful reply is handed over to the callback, and no later reply reaches it.
+    /// Error replies received before are handed over as usual. Can not be combined with `group_by_replier`.
+    /// The querier should be declared with `Z_CONSOLIDATION_MODE_NONE` or `Z_CONSOLIDATION_MODE_MONOTONIC` consolidation,
+    /// otherwise replies may only be handed over once all of them are received.
+    pub first_reply_only: bool,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// If set to ``true``, replies sent by reply streams (see `z_reply_stream_new()`) are reassembled, and each stream is handed over
+    /// to the callback as a single reply carrying the whole payload once its last chunk is received. Other replies are handed over as is.
+    /// Streams which are incomplete when the query ends are discarded, and aborted streams are handed over as their
+    /// end-of-stream marker. The querier should be declared with `Z_CONSOLIDATION_MODE_NONE` consolidation for all the chunks to be received.
+    pub reassemble_chunks: bool,
 }
 
 impl z_querier_get_options_t {
@@ -216,6 +437,12 @@ pub extern "C" fn z_querier_get_options_default(this: &mut MaybeUninit<z_querier
         attachment: None,
         #[cfg(feature = "unstable")]
         cancellation_token: None,
+        #[cfg(feature = "unstable")]
+        group_by_replier: false,
+        #[cfg(feature = "unstable")]
+        first_reply_only: false,
+        #[cfg(feature = "unstable")]
+        reassemble_chunks: false,
     });
 }
 
@@ -245,6 +472,36 @@ pub unsafe extern "C" fn z_querier_get(
     )
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Query data from the matching queryables in the system.
+/// Replies are provided through a callback function.
+///
+/// @param querier: The querier to make query from.
+/// @param parameters: The query's selector parameters.
+/// @param callback: The callback function that will be called on reception of replies for this query. It will be automatically dropped once all replies are processed.
+/// @param options: Additional options for the get. All owned fields will be consumed.
+///
+/// @return 0 in case of success, a negative error value upon failure.
+#[no_mangle]
+pub extern "C" fn z_querier_get_with_parameters(
+    querier: &z_loaned_querier_t,
+    parameters: &z_loaned_parameters_t,
+    callback: &mut z_moved_closure_reply_t,
+    options: Option<&mut z_querier_get_options_t>,
+) -> result::z_result_t {
+    let parameters = parameters.as_rust_type_ref().as_str();
+    unsafe {
+        z_querier_get_with_parameters_substr(
+            querier,
+            parameters.as_ptr() as _,
+            parameters.len(),
+            callback,
+            options,
+        )
+    }
+}
+
 /// @brief Query data from the matching queryables in the system.
 /// Replies are provided through a callback function.
 ///
@@ -289,6 +546,14 @@ pub unsafe extern "C" fn z_querier_get_with_parameters_substr(
     };
 
     let mut get = querier.get();
+    #[cfg(feature = "unstable")]
+    let mut group_by_replier = false;
+    #[cfg(feature = "unstable")]
+    let mut cancellation_token = None;
+    #[cfg(feature = "unstable")]
+    let mut first_reply_only = false;
+    #[cfg(feature = "unstable")]
+    let mut reassembler = None;
     if let Some(options) = options {
         if let Some(payload) = options.payload.take() {
             get = get.payload(payload.take_rust_type());
@@ -304,19 +569,73 @@ pub unsafe extern "C" fn z_querier_get_with_parameters_substr(
             get = get.attachment(attachment.take_rust_type());
         }
         #[cfg(feature = "unstable")]
-        if let Some(ct) = options
-            .cancellation_token
-            .take()
-            .and_then(|ct| ct.take_rust_type())
         {
+            cancellation_token = options
+                .cancellation_token
+                .take()
+                .and_then(|ct| ct.take_rust_type());
+            group_by_replier = options.group_by_replier;
+            first_reply_only = options.first_reply_only;
+            if options.reassemble_chunks {
+                reassembler = Some(ReplyReassembler::default());
+            }
+        }
+    }
+    #[cfg(feature = "unstable")]
+    if first_reply_only && group_by_replier {
+        crate::report_error!("first_reply_only can not be combined with group_by_replier");
+        return result::Z_EINVAL;
+    }
+    #[cfg(feature = "unstable")]
+    let race = if first_reply_only {
+        let (race, token) = FirstReplyRace::new(cancellation_token);
+        get = get.cancellation_token(token);
+        Some(race)
+    } else {
+        if let Some(ct) = cancellation_token {
             get = get.cancellation_token(ct);
         }
+        None
+    };
+    #[allow(unused_mut)]
+    let mut parameters = Parameters::from(p);
+    // lets reply streams know that they are reassembled, see `z_reply_stream_new()`
+    #[cfg(feature = "unstable")]
+    if reassembler.is_some() {
+        parameters.insert(REASSEMBLE_PARAMETER, "");
     }
-    if !p.is_empty() {
-        get = get.parameters(p);
+    if !parameters.is_empty() {
+        get = get.parameters(parameters);
     }
+    #[cfg(feature = "unstable")]
+    let callback = QuerierReplyCallback::new(
+        callback,
+        querier.stats.clone(),
+        querier.timeout,
+        group_by_replier,
+    );
     match get
         .callback(move |response| {
+            #[cfg(feature = "unstable")]
+            let response = match reassembler.as_ref() {
+                Some(reassembler) => match reassembler.push(response) {
+                    Some(response) => response,
+                    None => return,
+                },
+                None => response,
+            };
+            #[cfg(feature = "unstable")]
+            let Some(response) = callback.handle(response) else {
+                return;
+            };
+            #[cfg(feature = "unstable")]
+            let _won = match race.as_ref() {
+                Some(race) => match race.admit(&response) {
+                    Some(won) => Some(won),
+                    None => return,
+                },
+                None => None,
+            };
             let mut owned_response = Some(response);
             z_closure_reply_call(
                 z_closure_reply_loan(&callback),
@@ -328,7 +647,11 @@ pub unsafe extern "C" fn z_querier_get_with_parameters_substr(
         })
         .wait()
     {
-        Ok(()) => result::Z_OK,
+        Ok(()) => {
+            #[cfg(feature = "unstable")]
+            querier.stats.queries_sent.fetch_add(1, Ordering::Relaxed);
+            result::Z_OK
+        }
         Err(e) if e.downcast_ref::<SessionClosedError>().is_some() => result::Z_ESESSION_CLOSED,
         Err(e) => {
             crate::report_error!("{}", e);
@@ -441,6 +764,52 @@ pub extern "C" fn z_querier_get_matching_status(
     }
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Statistics of the queries sent by a querier.
+#[repr(C)]
+pub struct z_querier_stats_t {
+    /// The number of queries successfully sent.
+    pub queries_sent: u64,
+    /// The number of successful replies received.
+    pub replies: u64,
+    /// The number of error replies received from queryables.
+    pub errors: u64,
+    /// The number of queries that timed out before all the replies were received.
+    pub timeouts: u64,
+    /// The median latency of the latest replies in microseconds, measured from the sending of the query.
+    pub latency_p50_us: u64,
+    /// The 90th percentile latency of the latest replies in microseconds.
+    pub latency_p90_us: u64,
+    /// The 99th percentile latency of the latest replies in microseconds.
+    pub latency_p99_us: u64,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns the statistics of the queries sent by the querier since its declaration or the last call to `z_querier_reset_stats()`.
+///
+/// Both successful and error replies count towards the latency percentiles, which are computed on the latest 1024 replies
+/// and are 0 if no reply was received.
+///
+/// @param querier: The querier to get the statistics of.
+/// @param stats: An uninitialized location in memory where the statistics will be written.
+#[no_mangle]
+pub extern "C" fn z_querier_stats(
+    querier: &z_loaned_querier_t,
+    stats: &mut MaybeUninit<z_querier_stats_t>,
+) {
+    stats.write(querier.as_rust_type_ref().stats.snapshot());
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Resets the statistics of the querier.
+#[no_mangle]
+pub extern "C" fn z_querier_reset_stats(querier: &z_loaned_querier_t) {
+    querier.as_rust_type_ref().stats.reset();
+}
+
 /// @brief Frees memory and resets querier to its gravestone state.
 /// This is equivalent to calling `z_undeclare_querier()` and discarding its return value.
 #[no_mangle]
@@ -455,7 +824,7 @@ pub extern "C" fn z_querier_drop(this: &mut z_moved_querier_t) {
 #[no_mangle]
 pub extern "C" fn z_undeclare_querier(this_: &mut z_moved_querier_t) -> result::z_result_t {
     if let Some(q) = this_.take_rust_type() {
-        if let Err(e) = q.undeclare().wait_callbacks().wait() {
+        if let Err(e) = q.querier.undeclare().wait_callbacks().wait() {
             crate::report_error!("{}", e);
             return result::Z_ENETWORK;
         }
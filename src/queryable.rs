@@ -11,17 +11,33 @@
 // Contributors:
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
-use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+use std::{
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::{atomic::AtomicBool, Arc, RwLock},
+};
 
+#[cfg(feature = "unstable")]
+use zenoh::sample::SourceInfo;
 use zenoh::{
-    bytes::Encoding,
+    bytes::{Encoding, ZBytes},
     handlers::Callback,
+    key_expr::KeyExpr,
     qos::{CongestionControl, Priority},
-    query::{Query, Queryable, QueryableBuilder},
+    query::{Parameters, Query, Queryable, QueryableBuilder, ReplyKeyExpr},
     Wait,
 };
 
 pub use crate::opaque_types::{z_loaned_queryable_t, z_owned_queryable_t};
+#[cfg(feature = "unstable")]
+use crate::{
+    _is_paused, transmute::IntoCType, z_entity_global_id_t, z_loaned_parameters_t, z_source_info_t,
+};
 use crate::{
     result,
     transmute::{IntoRustType, LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
@@ -30,10 +46,23 @@ use crate::{
     z_moved_bytes_t, z_moved_closure_query_t, z_moved_encoding_t, z_moved_queryable_t,
     z_priority_t, z_reply_keyexpr_t, z_timestamp_t, z_view_string_from_substr, z_view_string_t,
 };
-#[cfg(feature = "unstable")]
-use crate::{transmute::IntoCType, z_entity_global_id_t, z_source_info_t};
+
+pub struct CQueryable {
+    queryable: Queryable<()>,
+    #[cfg(feature = "unstable")]
+    paused: Arc<AtomicBool>,
+}
+
+impl Deref for CQueryable {
+    type Target = Queryable<()>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.queryable
+    }
+}
+
 decl_c_type!(
-    owned(z_owned_queryable_t, option Queryable<()>),
+    owned(z_owned_queryable_t, option CQueryable),
     loaned(z_loaned_queryable_t),
 );
 
@@ -55,11 +84,168 @@ pub unsafe extern "C" fn z_queryable_loan(this_: &z_owned_queryable_t) -> &z_loa
 }
 
 pub use crate::opaque_types::{z_loaned_query_t, z_moved_query_t, z_owned_query_t};
+
+/// The part of a query shared by all its clones.
+struct QueryShared {
+    /// The query used to reply, `None` once the query is finalized.
+    query: RwLock<Option<Query>>,
+    key_expr: KeyExpr<'static>,
+    parameters: Parameters<'static>,
+    accepts_replies: ReplyKeyExpr,
+    #[cfg(feature = "unstable")]
+    source_info: Option<SourceInfo>,
+    /// Released once the query is finalized.
+    #[cfg(feature = "unstable")]
+    in_flight: Mutex<Option<InFlightSlot>>,
+}
+
+#[cfg(feature = "unstable")]
+/// The state given to a query by the queryable which received it.
+#[derive(Default)]
+struct QueryState {
+    in_flight: Option<InFlightSlot>,
+}
+
+#[cfg(feature = "unstable")]
+/// A query counted in the in-flight queries of a queryable.
+struct InFlightSlot(Arc<AtomicUsize>);
+
+#[cfg(feature = "unstable")]
+impl Drop for InFlightSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// The limits applied by a queryable to the queries it receives, owned by its callback.
+struct QueryableState {
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: usize,
+    overload_policy: z_queryable_overload_policy_t,
+}
+
+#[cfg(feature = "unstable")]
+impl QueryableState {
+    fn new(options: Option<&z_queryable_options_t>) -> Self {
+        let (max_in_flight, overload_policy) = match options {
+            Some(o) => (o.max_in_flight, o.overload_policy),
+            None => (0, z_queryable_overload_policy_t::REPLY_ERR),
+        };
+        QueryableState {
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight,
+            overload_policy,
+        }
+    }
+
+    /// Returns the state of a newly received query, or `None` if the queryable is overloaded,
+    /// in which case the query is handled according to the overload policy.
+    fn accept(&self, query: &Query) -> Option<QueryState> {
+        let mut in_flight = None;
+        if self.max_in_flight != 0 {
+            let acquired = self
+                .in_flight
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+                    (n < self.max_in_flight).then_some(n + 1)
+                })
+                .is_ok();
+            if !acquired {
+                if self.overload_policy == z_queryable_overload_policy_t::REPLY_ERR {
+                    if let Err(e) = query.reply_err("queryable overloaded").wait() {
+                        crate::report_error!("{}", e);
+                    }
+                }
+                return None;
+            }
+            in_flight = Some(InFlightSlot(self.in_flight.clone()));
+        }
+        Some(QueryState { in_flight })
+    }
+}
+
+/// A query received by a queryable.
+///
+/// The zenoh query is kept in a state shared by all the clones, so that dropping it finalizes the query
+/// for all of them, while its content stays accessible.
+#[derive(Clone)]
+pub struct CQuery {
+    shared: Arc<QueryShared>,
+    value: Option<(ZBytes, Encoding)>,
+    attachment: Option<ZBytes>,
+}
+
 decl_c_type!(
-    owned(z_owned_query_t, option Query),
+    owned(z_owned_query_t, option CQuery),
     loaned(z_loaned_query_t),
 );
 
+impl CQuery {
+    fn new(mut query: Query, #[cfg(feature = "unstable")] state: QueryState) -> Self {
+        let encoding = query.encoding().cloned().unwrap_or_default();
+        let value = query.payload_mut().map(|p| (std::mem::take(p), encoding));
+        let attachment = query.attachment_mut().map(std::mem::take);
+        CQuery {
+            shared: Arc::new(QueryShared {
+                key_expr: query.key_expr().clone(),
+                parameters: query.parameters().clone(),
+                accepts_replies: query.accepts_replies(),
+                #[cfg(feature = "unstable")]
+                source_info: query.source_info().cloned(),
+                #[cfg(feature = "unstable")]
+                in_flight: Mutex::new(state.in_flight),
+                query: RwLock::new(Some(query)),
+            }),
+            value,
+            attachment,
+        }
+    }
+
+    fn empty() -> Self {
+        let query = CQuery::new(
+            Query::empty(),
+            #[cfg(feature = "unstable")]
+            QueryState::default(),
+        );
+        query.finalize();
+        query
+    }
+
+    /// Returns the query to reply with, fails if it was finalized.
+    /// The returned query keeps the zenoh query open while replying, so that a concurrent finalization is sent after the reply.
+    pub(crate) fn reply_handle(&self) -> Result<Query, result::z_result_t> {
+        match self
+            .shared
+            .query
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+        {
+            Some(query) => Ok(query.clone()),
+            None => {
+                crate::report_error!("The query has already been finalized");
+                Err(result::Z_EINVAL)
+            }
+        }
+    }
+
+    fn finalize(&self) {
+        let query = self
+            .shared
+            .query
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        drop(query);
+        #[cfg(feature = "unstable")]
+        self.shared
+            .in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+    }
+}
+
 /// Constructs query in its gravestone value.
 #[no_mangle]
 pub extern "C" fn z_internal_query_null(this_: &mut MaybeUninit<z_owned_query_t>) {
@@ -104,7 +290,7 @@ pub unsafe extern "C" fn z_query_take_from_loaned(
 ) {
     let dst = dst.as_rust_type_mut_uninit();
     let src = src.as_rust_type_mut();
-    let src = std::mem::replace(src, Query::empty());
+    let src = std::mem::replace(src, CQuery::empty());
     dst.write(Some(src));
 }
 
@@ -122,6 +308,19 @@ pub extern "C" fn z_query_clone(dst: &mut MaybeUninit<z_owned_query_t>, this_: &
         .write(Some(this_.as_rust_type_ref().clone()));
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Finalizes the query, signaling to the querier that no more replies will be sent by this queryable.
+///
+/// The query is finalized for all its clones at once, without waiting for them to be dropped. Replying to it afterwards fails,
+/// while its key expression, parameters, payload and attachment can still be accessed. Finalizing an already finalized query is a no-op.
+/// If the same query was also received by other queryables of the session, the querier receives the finalization once all of them
+/// have finalized or dropped it.
+#[no_mangle]
+pub extern "C" fn z_query_finalize(this_: &z_loaned_query_t) {
+    this_.as_rust_type_ref().finalize();
+}
+
 /// Options passed to the `z_declare_queryable()` function.
 #[allow(non_camel_case_types)]
 #[repr(C)]
@@ -131,6 +330,31 @@ pub struct z_queryable_options_t {
     /// Restricts the matching requests that will be received by this Queryable to the ones
     /// that have the compatible allowed_destination.
     pub allowed_origin: z_locality_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The maximum number of queries in flight, 0 meaning unlimited. A query is in flight from its reception until it is
+    /// finalized, either explicitly with `z_query_finalize()` or when it and all its clones are dropped.
+    /// Queries received while the limit is reached are handled according to `overload_policy` and not passed to the callback.
+    pub max_in_flight: usize,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The handling of queries received while `max_in_flight` queries are in flight.
+    pub overload_policy: z_queryable_overload_policy_t,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The handling of queries received by an overloaded queryable.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_queryable_overload_policy_t {
+    /// The query is answered with an error reply carrying the ``"queryable overloaded"`` string.[Default]
+    REPLY_ERR = 0,
+    /// The query is dropped without reply.
+    DROP = 1,
 }
 /// Constructs the default value for `z_query_reply_options_t`.
 #[no_mangle]
@@ -139,6 +363,10 @@ pub extern "C" fn z_queryable_options_default(this_: &mut MaybeUninit<z_queryabl
     this_.write(z_queryable_options_t {
         complete: false,
         allowed_origin: z_locality_default(),
+        #[cfg(feature = "unstable")]
+        max_in_flight: 0,
+        #[cfg(feature = "unstable")]
+        overload_policy: z_queryable_overload_policy_t::REPLY_ERR,
     });
 }
 
@@ -168,6 +396,17 @@ pub struct z_query_reply_options_t {
     pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
+impl z_query_reply_options_t {
+    fn clear(&mut self) {
+        if let Some(e) = self.encoding.take() {
+            e.take_rust_type();
+        }
+        if let Some(a) = self.attachment.take() {
+            a.take_rust_type();
+        }
+    }
+}
+
 /// Constructs the default value for `z_query_reply_options_t`.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
@@ -226,6 +465,14 @@ pub struct z_query_reply_del_options_t {
     pub attachment: Option<&'static mut z_moved_bytes_t>,
 }
 
+impl z_query_reply_del_options_t {
+    fn clear(&mut self) {
+        if let Some(a) = self.attachment.take() {
+            a.take_rust_type();
+        }
+    }
+}
+
 /// Constructs the default value for `z_query_reply_del_options_t`.
 #[no_mangle]
 #[allow(clippy::missing_safety_doc)]
@@ -248,18 +495,35 @@ fn _declare_queryable_inner<'a, 'b>(
     key_expr: &'b z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_query_t,
     options: Option<&mut z_queryable_options_t>,
+    paused: Option<Arc<AtomicBool>>,
 ) -> QueryableBuilder<'a, 'b, Callback<Query>> {
+    #[cfg(not(feature = "unstable"))]
+    let _ = paused;
     let session = session.as_rust_type_ref();
     let keyexpr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
     let mut builder = session.declare_queryable(keyexpr);
-    if let Some(options) = options {
+    if let Some(options) = options.as_ref() {
         builder = builder
             .complete(options.complete)
             .allowed_origin(options.allowed_origin.into());
     }
+    #[cfg(feature = "unstable")]
+    let state = Arc::new(QueryableState::new(options.as_deref()));
     let queryable = builder.callback(move |query| {
-        let mut owned_query = Some(query);
+        #[cfg(feature = "unstable")]
+        if _is_paused(&paused) {
+            return;
+        }
+        #[cfg(feature = "unstable")]
+        let Some(query_state) = state.accept(&query) else {
+            return;
+        };
+        let mut owned_query = Some(CQuery::new(
+            query,
+            #[cfg(feature = "unstable")]
+            query_state,
+        ));
         z_closure_query_call(z_closure_query_loan(&callback), unsafe {
             owned_query
                 .as_mut()
@@ -288,10 +552,20 @@ pub extern "C" fn z_declare_queryable(
     options: Option<&mut z_queryable_options_t>,
 ) -> result::z_result_t {
     let this = queryable.as_rust_type_mut_uninit();
-    let queryable = _declare_queryable_inner(session, key_expr, callback, options);
+    #[cfg(feature = "unstable")]
+    let paused = Arc::new(AtomicBool::new(false));
+    #[cfg(feature = "unstable")]
+    let queryable =
+        _declare_queryable_inner(session, key_expr, callback, options, Some(paused.clone()));
+    #[cfg(not(feature = "unstable"))]
+    let queryable = _declare_queryable_inner(session, key_expr, callback, options, None);
     match queryable.wait() {
         Ok(q) => {
-            this.write(Some(q));
+            this.write(Some(CQueryable {
+                queryable: q,
+                #[cfg(feature = "unstable")]
+                paused,
+            }));
             result::Z_OK
         }
         Err(e) => {
@@ -318,7 +592,7 @@ pub extern "C" fn z_declare_background_queryable(
     callback: &mut z_moved_closure_query_t,
     options: Option<&mut z_queryable_options_t>,
 ) -> result::z_result_t {
-    let queryable = _declare_queryable_inner(session, key_expr, callback, options);
+    let queryable = _declare_queryable_inner(session, key_expr, callback, options, None);
     match queryable.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -328,6 +602,41 @@ pub extern "C" fn z_declare_background_queryable(
     }
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Pauses the queryable: queries received while it is paused are dropped without calling its callback,
+/// so that the querier receives no reply from this queryable.
+///
+/// Pausing is local: the queryable stays declared on the network and keeps its id, so matching queries are still routed
+/// to this session. Zenoh can not suspend the routing of a declared queryable, undeclare it to stop receiving queries
+/// from the network. Pausing an already paused queryable is a no-op.
+#[no_mangle]
+pub extern "C" fn z_queryable_pause(this_: &z_loaned_queryable_t) {
+    this_
+        .as_rust_type_ref()
+        .paused
+        .store(true, Ordering::Relaxed);
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Resumes the delivery of queries to the callback of a queryable paused with `z_queryable_pause()`.
+#[no_mangle]
+pub extern "C" fn z_queryable_resume(this_: &z_loaned_queryable_t) {
+    this_
+        .as_rust_type_ref()
+        .paused
+        .store(false, Ordering::Relaxed);
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the queryable is paused, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_queryable_is_paused(this_: &z_loaned_queryable_t) -> bool {
+    this_.as_rust_type_ref().paused.load(Ordering::Relaxed)
+}
+
 /// Undeclares queryable callback and resets it to its gravestone state.
 /// This is equivalent to calling `z_undeclare_queryable()` and discarding its return value.
 #[allow(clippy::missing_safety_doc)]
@@ -371,9 +680,17 @@ pub extern "C" fn z_query_reply(
     payload: &mut z_moved_bytes_t,
     options: Option<&mut z_query_reply_options_t>,
 ) -> result::z_result_t {
-    let query = this.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let payload = payload.take_rust_type();
+    let query = match this.as_rust_type_ref().reply_handle() {
+        Ok(query) => query,
+        Err(e) => {
+            if let Some(options) = options {
+                options.clear();
+            }
+            return e;
+        }
+    };
     let mut reply = query.reply(key_expr, payload);
     if let Some(options) = options {
         if let Some(encoding) = options.encoding.take() {
@@ -418,14 +735,16 @@ pub unsafe extern "C" fn z_query_reply_err(
     payload: &mut z_moved_bytes_t,
     options: Option<&mut z_query_reply_err_options_t>,
 ) -> result::z_result_t {
-    let query = this.as_rust_type_ref();
     let payload = payload.take_rust_type();
-    let reply = query.reply_err(payload).encoding(
-        options
-            .and_then(|o| o.encoding.take())
-            .map(|e| e.take_rust_type())
-            .unwrap_or(Encoding::default()),
-    );
+    let encoding = options
+        .and_then(|o| o.encoding.take())
+        .map(|e| e.take_rust_type())
+        .unwrap_or(Encoding::default());
+    let query = match this.as_rust_type_ref().reply_handle() {
+        Ok(query) => query,
+        Err(e) => return e,
+    };
+    let reply = query.reply_err(payload).encoding(encoding);
 
     if let Err(e) = reply.wait() {
         crate::report_error!("{}", e);
@@ -453,9 +772,16 @@ pub unsafe extern "C" fn z_query_reply_del(
     key_expr: &z_loaned_keyexpr_t,
     options: Option<&mut z_query_reply_del_options_t>,
 ) -> result::z_result_t {
-    let query = this.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
-
+    let query = match this.as_rust_type_ref().reply_handle() {
+        Ok(query) => query,
+        Err(e) => {
+            if let Some(options) = options {
+                options.clear();
+            }
+            return e;
+        }
+    };
     let mut reply = query.reply_del(key_expr);
     if let Some(options) = options {
         #[cfg(feature = "unstable")]
@@ -482,7 +808,11 @@ pub unsafe extern "C" fn z_query_reply_del(
 #[allow(clippy::missing_safety_doc)]
 #[no_mangle]
 pub extern "C" fn z_query_keyexpr(this_: &z_loaned_query_t) -> &z_loaned_keyexpr_t {
-    this_.as_rust_type_ref().key_expr().as_loaned_c_type_ref()
+    this_
+        .as_rust_type_ref()
+        .shared
+        .key_expr
+        .as_loaned_c_type_ref()
 }
 
 /// Gets query <a href="https://github.com/eclipse-zenoh/roadmap/tree/main/rfcs/ALL/Selectors">value selector</a>.
@@ -493,10 +823,22 @@ pub unsafe extern "C" fn z_query_parameters(
     parameters: &mut MaybeUninit<z_view_string_t>,
 ) {
     let query = this.as_rust_type_ref();
-    let params = query.parameters().as_str();
+    let params = query.shared.parameters.as_str();
     unsafe { z_view_string_from_substr(parameters, params.as_ptr() as _, params.len()) };
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the parsed selector parameters of the query.
+#[no_mangle]
+pub extern "C" fn z_query_selector_parameters(this_: &z_loaned_query_t) -> &z_loaned_parameters_t {
+    this_
+        .as_rust_type_ref()
+        .shared
+        .parameters
+        .as_loaned_c_type_ref()
+}
+
 /// Gets query <a href="https://github.com/eclipse-zenoh/roadmap/blob/main/rfcs/ALL/Query%20Payload.md">payload</a>.
 ///
 /// Returns NULL if query does not contain a payload.
@@ -504,8 +846,9 @@ pub unsafe extern "C" fn z_query_parameters(
 pub extern "C" fn z_query_payload(this_: &z_loaned_query_t) -> Option<&z_loaned_bytes_t> {
     this_
         .as_rust_type_ref()
-        .payload()
-        .map(|v| v.as_loaned_c_type_ref())
+        .value
+        .as_ref()
+        .map(|(p, _)| p.as_loaned_c_type_ref())
 }
 
 /// Gets mutable query <a href="https://github.com/eclipse-zenoh/roadmap/blob/main/rfcs/ALL/Query%20Payload.md">payload</a>.
@@ -517,8 +860,9 @@ pub extern "C" fn z_query_payload_mut(
 ) -> Option<&mut z_loaned_bytes_t> {
     this_
         .as_rust_type_mut()
-        .payload_mut()
-        .map(|v| v.as_loaned_c_type_mut())
+        .value
+        .as_mut()
+        .map(|(p, _)| p.as_loaned_c_type_mut())
 }
 
 /// Gets query <a href="https://github.com/eclipse-zenoh/roadmap/blob/main/rfcs/ALL/Query%20Payload.md">payload encoding</a>.
@@ -528,8 +872,9 @@ pub extern "C" fn z_query_payload_mut(
 pub extern "C" fn z_query_encoding(this_: &z_loaned_query_t) -> Option<&z_loaned_encoding_t> {
     this_
         .as_rust_type_ref()
-        .encoding()
-        .map(|v| v.as_loaned_c_type_ref())
+        .value
+        .as_ref()
+        .map(|(_, e)| e.as_loaned_c_type_ref())
 }
 
 /// Gets query attachment.
@@ -539,7 +884,8 @@ pub extern "C" fn z_query_encoding(this_: &z_loaned_query_t) -> Option<&z_loaned
 pub extern "C" fn z_query_attachment(this_: &z_loaned_query_t) -> Option<&z_loaned_bytes_t> {
     this_
         .as_rust_type_ref()
-        .attachment()
+        .attachment
+        .as_ref()
         .map(|a| a.as_loaned_c_type_ref())
 }
 
@@ -552,7 +898,8 @@ pub extern "C" fn z_query_attachment_mut(
 ) -> Option<&mut z_loaned_bytes_t> {
     this_
         .as_rust_type_mut()
-        .attachment_mut()
+        .attachment
+        .as_mut()
         .map(|a| a.as_loaned_c_type_mut())
 }
 
@@ -565,7 +912,9 @@ pub extern "C" fn z_query_source_info(this_: &z_loaned_query_t) -> Option<&z_sou
 
     this_
         .as_rust_type_ref()
-        .source_info()
+        .shared
+        .source_info
+        .as_ref()
         .map(|si| si.as_ctype_ref())
 }
 
@@ -573,7 +922,7 @@ pub extern "C" fn z_query_source_info(this_: &z_loaned_query_t) -> Option<&z_sou
 /// i.e. which replies are accepted by the query originator.
 #[no_mangle]
 pub extern "C" fn z_query_accepts_replies(this_: &z_loaned_query_t) -> z_reply_keyexpr_t {
-    this_.as_rust_type_ref().accepts_replies().into()
+    this_.as_rust_type_ref().shared.accepts_replies.into()
 }
 
 /// Undeclares a `z_owned_queryable_t`.
@@ -581,7 +930,7 @@ pub extern "C" fn z_query_accepts_replies(this_: &z_loaned_query_t) -> z_reply_k
 #[no_mangle]
 pub extern "C" fn z_undeclare_queryable(this_: &mut z_moved_queryable_t) -> result::z_result_t {
     if let Some(qable) = this_.take_rust_type() {
-        if let Err(e) = qable.undeclare().wait_callbacks().wait() {
+        if let Err(e) = qable.queryable.undeclare().wait_callbacks().wait() {
             crate::report_error!("{}", e);
             return result::Z_EGENERIC;
         }
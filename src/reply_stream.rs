@@ -0,0 +1,535 @@
+//
+// Copyright (c) 2025 ZettaScale Technology.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh team, <zenoh@zettascale.tech>
+//
+
+use std::{
+    collections::{HashMap, HashSet},
+    mem::MaybeUninit,
+    sync::Mutex,
+};
+
+use zenoh::{
+    bytes::{Encoding, ZBytes, ZBytesWriter},
+    internal::buffers::{HasReader, Reader, ZBuf},
+    key_expr::KeyExpr,
+    query::Reply,
+    sample::{Sample, SampleBuilder, SampleBuilderPut},
+    Wait,
+};
+
+use crate::{
+    opaque_types::{z_loaned_reply_stream_t, z_moved_reply_stream_t, z_owned_reply_stream_t},
+    result,
+    transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
+    z_loaned_keyexpr_t, z_loaned_query_t, z_loaned_reply_t, z_moved_bytes_t, z_moved_encoding_t,
+    CQuery,
+};
+
+/// Chunks are marked by this schema prefix, reserved by zenoh-c, which is followed by the chunk header
+/// and by the schema of the streamed payload, if any.
+const CHUNK_SCHEMA_PREFIX: &[u8] = b"zenoh-c/reply-stream-chunk:";
+/// The schema of the error reply sent ahead of a stream to the queries reassembling it.
+const BROKEN_STREAM_SCHEMA_PREFIX: &str = "zenoh-c/reply-stream-broken:";
+/// The query parameter set by the queries reassembling reply streams.
+pub(crate) const REASSEMBLE_PARAMETER: &str = "_zc_reassemble";
+const CHUNK_FLAG_LAST: u8 = 0x01;
+const CHUNK_FLAG_ABORTED: u8 = 0x02;
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The header carried in the encoding schema of each chunk of a streamed reply.
+struct ChunkHeader {
+    stream_id: u64,
+    index: u64,
+    flags: u8,
+}
+
+impl ChunkHeader {
+    /// Returns `encoding` with the header prepended to its schema.
+    fn encode(&self, encoding: &Encoding) -> Encoding {
+        let mut schema = CHUNK_SCHEMA_PREFIX.to_vec();
+        schema.extend_from_slice(
+            format!("{:016x}:{}:{}:", self.stream_id, self.index, self.flags).as_bytes(),
+        );
+        if let Some(s) = encoding.schema() {
+            schema.extend_from_slice(s);
+        }
+        Encoding::new(encoding.id(), Some(schema.into()))
+    }
+
+    /// Returns the header of a chunk encoding, along with the encoding of the streamed payload.
+    fn decode(encoding: &Encoding) -> Option<(Self, Encoding)> {
+        let schema = encoding.schema()?.strip_prefix(CHUNK_SCHEMA_PREFIX)?;
+        let mut fields = schema.splitn(4, |b| *b == b':');
+        let mut field = || std::str::from_utf8(fields.next()?).ok();
+        let header = ChunkHeader {
+            stream_id: u64::from_str_radix(field()?, 16).ok()?,
+            index: field()?.parse().ok()?,
+            flags: field()?.parse().ok()?,
+        };
+        let schema = fields.next()?;
+        let schema = (!schema.is_empty()).then(|| schema.to_vec().into());
+        Some((header, Encoding::new(encoding.id(), schema)))
+    }
+
+    fn from_reply(reply: &Reply) -> Option<Self> {
+        let sample = reply.result().ok()?;
+        ChunkHeader::decode(sample.encoding()).map(|(header, _)| header)
+    }
+}
+
+/// Returns the id of the stream announced by an error reply sent ahead of it.
+fn broken_stream_id(reply: &Reply) -> Option<u64> {
+    let schema = reply.result().err()?.encoding().schema()?;
+    let id = std::str::from_utf8(schema)
+        .ok()?
+        .strip_prefix(BROKEN_STREAM_SCHEMA_PREFIX)?;
+    u64::from_str_radix(id, 16).ok()
+}
+
+pub struct CReplyStream {
+    query: CQuery,
+    key_expr: KeyExpr<'static>,
+    encoding: Encoding,
+    chunk_size: usize,
+    is_express: bool,
+    stream_id: u64,
+    next_index: u64,
+    pending: ZBytesWriter,
+    pending_len: usize,
+}
+
+decl_c_type!(
+    owned(z_owned_reply_stream_t, option CReplyStream),
+    loaned(z_loaned_reply_stream_t),
+);
+
+impl CReplyStream {
+    fn new(
+        query: &z_loaned_query_t,
+        key_expr: &z_loaned_keyexpr_t,
+        options: Option<&mut z_reply_stream_options_t>,
+    ) -> Result<Self, result::z_result_t> {
+        let mut stream = CReplyStream {
+            query: query.as_rust_type_ref().clone(),
+            key_expr: key_expr.as_rust_type_ref().clone().into_owned(),
+            encoding: Encoding::default(),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            is_express: false,
+            stream_id: rand::random(),
+            next_index: 0,
+            pending: ZBytes::writer(),
+            pending_len: 0,
+        };
+        if let Some(options) = options {
+            if let Some(encoding) = options.encoding.take() {
+                stream.encoding = encoding.take_rust_type();
+            }
+            if options.chunk_size != 0 {
+                stream.chunk_size = options.chunk_size;
+            }
+            stream.is_express = options.is_express;
+        }
+        let query = stream.query.reply_handle()?;
+        // Replies can only be created by zenoh, so the error reply handed over in place of a broken stream is sent ahead of it.
+        if query.parameters().contains_key(REASSEMBLE_PARAMETER) {
+            let reply = query
+                .reply_err(format!("Reply stream {:016x} is broken", stream.stream_id))
+                .encoding(Encoding::ZENOH_STRING.with_schema(format!(
+                    "{}{:016x}",
+                    BROKEN_STREAM_SCHEMA_PREFIX, stream.stream_id
+                )));
+            if let Err(e) = reply.wait() {
+                crate::report_error!("{}", e);
+                return Err(result::Z_EGENERIC);
+            }
+        }
+        Ok(stream)
+    }
+
+    fn send_chunk(&mut self, payload: ZBytes, flags: u8) -> result::z_result_t {
+        let header = ChunkHeader {
+            stream_id: self.stream_id,
+            index: self.next_index,
+            flags,
+        };
+        self.next_index += 1;
+        let query = match self.query.reply_handle() {
+            Ok(query) => query,
+            Err(e) => return e,
+        };
+        let reply = query
+            .reply(&self.key_expr, payload)
+            .encoding(header.encode(&self.encoding))
+            .express(self.is_express);
+        match reply.wait() {
+            Ok(_) => result::Z_OK,
+            Err(e) => {
+                crate::report_error!("{}", e);
+                result::Z_EGENERIC
+            }
+        }
+    }
+
+    fn take_pending(&mut self) -> ZBytes {
+        self.pending_len = 0;
+        std::mem::replace(&mut self.pending, ZBytes::writer()).finish()
+    }
+
+    // The data is split into chunks without copying it: each chunk refers to the slices of the original payload.
+    fn send(&mut self, data: ZBytes) -> result::z_result_t {
+        let data = ZBuf::from(data);
+        let mut reader = data.reader();
+        while reader.can_read() {
+            let len = reader.remaining().min(self.chunk_size - self.pending_len);
+            match reader.read_zbuf(len) {
+                Ok(slice) => self.pending.append(slice.into()),
+                Err(_) => return result::Z_EIO,
+            }
+            self.pending_len += len;
+            if self.pending_len == self.chunk_size {
+                let chunk = self.take_pending();
+                let res = self.send_chunk(chunk, 0);
+                if res != result::Z_OK {
+                    return res;
+                }
+            }
+        }
+        result::Z_OK
+    }
+
+    fn finish(mut self) -> result::z_result_t {
+        let chunk = self.take_pending();
+        self.send_chunk(chunk, CHUNK_FLAG_LAST)
+    }
+
+    fn abort(mut self) -> result::z_result_t {
+        self.send_chunk(ZBytes::new(), CHUNK_FLAG_LAST | CHUNK_FLAG_ABORTED)
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Options passed to the `z_reply_stream_new()` and `z_query_reply_chunked()` functions.
+#[repr(C)]
+pub struct z_reply_stream_options_t {
+    /// The encoding of the streamed payload.
+    pub encoding: Option<&'static mut z_moved_encoding_t>,
+    /// The maximum size of a chunk in bytes, 0 meaning the default of 64 KiB.
+    pub chunk_size: usize,
+    /// If set to ``true``, the chunks will not be batched. This usually has a positive impact on latency but negative impact on throughput.
+    pub is_express: bool,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_reply_stream_options_t`.
+#[no_mangle]
+pub extern "C" fn z_reply_stream_options_default(
+    this_: &mut MaybeUninit<z_reply_stream_options_t>,
+) {
+    this_.write(z_reply_stream_options_t {
+        encoding: None,
+        chunk_size: DEFAULT_CHUNK_SIZE,
+        is_express: false,
+    });
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Starts a streamed reply to a query.
+///
+/// A reply stream sends a large payload as an ordered series of chunks: each chunk is a regular reply on `key_expr`,
+/// whose encoding carries a schema reserved by zenoh-c, which identifies the stream, the position of the chunk in it and
+/// whether it is the last one (see `z_reply_chunk_info()`), followed by the schema of the stream encoding.
+/// Data is added with `z_reply_stream_send()` and the stream is completed with `z_reply_stream_finish()`.
+/// Dropping a stream without finishing it sends an aborted end-of-stream marker instead.
+///
+/// When the query reassembles the stream, an error reply is also sent ahead of the chunks, which is handed over in place
+/// of the stream if one of its chunks is lost.
+///
+/// Chunks are subject to the congestion control of the query: with `Z_CONGESTION_CONTROL_BLOCK`, which is the default for queries,
+/// sending blocks until the network can accept more data. Since all chunks are sent on the same key expression, the query should
+/// be made with `Z_CONSOLIDATION_MODE_NONE` for them to be received; this is done automatically by `z_get()` when
+/// `z_get_options_t::reassemble_chunks` is set. Queries made with `z_querier_get()` are reassembled when
+/// `z_querier_get_options_t::reassemble_chunks` is set.
+///
+/// The stream keeps the query alive until it is finished or dropped.
+///
+/// @param this_: An uninitialized location in memory where reply stream will be constructed.
+/// @param query: The query to reply to.
+/// @param key_expr: The key expression of the replies.
+/// @param options: The options of the stream. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_reply_stream_new(
+    this_: &mut MaybeUninit<z_owned_reply_stream_t>,
+    query: &z_loaned_query_t,
+    key_expr: &z_loaned_keyexpr_t,
+    options: Option<&mut z_reply_stream_options_t>,
+) -> result::z_result_t {
+    let this = this_.as_rust_type_mut_uninit();
+    match CReplyStream::new(query, key_expr, options) {
+        Ok(stream) => {
+            this.write(Some(stream));
+            result::Z_OK
+        }
+        Err(e) => {
+            this.write(None);
+            e
+        }
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Adds data to a reply stream.
+///
+/// Data is sent in chunks of the stream's chunk size as soon as enough of it is available. The remaining data is sent
+/// by `z_reply_stream_finish()`.
+///
+/// @param this_: The reply stream.
+/// @param data: The data to send. Will be consumed.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_reply_stream_send(
+    this_: &mut z_loaned_reply_stream_t,
+    data: &mut z_moved_bytes_t,
+) -> result::z_result_t {
+    let data = data.take_rust_type();
+    this_.as_rust_type_mut().send(data)
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sends the remaining data of a reply stream along with the end-of-stream marker, and releases the query.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_reply_stream_finish(this_: &mut z_moved_reply_stream_t) -> result::z_result_t {
+    match this_.take_rust_type() {
+        Some(stream) => stream.finish(),
+        None => result::Z_EINVAL,
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Aborts a reply stream if it was not finished, and resets it to its gravestone state.
+///
+/// The data that was not yet sent is discarded, and an end-of-stream marker flagged as aborted is sent.
+#[no_mangle]
+pub extern "C" fn z_reply_stream_drop(this_: &mut z_moved_reply_stream_t) {
+    if let Some(stream) = this_.take_rust_type() {
+        let _ = stream.abort();
+    }
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs a reply stream in its gravestone state.
+#[no_mangle]
+pub extern "C" fn z_internal_reply_stream_null(this_: &mut MaybeUninit<z_owned_reply_stream_t>) {
+    this_.as_rust_type_mut_uninit().write(None);
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if reply stream is valid, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_internal_reply_stream_check(this_: &z_owned_reply_stream_t) -> bool {
+    this_.as_rust_type_ref().is_some()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Borrows reply stream.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_reply_stream_loan(
+    this_: &z_owned_reply_stream_t,
+) -> &z_loaned_reply_stream_t {
+    this_
+        .as_rust_type_ref()
+        .as_ref()
+        .unwrap_unchecked()
+        .as_loaned_c_type_ref()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Mutably borrows reply stream.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_reply_stream_loan_mut(
+    this_: &mut z_owned_reply_stream_t,
+) -> &mut z_loaned_reply_stream_t {
+    this_
+        .as_rust_type_mut()
+        .as_mut()
+        .unwrap_unchecked()
+        .as_loaned_c_type_mut()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sends a payload as a streamed reply to a query.
+///
+/// This is equivalent to creating a reply stream with `z_reply_stream_new()`, sending the whole payload with
+/// `z_reply_stream_send()` and finishing it with `z_reply_stream_finish()`.
+///
+/// @param query: The query to reply to.
+/// @param key_expr: The key expression of the replies.
+/// @param payload: The payload to send. Will be consumed.
+/// @param options: The options of the stream. All owned fields will be consumed.
+///
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn z_query_reply_chunked(
+    query: &z_loaned_query_t,
+    key_expr: &z_loaned_keyexpr_t,
+    payload: &mut z_moved_bytes_t,
+    options: Option<&mut z_reply_stream_options_t>,
+) -> result::z_result_t {
+    let payload = payload.take_rust_type();
+    let mut stream = match CReplyStream::new(query, key_expr, options) {
+        Ok(stream) => stream,
+        Err(e) => return e,
+    };
+    let res = stream.send(payload);
+    if res != result::Z_OK {
+        let _ = stream.abort();
+        return res;
+    }
+    stream.finish()
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Information about a reply which is a chunk of a reply stream.
+#[repr(C)]
+pub struct z_reply_chunk_info_t {
+    /// The identifier of the stream the chunk belongs to, unique per stream.
+    pub stream_id: u64,
+    /// The position of the chunk in the stream, starting from 0.
+    pub index: u64,
+    /// ``true`` if this chunk is the end of the stream.
+    pub is_last: bool,
+    /// ``true`` if the stream was aborted by the replier; in this case the chunk is the end of the stream and carries no data.
+    pub is_aborted: bool,
+}
+
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Gets the chunk information of a reply sent by a reply stream.
+///
+/// This allows to process chunks as they arrive, instead of having them reassembled by `z_get()` or `z_querier_get()`.
+///
+/// @param this_: The reply.
+/// @param out_info: An uninitialized location in memory where the chunk information will be written.
+///
+/// @return ``true`` if the reply is a chunk of a reply stream, ``false`` otherwise, in which case `out_info` is left untouched.
+#[no_mangle]
+pub extern "C" fn z_reply_chunk_info(
+    this_: &z_loaned_reply_t,
+    out_info: &mut MaybeUninit<z_reply_chunk_info_t>,
+) -> bool {
+    match ChunkHeader::from_reply(this_.as_rust_type_ref()) {
+        Some(header) => {
+            out_info.write(z_reply_chunk_info_t {
+                stream_id: header.stream_id,
+                index: header.index,
+                is_last: header.flags & CHUNK_FLAG_LAST != 0,
+                is_aborted: header.flags & CHUNK_FLAG_ABORTED != 0,
+            });
+            true
+        }
+        None => false,
+    }
+}
+
+struct PartialStream {
+    next_index: u64,
+    data: ZBytesWriter,
+}
+
+/// Reassembles the chunks of reply streams received by a query.
+#[derive(Default)]
+pub(crate) struct ReplyReassembler {
+    streams: Mutex<HashMap<u64, PartialStream>>,
+    discarded: Mutex<HashSet<u64>>,
+    broken_replies: Mutex<HashMap<u64, Reply>>,
+}
+
+impl ReplyReassembler {
+    /// Returns the reply to hand over to the user, if any: replies which are not chunks are returned as is,
+    /// chunks are buffered until the end of their stream, which is returned with the whole payload.
+    /// Aborted streams are returned as their end-of-stream marker, streams which lost a chunk as an error reply.
+    pub(crate) fn push(&self, mut reply: Reply) -> Option<Reply> {
+        let mut broken_replies = self
+            .broken_replies
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(stream_id) = broken_stream_id(&reply) {
+            broken_replies.insert(stream_id, reply);
+            return None;
+        }
+        let Some(header) = ChunkHeader::from_reply(&reply) else {
+            return Some(reply);
+        };
+        let is_last = header.flags & CHUNK_FLAG_LAST != 0;
+        let mut streams = self.streams.lock().unwrap_or_else(|e| e.into_inner());
+        let mut discarded = self.discarded.lock().unwrap_or_else(|e| e.into_inner());
+        if header.flags & CHUNK_FLAG_ABORTED != 0 {
+            streams.remove(&header.stream_id);
+            discarded.remove(&header.stream_id);
+            broken_replies.remove(&header.stream_id);
+            return Some(reply);
+        }
+        // the remaining chunks of a stream which lost a chunk are dropped silently
+        if discarded.contains(&header.stream_id) {
+            if is_last {
+                discarded.remove(&header.stream_id);
+            }
+            return None;
+        }
+        let stream = streams
+            .entry(header.stream_id)
+            .or_insert_with(|| PartialStream {
+                next_index: 0,
+                data: ZBytes::writer(),
+            });
+        if header.index != stream.next_index {
+            let message = format!(
+                "Reply stream {:016x} lost chunk {}",
+                header.stream_id, stream.next_index
+            );
+            streams.remove(&header.stream_id);
+            if !is_last {
+                discarded.insert(header.stream_id);
+            }
+            let Some(mut broken) = broken_replies.remove(&header.stream_id) else {
+                crate::report_error!("{}, discarding it", message);
+                return None;
+            };
+            if let Err(e) = broken.result_mut() {
+                *e.payload_mut() = message.into();
+            }
+            return Some(broken);
+        }
+        stream.next_index += 1;
+        let sample = std::mem::replace(reply.result_mut().ok()?, Sample::empty());
+        stream.data.append(sample.payload().clone());
+        if !is_last {
+            return None;
+        }
+        broken_replies.remove(&header.stream_id);
+        let payload: ZBytes = streams.remove(&header.stream_id)?.data.finish();
+        let (_, encoding) = ChunkHeader::decode(sample.encoding())?;
+        let mut sample: Sample = SampleBuilder::<SampleBuilderPut>::try_from(sample)
+            .ok()?
+            .encoding(encoding)
+            .into();
+        *sample.payload_mut() = payload;
+        *reply.result_mut().ok()? = sample;
+        Some(reply)
+    }
+}
@@ -30,6 +30,8 @@ use crate::{
     z_loaned_bytes_t, z_loaned_slice_t, z_loaned_string_t, z_owned_bytes_t, z_owned_slice_t,
     z_owned_string_t, CSliceOwned, CStringOwned,
 };
+#[cfg(feature = "unstable")]
+use crate::{z_loaned_slice_map_t, z_owned_slice_map_t, ZSliceMap};
 
 decl_c_type! {
     owned(ze_owned_serializer_t, option ZSerializer),
@@ -863,3 +865,141 @@ pub extern "C" fn ze_deserializer_deserialize_sequence_length(
         }
     }
 }
+
+#[cfg(feature = "unstable")]
+fn deserialize_attachment_entries(
+    payload: &ZBytes,
+    mut f: impl FnMut(String, Vec<u8>) -> bool,
+) -> Result<(), String> {
+    let mut deserializer = ZDeserializer::new(payload);
+    let len = deserializer
+        .deserialize::<VarInt<usize>>()
+        .map_err(|e| e.to_string())?
+        .0;
+    for _ in 0..len {
+        let key = deserializer
+            .deserialize::<String>()
+            .map_err(|e| e.to_string())?;
+        let value = deserializer
+            .deserialize::<Vec<u8>>()
+            .map_err(|e| e.to_string())?;
+        if f(key, value) {
+            return Ok(());
+        }
+    }
+    if !deserializer.done() {
+        return Err("unexpected trailing data".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Serializes a map of string keys to byte values, to be used as sample, query or reply attachment.
+///
+/// The map is serialized the same way as `HashMap<String, Vec<u8>>` is serialized by zenoh-ext in Rust, so it can be
+/// read by other zenoh bindings. All keys should be valid UTF-8.
+/// @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
+/// @param map: A map to serialize.
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn ze_serialize_attachment_map(
+    this_: &mut MaybeUninit<z_owned_bytes_t>,
+    map: &z_loaned_slice_map_t,
+) -> z_result_t {
+    let map = map.as_rust_type_ref();
+    let mut serializer = ZSerializer::new();
+    serializer.serialize(VarInt::<usize>(map.len()));
+    for (key, value) in map {
+        match str::from_utf8(key.slice()) {
+            Ok(key) => {
+                serializer.serialize(key);
+                serializer.serialize(value.slice());
+            }
+            Err(e) => {
+                crate::report_error!("Attachment key is not a valid UTF-8 string: {}", e);
+                this_.as_rust_type_mut_uninit().write(ZBytes::new());
+                return result::Z_EUTF8;
+            }
+        }
+    }
+    this_.as_rust_type_mut_uninit().write(serializer.finish());
+    result::Z_OK
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Deserializes an attachment serialized by `ze_serialize_attachment_map()` into a map of string keys to byte values.
+/// @param this_: Attachment data.
+/// @param map: An uninitialized location in memory where the map is to be constructed.
+/// @return 0 in case of success, negative error code otherwise.
+#[no_mangle]
+pub extern "C" fn ze_deserialize_attachment_map(
+    this_: &z_loaned_bytes_t,
+    map: &mut MaybeUninit<z_owned_slice_map_t>,
+) -> z_result_t {
+    let mut entries = ZSliceMap::new();
+    match deserialize_attachment_entries(this_.as_rust_type_ref(), |key, value| {
+        entries.insert(key.into_bytes().into(), value.into());
+        false
+    }) {
+        Ok(()) => {
+            map.as_rust_type_mut_uninit().write(entries);
+            result::Z_OK
+        }
+        Err(e) => {
+            crate::report_error!("Failed to deserialize the attachment: {}", e);
+            map.as_rust_type_mut_uninit().write(ZSliceMap::gravestone());
+            result::Z_EDESERIALIZE
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Extracts the value associated with a single key from an attachment serialized by `ze_serialize_attachment_map()`.
+///
+/// Unlike `ze_deserialize_attachment_map()` only the entries preceding the key are parsed.
+/// @param this_: Attachment data.
+/// @param key: A null-terminated key to look for.
+/// @param value: An uninitialized location in memory where the value is to be constructed.
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if the key is not present in the attachment,
+/// `Z_EDESERIALIZE` if the attachment is not a valid attachment map.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn ze_attachment_get(
+    this_: &z_loaned_bytes_t,
+    key: *const libc::c_char,
+    value: &mut MaybeUninit<z_owned_slice_t>,
+) -> z_result_t {
+    let value = value.as_rust_type_mut_uninit();
+    if key.is_null() {
+        crate::report_error!("Attachment key should not be null");
+        value.write(CSliceOwned::gravestone());
+        return result::Z_EINVAL;
+    }
+    let key = from_raw_parts(key as *const u8, strlen_or_zero(key));
+    let mut found = None;
+    let res = deserialize_attachment_entries(this_.as_rust_type_ref(), |k, v| {
+        if k.as_bytes() == key {
+            found = Some(v);
+            return true;
+        }
+        false
+    });
+    match (res, found) {
+        (_, Some(v)) => {
+            value.write(v.into());
+            result::Z_OK
+        }
+        (Ok(()), None) => {
+            value.write(CSliceOwned::gravestone());
+            result::Z_EUNAVAILABLE
+        }
+        (Err(e), None) => {
+            crate::report_error!("Failed to deserialize the attachment: {}", e);
+            value.write(CSliceOwned::gravestone());
+            result::Z_EDESERIALIZE
+        }
+    }
+}
@@ -12,7 +12,17 @@
 //   ZettaScale Zenoh team, <zenoh@zettascale.tech>
 //
 
-use std::mem::MaybeUninit;
+#[cfg(feature = "unstable")]
+use std::{
+    collections::HashMap,
+    sync::{atomic::Ordering, Mutex, MutexGuard},
+    time::Duration,
+};
+use std::{
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::{atomic::AtomicBool, Arc},
+};
 
 use zenoh::{
     handlers::Callback,
@@ -20,6 +30,13 @@ use zenoh::{
     sample::Sample,
     Wait,
 };
+#[cfg(feature = "unstable")]
+use zenoh::{
+    sample::SampleKind,
+    session::{EntityGlobalId, ZenohId},
+};
+#[cfg(feature = "unstable")]
+use zenoh_runtime::ZRuntime;
 
 pub use crate::opaque_types::{z_loaned_subscriber_t, z_moved_subscriber_t, z_owned_subscriber_t};
 use crate::{
@@ -27,13 +44,34 @@ use crate::{
     result,
     transmute::{LoanedCTypeRef, RustTypeRef, RustTypeRefUninit, TakeRustType},
     z_closure_sample_call, z_closure_sample_loan, z_loaned_session_t, z_locality_default,
-    z_locality_t, z_moved_closure_sample_t,
+    z_locality_t, z_moved_closure_sample_t, z_owned_closure_sample_t,
 };
 #[cfg(feature = "unstable")]
-use crate::{transmute::IntoCType, z_entity_global_id_t};
+use crate::{transmute::IntoCType, z_entity_global_id_t, z_id_t, z_loaned_encoding_t};
+
+pub struct CSubscriber {
+    pub(crate) subscriber: Subscriber<()>,
+    #[cfg(feature = "unstable")]
+    pub(crate) paused: Arc<AtomicBool>,
+}
+
+impl Deref for CSubscriber {
+    type Target = Subscriber<()>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.subscriber
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// Returns ``true`` if the pause flag of a subscriber or a queryable is set.
+/// Entities which can not be paused, like background ones, have no pause flag.
+pub(crate) fn _is_paused(paused: &Option<Arc<AtomicBool>>) -> bool {
+    paused.as_ref().is_some_and(|p| p.load(Ordering::Relaxed))
+}
 
 decl_c_type!(
-    owned(z_owned_subscriber_t, option Subscriber<()>),
+    owned(z_owned_subscriber_t, option CSubscriber),
     loaned(z_loaned_subscriber_t),
 );
 
@@ -54,6 +92,372 @@ pub unsafe extern "C" fn z_subscriber_loan(this_: &z_owned_subscriber_t) -> &z_l
         .as_loaned_c_type_ref()
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The kinds of samples delivered to a subscriber callback.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_sample_kind_filter_t {
+    /// Both `PUT` and `DELETE` samples are delivered.[Default]
+    ANY = 0,
+    /// Only `PUT` samples are delivered.
+    PUT = 1,
+    /// Only `DELETE` samples are delivered.
+    DELETE = 2,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The way a subscriber filters samples by their source.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_source_filter_mode_t {
+    /// Samples are not filtered by their source.[Default]
+    NONE = 0,
+    /// Only samples whose source matches one of the listed ids or zids are delivered. Samples without source info are dropped.
+    ALLOW = 1,
+    /// Samples whose source matches one of the listed ids or zids are dropped. Samples without source info are delivered.
+    DENY = 2,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Filters applied to samples before the subscriber callback is called.
+///
+/// The arrays are copied upon subscriber declaration, so they do not need to outlive it.
+#[repr(C)]
+pub struct z_subscriber_filter_options_t {
+    /// The kinds of samples to deliver.
+    pub kind: z_sample_kind_filter_t,
+    /// An array of accepted encodings, NULL or empty to accept any encoding.
+    /// An encoding without schema accepts samples with the same encoding and any schema.
+    pub encodings: *const &'static z_loaned_encoding_t,
+    /// The number of elements in `encodings`.
+    pub encodings_len: usize,
+    /// The way the sample source is matched against `source_ids` and `source_zids`.
+    pub source_mode: z_source_filter_mode_t,
+    /// An array of source entity ids to match.
+    pub source_ids: *const z_entity_global_id_t,
+    /// The number of elements in `source_ids`.
+    pub source_ids_len: usize,
+    /// An array of source zenoh ids to match, any entity of the listed zids matches.
+    pub source_zids: *const z_id_t,
+    /// The number of elements in `source_zids`.
+    pub source_zids_len: usize,
+}
+
+#[cfg(feature = "unstable")]
+impl Default for z_subscriber_filter_options_t {
+    fn default() -> Self {
+        Self {
+            kind: z_sample_kind_filter_t::ANY,
+            encodings: std::ptr::null(),
+            encodings_len: 0,
+            source_mode: z_source_filter_mode_t::NONE,
+            source_ids: std::ptr::null(),
+            source_ids_len: 0,
+            source_zids: std::ptr::null(),
+            source_zids_len: 0,
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_subscriber_filter_options_t`, which accepts all samples.
+#[no_mangle]
+pub extern "C" fn z_subscriber_filter_options_default(
+    this_: &mut MaybeUninit<z_subscriber_filter_options_t>,
+) {
+    this_.write(z_subscriber_filter_options_t::default());
+}
+
+#[cfg(feature = "unstable")]
+unsafe fn slice_from_raw<'a, T>(data: *const T, len: usize) -> &'a [T] {
+    if data.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) struct SampleFilter {
+    kind: z_sample_kind_filter_t,
+    encodings: Vec<String>,
+    source_mode: z_source_filter_mode_t,
+    source_ids: Vec<EntityGlobalId>,
+    source_zids: Vec<ZenohId>,
+}
+
+#[cfg(feature = "unstable")]
+impl SampleFilter {
+    /// Returns `None` if options do not filter out any sample.
+    pub(crate) fn new(options: &z_subscriber_filter_options_t) -> Option<Self> {
+        let filter = unsafe {
+            SampleFilter {
+                kind: options.kind,
+                encodings: slice_from_raw(options.encodings, options.encodings_len)
+                    .iter()
+                    .map(|e| e.as_rust_type_ref().to_string())
+                    .collect(),
+                source_mode: options.source_mode,
+                source_ids: slice_from_raw(options.source_ids, options.source_ids_len)
+                    .iter()
+                    .map(|id| *id.as_rust_type_ref())
+                    .collect(),
+                source_zids: slice_from_raw(options.source_zids, options.source_zids_len)
+                    .iter()
+                    .map(|zid| *zid.as_rust_type_ref())
+                    .collect(),
+            }
+        };
+        (filter.kind != z_sample_kind_filter_t::ANY
+            || !filter.encodings.is_empty()
+            || filter.source_mode != z_source_filter_mode_t::NONE)
+            .then_some(filter)
+    }
+
+    pub(crate) fn accepts(&self, sample: &Sample) -> bool {
+        let kind_matches = match self.kind {
+            z_sample_kind_filter_t::ANY => true,
+            z_sample_kind_filter_t::PUT => sample.kind() == SampleKind::Put,
+            z_sample_kind_filter_t::DELETE => sample.kind() == SampleKind::Delete,
+        };
+        if !kind_matches {
+            return false;
+        }
+        if !self.encodings.is_empty() {
+            let encoding = sample.encoding().to_string();
+            // encodings are compared as strings, since custom encodings are entirely stored in the schema
+            let encoding_matches = |e: &String| {
+                encoding == *e
+                    || (!e.contains(';')
+                        && encoding
+                            .strip_prefix(e.as_str())
+                            .is_some_and(|schema| schema.starts_with(';')))
+            };
+            if !self.encodings.iter().any(encoding_matches) {
+                return false;
+            }
+        }
+        let source_matches = || {
+            sample.source_info().is_some_and(|info| {
+                let id = info.source_id();
+                self.source_ids.contains(id) || self.source_zids.contains(&id.zid())
+            })
+        };
+        match self.source_mode {
+            z_source_filter_mode_t::NONE => true,
+            z_source_filter_mode_t::ALLOW => source_matches(),
+            z_source_filter_mode_t::DENY => !source_matches(),
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief The way a subscriber coalesces samples before calling its callback.
+#[allow(clippy::upper_case_acronyms)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum z_subscriber_coalescing_mode_t {
+    /// Every sample is delivered as soon as it is received.[Default]
+    NONE = 0,
+    /// Samples are delivered every `interval_ms`, at most one per key expression: the latest one received during the interval.
+    DOWNSAMPLE = 1,
+    /// Samples are delivered as fast as the callback processes them, keeping only the latest pending sample per key expression.
+    LATEST = 2,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Sample coalescing settings of a subscriber.
+///
+/// When coalescing is enabled, the subscriber callback is called from the zenoh runtime, only while samples are pending.
+/// Pending samples are discarded when the subscriber is undeclared.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct z_subscriber_coalescing_options_t {
+    /// The coalescing mode.
+    pub mode: z_subscriber_coalescing_mode_t,
+    /// The delivery interval in milliseconds, used by `Z_SUBSCRIBER_COALESCING_MODE_DOWNSAMPLE`.
+    /// ``0`` behaves as `Z_SUBSCRIBER_COALESCING_MODE_LATEST`.
+    pub interval_ms: u64,
+}
+
+#[cfg(feature = "unstable")]
+impl Default for z_subscriber_coalescing_options_t {
+    fn default() -> Self {
+        Self {
+            mode: z_subscriber_coalescing_mode_t::NONE,
+            interval_ms: 0,
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs the default value for `z_subscriber_coalescing_options_t`, which disables coalescing.
+#[no_mangle]
+pub extern "C" fn z_subscriber_coalescing_options_default(
+    this_: &mut MaybeUninit<z_subscriber_coalescing_options_t>,
+) {
+    this_.write(z_subscriber_coalescing_options_t::default());
+}
+
+pub(crate) fn _call_sample_closure(callback: &z_owned_closure_sample_t, sample: Sample) {
+    let mut owned_sample = Some(sample);
+    z_closure_sample_call(z_closure_sample_loan(callback), unsafe {
+        owned_sample
+            .as_mut()
+            .unwrap_unchecked()
+            .as_loaned_c_type_mut()
+    })
+}
+
+#[cfg(feature = "unstable")]
+#[derive(Default)]
+struct CoalescerState {
+    pending: Vec<Sample>,
+    index: HashMap<String, usize>,
+    scheduled: bool,
+    closed: bool,
+}
+
+#[cfg(feature = "unstable")]
+struct CoalescerShared {
+    state: Mutex<CoalescerState>,
+    callback: z_owned_closure_sample_t,
+}
+
+#[cfg(feature = "unstable")]
+impl CoalescerShared {
+    fn lock(&self) -> MutexGuard<'_, CoalescerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Delivers the pending samples until there are none left, waiting `interval` before each batch.
+    /// Runs only while samples are pending, so that idle subscribers cost no wakeup.
+    async fn run(self: Arc<Self>, interval: Option<Duration>) {
+        loop {
+            if let Some(interval) = interval {
+                tokio::time::sleep(interval).await;
+            }
+            let samples = {
+                let mut state = self.lock();
+                if state.closed || state.pending.is_empty() {
+                    state.scheduled = false;
+                    return;
+                }
+                state.index.clear();
+                std::mem::take(&mut state.pending)
+            };
+            let this = self.clone();
+            // The callback may block, so it is called from the blocking pool rather than from a runtime worker.
+            let delivery = ZRuntime::Application.spawn_blocking(move || {
+                for sample in samples {
+                    if this.lock().closed {
+                        return;
+                    }
+                    _call_sample_closure(&this.callback, sample);
+                }
+            });
+            let _ = delivery.await;
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) struct SampleCoalescer {
+    shared: Arc<CoalescerShared>,
+    interval: Option<Duration>,
+}
+
+#[cfg(feature = "unstable")]
+impl SampleCoalescer {
+    fn new(interval: Option<Duration>, callback: z_owned_closure_sample_t) -> Self {
+        SampleCoalescer {
+            shared: Arc::new(CoalescerShared {
+                state: Mutex::new(CoalescerState::default()),
+                callback,
+            }),
+            interval,
+        }
+    }
+
+    fn push(&self, sample: Sample) {
+        let mut state = self.shared.lock();
+        let state = &mut *state;
+        if state.closed {
+            return;
+        }
+        match state.index.get(sample.key_expr().as_str()) {
+            Some(&i) => state.pending[i] = sample,
+            None => {
+                state
+                    .index
+                    .insert(sample.key_expr().as_str().to_string(), state.pending.len());
+                state.pending.push(sample);
+            }
+        }
+        if !state.scheduled {
+            state.scheduled = true;
+            ZRuntime::Application.spawn(self.shared.clone().run(self.interval));
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl Drop for SampleCoalescer {
+    fn drop(&mut self) {
+        // Nothing is joined here, since the subscriber may be undeclared from its own callback, or dropped by the session
+        // along with a background subscriber. The callback is dropped once the delivery in progress, if any, returns.
+        let mut state = self.shared.lock();
+        state.closed = true;
+        state.pending.clear();
+        state.index.clear();
+    }
+}
+
+#[cfg(feature = "unstable")]
+pub(crate) enum SampleDelivery {
+    Direct(z_owned_closure_sample_t),
+    Coalesced(SampleCoalescer),
+}
+
+#[cfg(feature = "unstable")]
+impl SampleDelivery {
+    pub(crate) fn new(
+        options: Option<&z_subscriber_coalescing_options_t>,
+        callback: z_owned_closure_sample_t,
+    ) -> Self {
+        match options {
+            Some(o)
+                if o.mode == z_subscriber_coalescing_mode_t::DOWNSAMPLE && o.interval_ms > 0 =>
+            {
+                SampleDelivery::Coalesced(SampleCoalescer::new(
+                    Some(Duration::from_millis(o.interval_ms)),
+                    callback,
+                ))
+            }
+            Some(o) if o.mode != z_subscriber_coalescing_mode_t::NONE => {
+                SampleDelivery::Coalesced(SampleCoalescer::new(None, callback))
+            }
+            _ => SampleDelivery::Direct(callback),
+        }
+    }
+
+    pub(crate) fn deliver(&self, sample: Sample) {
+        match self {
+            SampleDelivery::Direct(callback) => _call_sample_closure(callback, sample),
+            SampleDelivery::Coalesced(coalescer) => coalescer.push(sample),
+        }
+    }
+}
+
 /// Options passed to the `z_declare_subscriber()` function.
 #[allow(non_camel_case_types)]
 #[repr(C)]
@@ -61,12 +465,26 @@ pub struct z_subscriber_options_t {
     /// Restricts the matching publications that will be received by this Subscriber to the ones
     /// that have the compatible allowed_destination.
     pub allowed_origin: z_locality_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The filters applied to samples before the subscriber callback is called. All samples are delivered by default.
+    pub filter: z_subscriber_filter_options_t,
+    #[cfg(feature = "unstable")]
+    /// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+    ///
+    /// The coalescing applied to samples which passed the filters. Disabled by default.
+    pub coalescing: z_subscriber_coalescing_options_t,
 }
 
 impl Default for z_subscriber_options_t {
     fn default() -> Self {
         Self {
             allowed_origin: z_locality_default(),
+            #[cfg(feature = "unstable")]
+            filter: z_subscriber_filter_options_t::default(),
+            #[cfg(feature = "unstable")]
+            coalescing: z_subscriber_coalescing_options_t::default(),
         }
     }
 }
@@ -83,20 +501,26 @@ pub(crate) fn _declare_subscriber_inner<'a, 'b>(
     key_expr: &'b z_loaned_keyexpr_t,
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut z_subscriber_options_t>,
+    paused: Option<Arc<AtomicBool>>,
 ) -> SubscriberBuilder<'a, 'b, Callback<Sample>> {
     let session = session.as_rust_type_ref();
     let key_expr = key_expr.as_rust_type_ref();
     let callback = callback.take_rust_type();
+    #[cfg(feature = "unstable")]
+    let filter = options.as_ref().and_then(|o| SampleFilter::new(&o.filter));
+    #[cfg(feature = "unstable")]
+    let callback = SampleDelivery::new(options.as_ref().map(|o| &o.coalescing), callback);
     let mut subscriber = session
         .declare_subscriber(key_expr)
         .callback(move |sample| {
-            let mut owned_sample = Some(sample);
-            z_closure_sample_call(z_closure_sample_loan(&callback), unsafe {
-                owned_sample
-                    .as_mut()
-                    .unwrap_unchecked()
-                    .as_loaned_c_type_mut()
-            })
+            #[cfg(feature = "unstable")]
+            if _is_paused(&paused) || filter.as_ref().is_some_and(|f| !f.accepts(&sample)) {
+                return;
+            }
+            #[cfg(feature = "unstable")]
+            callback.deliver(sample);
+            #[cfg(not(feature = "unstable"))]
+            _call_sample_closure(&callback, sample);
         });
     if let Some(options) = options {
         subscriber = subscriber.allowed_origin(options.allowed_origin.into());
@@ -122,10 +546,19 @@ pub extern "C" fn z_declare_subscriber(
     options: Option<&mut z_subscriber_options_t>,
 ) -> result::z_result_t {
     let this = subscriber.as_rust_type_mut_uninit();
-    let s = _declare_subscriber_inner(session, key_expr, callback, options);
+    #[cfg(feature = "unstable")]
+    let paused = Arc::new(AtomicBool::new(false));
+    #[cfg(feature = "unstable")]
+    let s = _declare_subscriber_inner(session, key_expr, callback, options, Some(paused.clone()));
+    #[cfg(not(feature = "unstable"))]
+    let s = _declare_subscriber_inner(session, key_expr, callback, options, None);
     match s.wait() {
         Ok(sub) => {
-            this.write(Some(sub));
+            this.write(Some(CSubscriber {
+                subscriber: sub,
+                #[cfg(feature = "unstable")]
+                paused,
+            }));
             result::Z_OK
         }
         Err(e) => {
@@ -152,7 +585,7 @@ pub extern "C" fn z_declare_background_subscriber(
     callback: &mut z_moved_closure_sample_t,
     options: Option<&mut z_subscriber_options_t>,
 ) -> result::z_result_t {
-    let subscriber = _declare_subscriber_inner(session, key_expr, callback, options);
+    let subscriber = _declare_subscriber_inner(session, key_expr, callback, options, None);
     match subscriber.background().wait() {
         Ok(_) => result::Z_OK,
         Err(e) => {
@@ -172,6 +605,41 @@ pub extern "C" fn z_subscriber_keyexpr(subscriber: &z_loaned_subscriber_t) -> &z
         .as_loaned_c_type_ref()
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Pauses the subscriber: samples received while it is paused are dropped without calling its callback.
+///
+/// Pausing is local: the subscriber stays declared on the network and keeps its id, so matching samples are still routed
+/// to this session. Zenoh can not suspend the routing of a declared subscriber, undeclare it to stop receiving samples
+/// from the network. Samples already queued for delivery by the subscriber coalescing are still delivered.
+/// Pausing an already paused subscriber is a no-op.
+#[no_mangle]
+pub extern "C" fn z_subscriber_pause(this_: &z_loaned_subscriber_t) {
+    this_
+        .as_rust_type_ref()
+        .paused
+        .store(true, Ordering::Relaxed);
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Resumes the delivery of samples to the callback of a subscriber paused with `z_subscriber_pause()`.
+#[no_mangle]
+pub extern "C" fn z_subscriber_resume(this_: &z_loaned_subscriber_t) {
+    this_
+        .as_rust_type_ref()
+        .paused
+        .store(false, Ordering::Relaxed);
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Returns ``true`` if the subscriber is paused, ``false`` otherwise.
+#[no_mangle]
+pub extern "C" fn z_subscriber_is_paused(this_: &z_loaned_subscriber_t) -> bool {
+    this_.as_rust_type_ref().paused.load(Ordering::Relaxed)
+}
+
 /// Undeclares subscriber callback and resets it to its gravestone state.
 /// This is equivalent to calling `z_undeclare_subscriber()` and discarding its return value.
 #[no_mangle]
@@ -191,7 +659,7 @@ pub extern "C" fn z_internal_subscriber_check(this_: &z_owned_subscriber_t) -> b
 #[no_mangle]
 pub extern "C" fn z_undeclare_subscriber(this_: &mut z_moved_subscriber_t) -> result::z_result_t {
     if let Some(s) = this_.take_rust_type() {
-        if let Err(e) = s.undeclare().wait_callbacks().wait() {
+        if let Err(e) = s.subscriber.undeclare().wait_callbacks().wait() {
             crate::report_error!("{}", e);
             return result::Z_EGENERIC;
         }
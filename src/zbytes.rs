@@ -22,6 +22,8 @@ use std::{
     slice::{from_raw_parts, from_raw_parts_mut},
 };
 
+#[cfg(feature = "unstable")]
+use zenoh::internal::buffers::{AdvanceableReader, Reader, ZBufReader};
 use zenoh::{
     bytes::{ZBytes, ZBytesReader, ZBytesSliceIterator, ZBytesWriter},
     internal::buffers::{ZBuf, ZSliceBuffer},
@@ -30,6 +32,8 @@ use zenoh::{
 pub use crate::opaque_types::{z_loaned_bytes_t, z_owned_bytes_t};
 #[cfg(all(feature = "shared-memory", feature = "unstable"))]
 use crate::result::Z_ENULL;
+#[cfg(feature = "unstable")]
+use crate::result::Z_EUNAVAILABLE;
 use crate::{
     result::{self, z_result_t, Z_EINVAL, Z_EIO, Z_OK},
     strlen_or_zero,
@@ -454,6 +458,99 @@ pub unsafe extern "C" fn z_bytes_copy_from_str(
     }
 }
 
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief A scatter/gather buffer descriptor.
+///
+/// Its layout matches the POSIX `struct iovec`, so arrays of `z_iovec_t` can be passed to `readv()`/`writev()`/`sendmsg()`
+/// (and vice versa) by a simple cast.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct z_iovec_t {
+    /// Pointer to the start of the fragment.
+    pub iov_base: *mut c_void,
+    /// Length of the fragment in bytes.
+    pub iov_len: usize,
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Constructs `z_owned_bytes_t` out of an array of fragments without copying them.
+///
+/// The resulting payload is the concatenation of all fragments in the array order.
+/// @param this_: An uninitialized location in memory where `z_owned_bytes_t` is to be constructed.
+/// @param iov: A pointer to the array of fragments. `this_` will take ownership of each fragment buffer.
+/// @param iovcnt: Number of elements in `iov`.
+/// @param deleter: A thread-safe function, that will be called once on each fragment `iov_base` when `this_` is dropped.
+/// Can be `NULL` if fragments are located in static memory and do not require a drop.
+/// @param context: An optional context to be passed to `deleter`.
+/// @return 0 in case of success, negative error code otherwise. In case of failure no ownership is taken and `deleter` is never called.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_bytes_from_iovec(
+    this: &mut MaybeUninit<z_owned_bytes_t>,
+    iov: *const z_iovec_t,
+    iovcnt: usize,
+    deleter: Option<extern "C" fn(data: *mut c_void, context: *mut c_void)>,
+    context: *mut c_void,
+) -> z_result_t {
+    if iov.is_null() && iovcnt > 0 {
+        crate::report_error!("Non zero-length fragment array should not be NULL");
+        this.as_rust_type_mut_uninit().write(ZBytes::default());
+        return Z_EINVAL;
+    }
+    let iov = if iovcnt == 0 {
+        &[]
+    } else {
+        from_raw_parts(iov, iovcnt)
+    };
+    if iov.iter().any(|v| v.iov_base.is_null() && v.iov_len > 0) {
+        crate::report_error!("Non zero-length fragment should not be NULL");
+        this.as_rust_type_mut_uninit().write(ZBytes::default());
+        return Z_EINVAL;
+    }
+    let mut writer = ZBytes::writer();
+    for v in iov {
+        let s = CSliceOwned::wrap(v.iov_base as *mut u8, v.iov_len, deleter, context)
+            .unwrap_unchecked();
+        writer.append(ZBytes::from(s));
+    }
+    this.as_rust_type_mut_uninit().write(writer.finish());
+    Z_OK
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Exports the fragments of the data as an array of `z_iovec_t` without copying.
+///
+/// The filled descriptors point into the memory of `this_` and remain valid as long as `this_` is neither modified nor dropped.
+/// The memory they point to must not be written.
+/// To know the required array size, the function can first be called with `iovcnt` equal to 0.
+///
+/// @param this_: Data to export.
+/// @param iov: A pointer to the array where fragment descriptors will be written. Can be `NULL` if `iovcnt` is 0.
+/// @param iovcnt: Capacity of `iov`.
+/// @return total number of fragments in `this_`. If it is greater than `iovcnt`, only the first `iovcnt` fragments were written.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_bytes_to_iovec(
+    this: &z_loaned_bytes_t,
+    iov: *mut z_iovec_t,
+    iovcnt: usize,
+) -> usize {
+    let mut count = 0;
+    for s in this.as_rust_type_ref().slices() {
+        if count < iovcnt && !iov.is_null() {
+            iov.add(count).write(z_iovec_t {
+                iov_base: s.as_ptr() as *mut c_void,
+                iov_len: s.len(),
+            });
+        }
+        count += 1;
+    }
+    count
+}
+
 pub use crate::z_bytes_slice_iterator_t;
 decl_c_type!(loaned(z_bytes_slice_iterator_t, ZBytesSliceIterator<'static>));
 
@@ -627,6 +724,189 @@ pub unsafe extern "C" fn z_bytes_reader_remaining(this_: &z_bytes_reader_t) -> u
     reader.remaining()
 }
 
+// `ZBytesReader` only exposes copying reads of the `ZBufReader` it wraps as `#[repr(transparent)]`,
+// so zero-copy reads access the wrapped reader by casting. The cast relies on both types sharing
+// their layout, which is asserted at compile time, and casted readers are checked in debug builds.
+#[cfg(feature = "unstable")]
+validate_equivalence!(ZBytesReader<'static>, ZBufReader<'static>);
+
+#[cfg(feature = "unstable")]
+fn as_zbuf_reader<'a, 'b>(reader: &'b ZBytesReader<'a>) -> &'b ZBufReader<'a> {
+    let zbuf_reader = unsafe { &*(reader as *const ZBytesReader<'a> as *const ZBufReader<'a>) };
+    debug_assert_eq!(zbuf_reader.remaining(), reader.remaining());
+    zbuf_reader
+}
+
+#[cfg(feature = "unstable")]
+fn as_zbuf_reader_mut<'a, 'b>(reader: &'b mut ZBytesReader<'a>) -> &'b mut ZBufReader<'a> {
+    let remaining = reader.remaining();
+    let zbuf_reader = unsafe { &mut *(reader as *mut ZBytesReader<'a> as *mut ZBufReader<'a>) };
+    debug_assert_eq!(zbuf_reader.remaining(), remaining);
+    zbuf_reader
+}
+
+// Returns the offset of the first occurrence of `pattern` in the remaining data of `reader`,
+// without advancing it. Occurrences spanning several fragments are taken into account.
+#[cfg(feature = "unstable")]
+fn zbuf_reader_find(reader: &ZBufReader, pattern: &[u8]) -> Option<usize> {
+    if pattern.is_empty() {
+        return Some(0);
+    }
+    let mut reader = reader.clone();
+    let remaining = reader.remaining();
+    let data = ZBytes::from(reader.read_zbuf(remaining).ok()?);
+    let mut tail: Vec<u8> = Vec::with_capacity(2 * pattern.len());
+    let mut pos = 0;
+    for s in data.slices() {
+        if !tail.is_empty() {
+            // the only matches that fit into the tail and the head of the fragment are those starting in the tail
+            let mut joined = tail.clone();
+            joined.extend_from_slice(&s[..s.len().min(pattern.len() - 1)]);
+            if let Some(p) = joined.windows(pattern.len()).position(|w| w == pattern) {
+                return Some(pos - tail.len() + p);
+            }
+        }
+        if let Some(p) = s.windows(pattern.len()).position(|w| w == pattern) {
+            return Some(pos + p);
+        }
+        tail.extend_from_slice(&s[s.len().saturating_sub(pattern.len() - 1)..]);
+        tail.drain(..tail.len().saturating_sub(pattern.len() - 1));
+        pos += s.len();
+    }
+    None
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Reads exactly `len` bytes into specified destination.
+///
+/// @param this_: Data reader to read from.
+/// @param dst: Buffer where the read data is written.
+/// @param len: Number of bytes to read.
+/// @return 0 in case of success, `Z_EINVAL` if less than `len` bytes remain in the reader (in this case the reader position is not changed).
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_bytes_reader_read_exact(
+    this: &mut z_bytes_reader_t,
+    dst: *mut u8,
+    len: usize,
+) -> z_result_t {
+    let reader = this.as_rust_type_mut();
+    if reader.remaining() < len {
+        crate::report_error!(
+            "Can not read {} bytes, only {} remaining",
+            len,
+            reader.remaining()
+        );
+        return Z_EINVAL;
+    }
+    let buf = unsafe { from_raw_parts_mut(dst, len) };
+    match reader.read_exact(buf) {
+        Ok(_) => Z_OK,
+        Err(_) => Z_EIO,
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Reads `len` bytes from the current reader position into a new `z_owned_bytes_t` without copying.
+///
+/// @param this_: Data reader to read from.
+/// @param len: Number of bytes to read.
+/// @param dst: An uninitialized memory location where the read data will be constructed.
+/// @return 0 in case of success, `Z_EINVAL` if less than `len` bytes remain in the reader (in this case the reader position is not changed).
+#[no_mangle]
+pub extern "C" fn z_bytes_reader_read_bytes(
+    this: &mut z_bytes_reader_t,
+    len: usize,
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+) -> z_result_t {
+    let reader = as_zbuf_reader_mut(this.as_rust_type_mut());
+    match reader.read_zbuf(len) {
+        Ok(zbuf) => {
+            dst.as_rust_type_mut_uninit().write(zbuf.into());
+            Z_OK
+        }
+        Err(_) => {
+            crate::report_error!(
+                "Can not read {} bytes, only {} remaining",
+                len,
+                reader.remaining()
+            );
+            dst.as_rust_type_mut_uninit().write(ZBytes::default());
+            Z_EINVAL
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Searches for the first occurrence of `pattern` in the remaining data, without advancing the reader.
+///
+/// The occurrences spanning several underlying data fragments are also found.
+/// @param this_: Data reader to search in.
+/// @param pattern: A pointer to the byte sequence to search for.
+/// @param len: Length of the `pattern`.
+/// @param offset: A location where the offset of the found occurrence, relative to the current reader position, will be written.
+/// @return ``true`` if `pattern` was found, ``false`` otherwise (in this case `offset` stays unchanged).
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_bytes_reader_find(
+    this: &z_bytes_reader_t,
+    pattern: *const u8,
+    len: usize,
+    offset: &mut usize,
+) -> bool {
+    let Ok(pattern) = CSliceView::new(pattern, len) else {
+        return false;
+    };
+    match zbuf_reader_find(as_zbuf_reader(this.as_rust_type_ref()), pattern.slice()) {
+        Some(o) => {
+            *offset = o;
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(feature = "unstable")]
+/// @warning This API has been marked as unstable: it works as advertised, but it may be changed in a future release.
+/// @brief Reads data until the first occurrence of `delimiter` into a new `z_owned_bytes_t` without copying.
+///
+/// The delimiter itself is consumed but not included into the read data.
+/// @param this_: Data reader to read from.
+/// @param delimiter: A pointer to the delimiter byte sequence.
+/// @param len: Length of the `delimiter`, should be greater than 0.
+/// @param dst: An uninitialized memory location where the read data will be constructed.
+/// @return 0 in case of success, `Z_EUNAVAILABLE` if the delimiter was not found (in this case the reader position is not changed),
+/// `Z_EINVAL` if the delimiter is empty.
+#[no_mangle]
+#[allow(clippy::missing_safety_doc)]
+pub unsafe extern "C" fn z_bytes_reader_read_until(
+    this: &mut z_bytes_reader_t,
+    delimiter: *const u8,
+    len: usize,
+    dst: &mut MaybeUninit<z_owned_bytes_t>,
+) -> z_result_t {
+    let delimiter = match CSliceView::new(delimiter, len) {
+        Ok(d) if len > 0 => d,
+        _ => {
+            crate::report_error!("Delimiter should be a non-empty byte sequence");
+            dst.as_rust_type_mut_uninit().write(ZBytes::default());
+            return Z_EINVAL;
+        }
+    };
+    let reader = as_zbuf_reader_mut(this.as_rust_type_mut());
+    let Some(offset) = zbuf_reader_find(reader, delimiter.slice()) else {
+        dst.as_rust_type_mut_uninit().write(ZBytes::default());
+        return Z_EUNAVAILABLE;
+    };
+    let data = reader.read_zbuf(offset).unwrap_unchecked();
+    let _ = reader.skip(len);
+    dst.as_rust_type_mut_uninit().write(data.into());
+    Z_OK
+}
+
 pub use crate::opaque_types::{
     z_loaned_bytes_writer_t, z_moved_bytes_writer_t, z_owned_bytes_writer_t,
 };
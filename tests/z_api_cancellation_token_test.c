@@ -264,10 +264,138 @@ void test_liveliness_get(void) {
     z_session_drop(z_session_move(&s2));
 }
 
+void reply_str(const z_loaned_query_t* q, const char* value) {
+    z_owned_bytes_t b;
+    z_bytes_copy_from_str(&b, value);
+    assert(z_query_reply(q, z_query_keyexpr(q), z_bytes_move(&b), NULL) == Z_OK);
+}
+
+void reply_err_str(const z_loaned_query_t* q, const char* value) {
+    z_owned_bytes_t b;
+    z_bytes_copy_from_str(&b, value);
+    assert(z_query_reply_err(q, z_bytes_move(&b), NULL) == Z_OK);
+}
+
+void check_first_reply_only(const z_loaned_fifo_handler_query_t* query_handler,
+                            const z_loaned_fifo_handler_reply_t* reply_handler) {
+    z_owned_query_t q;
+    assert(z_recv(query_handler, &q) == Z_OK);
+    reply_err_str(z_loan(q), "error");
+    reply_str(z_loan(q), "fast");
+    reply_str(z_loan(q), "slow");
+
+    z_owned_reply_t reply;
+    assert(z_recv(reply_handler, &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    assert(z_recv(reply_handler, &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(z_reply_ok(z_loan(reply))), &value);
+    assert(strncmp(z_string_data(z_loan(value)), "fast", z_string_len(z_loan(value))) == 0);
+    z_drop(z_move(value));
+    z_drop(z_move(reply));
+
+    // the query is interrupted while still held by the queryable
+    z_clock_t clock = z_clock_now();
+    assert(z_recv(reply_handler, &reply) == Z_CHANNEL_DISCONNECTED);
+    assert(z_clock_elapsed_ms(&clock) < 2000);
+    reply_str(z_loan(q), "late");
+    z_query_drop(z_query_move(&q));
+}
+
+void test_first_reply_only(void) {
+    printf("test_first_reply_only\n");
+    const char* query_expr = "zenoh-pico/query/cancellation/first";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, query_expr);
+
+    assert(z_open(&s1, z_config_move(&c1), NULL) == Z_OK);
+    assert(z_open(&s2, z_config_move(&c2), NULL) == Z_OK);
+
+    z_owned_queryable_t queryable;
+    z_owned_closure_query_t query_callback;
+    z_owned_fifo_handler_query_t query_handler;
+    z_fifo_channel_query_new(&query_callback, &query_handler, 16);
+    z_declare_queryable(z_session_loan(&s1), &queryable, z_view_keyexpr_loan(&ke),
+                        z_closure_query_move(&query_callback), NULL);
+    z_sleep_s(2);
+
+    printf("Check get is interrupted after the first successful reply\n");
+    z_owned_closure_reply_t reply_callback;
+    z_owned_fifo_handler_reply_t reply_handler;
+    z_fifo_channel_reply_new(&reply_callback, &reply_handler, 16);
+    z_get_options_t opts;
+    z_get_options_default(&opts);
+    opts.timeout_ms = 10000;
+    opts.first_reply_only = true;
+    assert(z_get(z_session_loan(&s2), z_view_keyexpr_loan(&ke), "", z_closure_reply_move(&reply_callback), &opts) ==
+           Z_OK);
+    check_first_reply_only(z_loan(query_handler), z_loan(reply_handler));
+    z_drop(z_move(reply_handler));
+
+    printf("Check querier get is interrupted after the first successful reply\n");
+    z_owned_querier_t querier;
+    z_querier_options_t querier_opts;
+    z_querier_options_default(&querier_opts);
+    querier_opts.consolidation = z_query_consolidation_none();
+    querier_opts.timeout_ms = 10000;
+    assert(z_declare_querier(z_session_loan(&s2), &querier, z_view_keyexpr_loan(&ke), &querier_opts) == Z_OK);
+    z_sleep_s(1);
+    z_fifo_channel_reply_new(&reply_callback, &reply_handler, 16);
+    z_querier_get_options_t querier_get_opts;
+    z_querier_get_options_default(&querier_get_opts);
+    querier_get_opts.first_reply_only = true;
+    assert(z_querier_get(z_loan(querier), "", z_closure_reply_move(&reply_callback), &querier_get_opts) == Z_OK);
+    check_first_reply_only(z_loan(query_handler), z_loan(reply_handler));
+    z_drop(z_move(reply_handler));
+
+    printf("Check user token still interrupts the query\n");
+    z_owned_cancellation_token_t ct, ct_clone;
+    assert(z_cancellation_token_new(&ct) == Z_OK);
+    z_clone(&ct_clone, z_loan(ct));
+    z_fifo_channel_reply_new(&reply_callback, &reply_handler, 16);
+    z_get_options_default(&opts);
+    opts.timeout_ms = 10000;
+    opts.first_reply_only = true;
+    opts.cancellation_token = z_move(ct_clone);
+    assert(z_get(z_session_loan(&s2), z_view_keyexpr_loan(&ke), "", z_closure_reply_move(&reply_callback), &opts) ==
+           Z_OK);
+    z_owned_query_t q;
+    assert(z_recv(z_loan(query_handler), &q) == Z_OK);
+    assert(z_cancellation_token_cancel(z_loan_mut(ct)) == Z_OK);
+    z_owned_reply_t reply;
+    assert(z_try_recv(z_loan(reply_handler), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_query_drop(z_query_move(&q));
+    z_drop(z_move(reply_handler));
+    z_drop(z_move(ct));
+
+    printf("Check incompatible options are rejected\n");
+    z_querier_get_options_default(&querier_get_opts);
+    querier_get_opts.first_reply_only = true;
+    querier_get_opts.group_by_replier = true;
+    z_fifo_channel_reply_new(&reply_callback, &reply_handler, 16);
+    assert(z_querier_get(z_loan(querier), "", z_closure_reply_move(&reply_callback), &querier_get_opts) == Z_EINVAL);
+    assert(z_try_recv(z_loan(reply_handler), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(reply_handler));
+
+    z_querier_drop(z_querier_move(&querier));
+    z_fifo_handler_query_drop(z_fifo_handler_query_move(&query_handler));
+    z_queryable_drop(z_queryable_move(&queryable));
+    z_session_drop(z_session_move(&s1));
+    z_session_drop(z_session_move(&s2));
+}
+
 int main(int argc, char** argv) {
     zc_try_init_log_from_env();
     test_cancel_get();
     test_cancel_querier_get();
     test_liveliness_get();
+    test_first_reply_only();
     return 0;
 }
@@ -0,0 +1,119 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+bool slice_equals(const z_loaned_slice_t *s, const char *expected) {
+    return z_slice_len(s) == strlen(expected) && memcmp(z_slice_data(s), expected, z_slice_len(s)) == 0;
+}
+
+void view_slice_from_str(z_view_slice_t *s, const char *str) {
+    z_view_slice_from_buf(s, (const uint8_t *)str, strlen(str));
+}
+
+void test_slice_array(void) {
+    z_owned_slice_array_t array;
+    z_slice_array_new(&array);
+    assert(z_slice_array_is_empty(z_loan(array)));
+
+    z_owned_slice_t a, b;
+    z_slice_copy_from_buf(&a, (const uint8_t *)"abc", 3);
+    z_slice_copy_from_buf(&b, (const uint8_t *)"de", 2);
+    assert(z_slice_array_push_by_copy(z_loan_mut(array), z_loan(a)) == 1);
+    assert(z_slice_array_push_by_alias(z_loan_mut(array), z_loan(b)) == 2);
+    z_drop(z_move(a));
+
+    assert(z_slice_array_len(z_loan(array)) == 2);
+    assert(slice_equals(z_slice_array_get(z_loan(array), 0), "abc"));
+    assert(slice_equals(z_slice_array_get(z_loan(array), 1), "de"));
+    assert(z_slice_array_get(z_loan(array), 2) == NULL);
+
+    z_owned_slice_array_t copy;
+    z_slice_array_clone(&copy, z_loan(array));
+    z_drop(z_move(array));
+    z_drop(z_move(b));
+    assert(z_slice_array_len(z_loan(copy)) == 2);
+    assert(slice_equals(z_slice_array_get(z_loan(copy), 1), "de"));
+    z_drop(z_move(copy));
+}
+
+bool count_entries(const z_loaned_slice_t *key, const z_loaned_slice_t *value, void *context) {
+    (void)key;
+    (void)value;
+    (*(size_t *)context)++;
+    return false;
+}
+
+bool stop_immediately(const z_loaned_slice_t *key, const z_loaned_slice_t *value, void *context) {
+    (void)key;
+    (void)value;
+    (*(size_t *)context)++;
+    return true;
+}
+
+void test_slice_map(void) {
+    z_owned_slice_map_t map;
+    z_slice_map_new(&map);
+    assert(z_slice_map_is_empty(z_loan(map)));
+
+    z_view_slice_t k1, k2, v1, v2;
+    view_slice_from_str(&k1, "trace_id");
+    view_slice_from_str(&k2, "span_id");
+    view_slice_from_str(&v1, "0123");
+    view_slice_from_str(&v2, "4567");
+
+    assert(!z_slice_map_insert_by_copy(z_loan_mut(map), z_loan(k1), z_loan(v1)));
+    assert(!z_slice_map_insert_by_alias(z_loan_mut(map), z_loan(k2), z_loan(v1)));
+    assert(z_slice_map_insert_by_copy(z_loan_mut(map), z_loan(k2), z_loan(v2)));
+    assert(z_slice_map_len(z_loan(map)) == 2);
+
+    assert(slice_equals(z_slice_map_get(z_loan(map), z_loan(k1)), "0123"));
+    assert(slice_equals(z_slice_map_get(z_loan(map), z_loan(k2)), "4567"));
+    z_view_slice_t missing;
+    view_slice_from_str(&missing, "missing");
+    assert(z_slice_map_get(z_loan(map), z_loan(missing)) == NULL);
+
+    size_t n = 0;
+    z_slice_map_iterate(z_loan(map), count_entries, &n);
+    assert(n == 2);
+    n = 0;
+    z_slice_map_iterate(z_loan(map), stop_immediately, &n);
+    assert(n == 1);
+
+    z_owned_slice_map_t copy;
+    z_slice_map_clone(&copy, z_loan(map));
+    assert(z_slice_map_remove(z_loan_mut(map), z_loan(k1)));
+    assert(!z_slice_map_remove(z_loan_mut(map), z_loan(k1)));
+    assert(z_slice_map_len(z_loan(map)) == 1);
+    assert(z_slice_map_len(z_loan(copy)) == 2);
+    assert(slice_equals(z_slice_map_get(z_loan(copy), z_loan(k1)), "0123"));
+
+    z_drop(z_move(map));
+    z_drop(z_move(copy));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_slice_array();
+    test_slice_map();
+#endif
+    return 0;
+}
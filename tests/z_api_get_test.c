@@ -0,0 +1,298 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+void on_query(z_loaned_query_t *query, void *context) {
+    size_t replies = *(size_t *)context;
+    char value[8];
+    for (size_t i = 0; i < replies; i++) {
+        snprintf(value, sizeof(value), "%zu", i);
+        z_owned_bytes_t payload;
+        z_bytes_copy_from_str(&payload, value);
+        z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+    }
+}
+
+void declare_queryable(const z_loaned_session_t *s, z_owned_queryable_t *qable, const char *key, size_t *replies) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, replies);
+    assert(z_declare_queryable(s, qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+}
+
+void get_options(z_get_options_t *options, uint64_t timeout_ms) {
+    z_get_options_default(options);
+    options->consolidation = z_query_consolidation_none();
+    options->timeout_ms = timeout_ms;
+}
+
+void test_collect(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    size_t replies = 3;
+    z_owned_queryable_t qable;
+    declare_queryable(z_loan(s), &qable, "zenoh/test/get/collect", &replies);
+    z_sleep_ms(200);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/get/collect");
+    z_get_options_t options;
+    get_options(&options, 5000);
+    z_owned_reply_array_t collected;
+    z_clock_t clock = z_clock_now();
+    assert(z_get_collect(z_loan(s), z_loan(ke), "", &collected, 0, &options) == Z_OK);
+    assert(z_clock_elapsed_ms(&clock) < 2000);
+    assert(z_reply_array_len(z_loan(collected)) == 3);
+    for (size_t i = 0; i < 3; i++) {
+        const z_loaned_reply_t *reply = z_reply_array_get(z_loan(collected), i);
+        assert(z_reply_is_ok(reply));
+        z_owned_string_t value;
+        z_bytes_to_string(z_sample_payload(z_reply_ok(reply)), &value);
+        assert(z_string_len(z_loan(value)) == 1 && z_string_data(z_loan(value))[0] == (char)('0' + i));
+        z_drop(z_move(value));
+    }
+    assert(z_reply_array_get(z_loan(collected), 3) == NULL);
+
+    z_owned_reply_array_t copy;
+    z_reply_array_clone(&copy, z_loan(collected));
+    z_drop(z_move(collected));
+    assert(!z_internal_check(collected));
+    assert(z_reply_array_len(z_loan(copy)) == 3);
+    z_drop(z_move(copy));
+
+    // stops as soon as the maximum number of replies is reached
+    get_options(&options, 5000);
+    assert(z_get_collect(z_loan(s), z_loan(ke), "", &collected, 2, &options) == Z_OK);
+    assert(z_reply_array_len(z_loan(collected)) == 2);
+    z_drop(z_move(collected));
+
+    // finishes without replies
+    replies = 0;
+    get_options(&options, 5000);
+    assert(z_get_collect(z_loan(s), z_loan(ke), "", &collected, 1, &options) == Z_OK);
+    assert(z_reply_array_is_empty(z_loan(collected)));
+    assert(!z_internal_check(collected));
+    z_drop(z_move(collected));
+
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+
+void reply_on(const z_loaned_query_t *query, const char *key, const char *value) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    assert(z_query_reply(query, z_loan(ke), z_move(payload), NULL) == Z_OK);
+}
+
+void on_versioned_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    reply_on(query, "zenoh/test/get/consolidate/a", "1");
+    reply_on(query, "zenoh/test/get/consolidate/b", "5");
+    reply_on(query, "zenoh/test/get/consolidate/a", "3");
+    z_owned_bytes_t err;
+    z_bytes_copy_from_str(&err, "error");
+    assert(z_query_reply_err(query, z_move(err), NULL) == Z_OK);
+    reply_on(query, "zenoh/test/get/consolidate/a", "2");
+}
+
+int reply_version(const z_loaned_reply_t *reply) {
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(z_reply_ok(reply)), &value);
+    int version = z_string_data(z_loan(value))[0] - '0';
+    z_drop(z_move(value));
+    return version;
+}
+
+void keep_latest_version(z_loaned_reply_t *kept, z_loaned_reply_t *candidate, bool *replace, void *context) {
+    (*(int *)context)++;
+    *replace = reply_version(candidate) > reply_version(kept);
+}
+
+void test_consolidation_hook(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/get/consolidate/*");
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_versioned_query, NULL, NULL);
+    z_owned_queryable_t qable;
+    assert(z_declare_queryable(z_loan(s), &qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+
+    int calls = 0;
+    z_owned_closure_reply_consolidation_t hook;
+    z_closure(&hook, keep_latest_version, NULL, &calls);
+    z_get_options_t options;
+    get_options(&options, 5000);
+    options.consolidation = z_query_consolidation_latest();
+    options.consolidation_hook = z_move(hook);
+    z_owned_reply_array_t replies;
+    assert(z_get_collect(z_loan(s), z_loan(ke), "", &replies, 0, &options) == Z_OK);
+    assert(calls == 2);
+    assert(z_reply_array_len(z_loan(replies)) == 3);
+    assert(!z_reply_is_ok(z_reply_array_get(z_loan(replies), 0)));
+    const z_loaned_reply_t *a = z_reply_array_get(z_loan(replies), 1);
+    const z_loaned_reply_t *b = z_reply_array_get(z_loan(replies), 2);
+    z_view_string_t key;
+    z_keyexpr_as_view_string(z_sample_keyexpr(z_reply_ok(a)), &key);
+    assert(strncmp(z_string_data(z_loan(key)), "zenoh/test/get/consolidate/a", z_string_len(z_loan(key))) == 0);
+    assert(reply_version(a) == 3);
+    assert(reply_version(b) == 5);
+    z_drop(z_move(replies));
+
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+
+z_owned_query_t held_query;
+
+void on_held_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    reply_on(query, "zenoh/test/get/querier", "0");
+    z_query_clone(&held_query, query);
+}
+
+void on_failing_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    z_owned_bytes_t err;
+    z_bytes_copy_from_str(&err, "error");
+    assert(z_query_reply_err(query, z_move(err), NULL) == Z_OK);
+}
+
+uint32_t replier_eid(const z_loaned_reply_t *reply) {
+    z_entity_global_id_t id;
+    assert(z_reply_replier_id(reply, &id));
+    return z_entity_global_id_eid(&id);
+}
+
+void test_querier_stats(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/get/querier");
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_held_query, NULL, NULL);
+    z_owned_queryable_t held;
+    assert(z_declare_queryable(z_loan(s), &held, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_closure(&callback, on_failing_query, NULL, NULL);
+    z_owned_queryable_t failing;
+    assert(z_declare_queryable(z_loan(s), &failing, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+
+    z_owned_querier_t querier;
+    z_querier_options_t querier_options;
+    z_querier_options_default(&querier_options);
+    querier_options.consolidation = z_query_consolidation_none();
+    querier_options.timeout_ms = 1000;
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(ke), &querier_options) == Z_OK);
+
+    z_querier_stats_t stats;
+    z_querier_stats(z_loan(querier), &stats);
+    assert(stats.queries_sent == 0 && stats.replies == 0 && stats.latency_p99_us == 0);
+
+    // the second reply of the held queryable is sent after the error, but is handed over right after its first reply
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    z_querier_get_options_t options;
+    z_querier_get_options_default(&options);
+    options.group_by_replier = true;
+    assert(z_querier_get(z_loan(querier), "", z_move(closure), &options) == Z_OK);
+    z_sleep_ms(200);
+    reply_on(z_loan(held_query), "zenoh/test/get/querier", "1");
+    z_drop(z_move(held_query));
+
+    z_owned_reply_t replies[3];
+    for (size_t i = 0; i < 3; i++) {
+        assert(z_recv(z_loan(handler), &replies[i]) == Z_OK);
+    }
+    z_owned_reply_t extra;
+    assert(z_recv(z_loan(handler), &extra) == Z_CHANNEL_DISCONNECTED);
+    // the queryables may be queried in any order, but the replies of the held one are always adjacent
+    size_t first = z_reply_is_ok(z_loan(replies[0])) ? 0 : 1;
+    size_t err = first == 0 ? 2 : 0;
+    assert(z_reply_is_ok(z_loan(replies[first])) && z_reply_is_ok(z_loan(replies[first + 1])));
+    assert(!z_reply_is_ok(z_loan(replies[err])));
+    assert(replier_eid(z_loan(replies[first])) == replier_eid(z_loan(replies[first + 1])));
+    assert(replier_eid(z_loan(replies[first])) != replier_eid(z_loan(replies[err])));
+    assert(reply_version(z_loan(replies[first])) == 0);
+    assert(reply_version(z_loan(replies[first + 1])) == 1);
+    for (size_t i = 0; i < 3; i++) {
+        z_drop(z_move(replies[i]));
+    }
+    z_drop(z_move(handler));
+
+    z_querier_stats(z_loan(querier), &stats);
+    assert(stats.queries_sent == 1);
+    assert(stats.replies == 2);
+    assert(stats.errors == 1);
+    assert(stats.timeouts == 0);
+    assert(stats.latency_p50_us <= stats.latency_p90_us && stats.latency_p90_us <= stats.latency_p99_us);
+    assert(stats.latency_p99_us >= 200000);
+
+    // the held query is never finalized, so the query times out
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    assert(z_querier_get(z_loan(querier), "", z_move(closure), NULL) == Z_OK);
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(handler), &reply) == Z_OK) {
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(handler));
+    z_querier_stats(z_loan(querier), &stats);
+    assert(stats.queries_sent == 2);
+    assert(stats.replies == 3);
+    assert(stats.errors == 2);
+    assert(stats.timeouts == 1);
+    z_drop(z_move(held_query));
+
+    z_querier_reset_stats(z_loan(querier));
+    z_querier_stats(z_loan(querier), &stats);
+    assert(stats.queries_sent == 0 && stats.replies == 0 && stats.errors == 0 && stats.timeouts == 0);
+    assert(stats.latency_p50_us == 0);
+
+    z_drop(z_move(querier));
+    z_drop(z_move(held));
+    z_drop(z_move(failing));
+    z_drop(z_move(s));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_collect();
+    test_consolidation_hook();
+    test_querier_stats();
+#endif
+    return 0;
+}
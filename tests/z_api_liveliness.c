@@ -146,7 +146,298 @@ void test_liveliness_get() {
     z_drop(z_move(s2));
 }
 
+#if defined(Z_FEATURE_UNSTABLE_API)
+bool payload_eq(const z_loaned_sample_t* s, const char* expected) {
+    z_owned_string_t payload;
+    z_bytes_to_string(z_sample_payload(s), &payload);
+    bool eq = z_string_len(z_loan(payload)) == strlen(expected) &&
+              strncmp(z_string_data(z_loan(payload)), expected, strlen(expected)) == 0;
+    z_drop(z_move(payload));
+    return eq;
+}
+
+bool keyexpr_eq(const z_loaned_sample_t* s, const char* expected) {
+    z_view_string_t ks;
+    z_keyexpr_as_view_string(z_sample_keyexpr(s), &ks);
+    return z_string_len(z_loan(ks)) == strlen(expected) &&
+           strncmp(z_string_data(z_loan(ks)), expected, strlen(expected)) == 0;
+}
+
+void test_liveliness_payload() {
+    const char* expr = "zenoh/liveliness/test/*";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1, k2;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+    z_view_keyexpr_from_str(&k2, token2_expr);
+
+    z_open(&s1, z_move(c1), NULL);
+    z_open(&s2, z_move(c2), NULL);
+
+    z_owned_fifo_handler_sample_t sub_handler;
+    z_owned_closure_sample_t closure;
+    z_fifo_channel_sample_new(&closure, &sub_handler, 16);
+    z_liveliness_subscriber_options_t sub_opts;
+    z_liveliness_subscriber_options_default(&sub_opts);
+    sub_opts.fetch_payload = true;
+    z_owned_subscriber_t sub;
+    assert(z_liveliness_declare_subscriber(z_loan(s2), &sub, z_loan(k), z_move(closure), &sub_opts) == Z_OK);
+    z_sleep_s(1);
+
+    z_owned_bytes_t version;
+    z_bytes_copy_from_str(&version, "v1.2");
+    z_liveliness_token_options_t opts;
+    z_liveliness_token_options_default(&opts);
+    opts.payload = z_loan(version);
+    z_owned_liveliness_token_t t1, t2;
+    assert(z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), &opts) == Z_OK);
+    z_drop(z_move(version));
+    assert(z_liveliness_declare_token(z_loan(s1), &t2, z_loan(k2), NULL) == Z_OK);
+
+    z_owned_sample_t sample;
+    for (int i = 0; i < 2; i++) {
+        assert(z_recv(z_loan(sub_handler), &sample) == Z_OK);
+        assert(z_sample_kind(z_loan(sample)) == Z_SAMPLE_KIND_PUT);
+        if (keyexpr_eq(z_loan(sample), token1_expr)) {
+            assert(payload_eq(z_loan(sample), "v1.2"));
+        } else {
+            assert(keyexpr_eq(z_loan(sample), token2_expr));
+            assert(payload_eq(z_loan(sample), ""));
+        }
+        z_drop(z_move(sample));
+    }
+
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t cb;
+    z_fifo_channel_reply_new(&cb, &handler, 3);
+    z_liveliness_get_options_t get_opts;
+    z_liveliness_get_options_default(&get_opts);
+    get_opts.fetch_payload = true;
+    assert(z_liveliness_get(z_loan(s2), z_loan(k1), z_move(cb), &get_opts) == Z_OK);
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(handler), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    assert(keyexpr_eq(z_reply_ok(z_loan(reply)), token1_expr));
+    assert(payload_eq(z_reply_ok(z_loan(reply)), "v1.2"));
+    z_drop(z_move(reply));
+    assert(z_recv(z_loan(handler), &reply) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(handler));
+
+    z_liveliness_undeclare_token(z_move(t1));
+    assert(z_recv(z_loan(sub_handler), &sample) == Z_OK);
+    assert(z_sample_kind(z_loan(sample)) == Z_SAMPLE_KIND_DELETE);
+    assert(keyexpr_eq(z_loan(sample), token1_expr));
+    z_drop(z_move(sample));
+
+    z_drop(z_move(t2));
+    z_drop(z_move(sub));
+    z_drop(z_move(sub_handler));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
+typedef struct tracker_context_t {
+    int joined;
+    int left;
+} tracker_context_t;
+
+void on_tracker_change(z_loaned_sample_t* s, void* context) {
+    tracker_context_t* c = (tracker_context_t*)context;
+    if (z_sample_kind(s) == Z_SAMPLE_KIND_PUT) {
+        c->joined++;
+    } else if (z_sample_kind(s) == Z_SAMPLE_KIND_DELETE) {
+        c->left++;
+    }
+}
+
+bool count_until_token2(const z_loaned_keyexpr_t* key_expr, void* context) {
+    int* count = (int*)context;
+    (*count)++;
+    z_view_string_t ks;
+    z_keyexpr_as_view_string(key_expr, &ks);
+    return z_string_len(z_loan(ks)) == strlen(token2_expr) &&
+           strncmp(z_string_data(z_loan(ks)), token2_expr, strlen(token2_expr)) == 0;
+}
+
+void test_liveliness_tracker() {
+    const char* expr = "zenoh/liveliness/test/*";
+    const char* token3_expr = "zenoh/liveliness/test/3";
+
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k, k1, k2, k3;
+    z_view_keyexpr_from_str(&k, expr);
+    z_view_keyexpr_from_str(&k1, token1_expr);
+    z_view_keyexpr_from_str(&k2, token2_expr);
+    z_view_keyexpr_from_str(&k3, token3_expr);
+
+    z_open(&s1, z_move(c1), NULL);
+    z_open(&s2, z_move(c2), NULL);
+
+    z_owned_liveliness_token_t t1, t2, t3;
+    z_liveliness_declare_token(z_loan(s1), &t1, z_loan(k1), NULL);
+    z_liveliness_declare_token(z_loan(s1), &t2, z_loan(k2), NULL);
+    z_sleep_s(1);
+
+    tracker_context_t context = {0, 0};
+    z_owned_closure_sample_t closure;
+    z_closure(&closure, on_tracker_change, NULL, (void*)(&context));
+    z_liveliness_tracker_options_t opts;
+    z_liveliness_tracker_options_default(&opts);
+    opts.callback = z_move(closure);
+
+    z_owned_liveliness_tracker_t tracker;
+    assert(z_liveliness_declare_tracker(z_loan(s2), &tracker, z_loan(k), &opts) == Z_OK);
+
+    // the initially alive tokens are known as soon as the tracker is declared
+    assert(z_liveliness_tracker_len(z_loan(tracker)) == 2);
+    assert(z_liveliness_tracker_contains(z_loan(tracker), z_loan(k1)));
+    assert(z_liveliness_tracker_contains(z_loan(tracker), z_loan(k2)));
+    assert(!z_liveliness_tracker_contains(z_loan(tracker), z_loan(k3)));
+    assert(context.joined == 2);
+
+    z_liveliness_declare_token(z_loan(s1), &t3, z_loan(k3), NULL);
+    z_sleep_s(1);
+    assert(z_liveliness_tracker_len(z_loan(tracker)) == 3);
+    assert(z_liveliness_tracker_contains(z_loan(tracker), z_loan(k3)));
+    assert(context.joined == 3);
+
+    z_owned_string_array_t snapshot;
+    z_liveliness_tracker_snapshot(z_loan(tracker), &snapshot);
+    assert(z_string_array_len(z_loan(snapshot)) == 3);
+    const char* expected[] = {token1_expr, token2_expr, token3_expr};
+    for (size_t i = 0; i < 3; i++) {
+        const z_loaned_string_t* member = z_string_array_get(z_loan(snapshot), i);
+        assert(z_string_len(member) == strlen(expected[i]));
+        assert(strncmp(z_string_data(member), expected[i], strlen(expected[i])) == 0);
+    }
+    z_drop(z_move(snapshot));
+
+    int count = 0;
+    z_liveliness_tracker_iterate(z_loan(tracker), count_until_token2, &count);
+    assert(count == 2);
+
+    z_liveliness_undeclare_token(z_move(t1));
+    z_sleep_s(1);
+    assert(z_liveliness_tracker_len(z_loan(tracker)) == 2);
+    assert(!z_liveliness_tracker_contains(z_loan(tracker), z_loan(k1)));
+    assert(context.left == 1);
+
+    assert(z_liveliness_undeclare_tracker(z_move(tracker)) == Z_OK);
+    z_liveliness_undeclare_token(z_move(t2));
+    z_sleep_s(1);
+    assert(context.left == 1);
+
+    z_drop(z_move(t3));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
+typedef struct leadership_context_t {
+    int gained;
+    int lost;
+} leadership_context_t;
+
+void on_leadership_change(const z_leadership_status_t* status, void* context) {
+    leadership_context_t* c = (leadership_context_t*)context;
+    if (status->is_leader) {
+        c->gained++;
+    } else {
+        c->lost++;
+    }
+}
+
+void test_liveliness_election() {
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k;
+    z_view_keyexpr_from_str(&k, "zenoh/liveliness/test/election");
+
+    z_open(&s1, z_move(c1), NULL);
+    z_open(&s2, z_move(c2), NULL);
+    z_sleep_s(1);
+
+    leadership_context_t context1 = {0, 0}, context2 = {0, 0};
+    z_owned_closure_leadership_status_t closure1, closure2;
+    z_closure(&closure1, on_leadership_change, NULL, (void*)(&context1));
+    z_closure(&closure2, on_leadership_change, NULL, (void*)(&context2));
+
+    z_owned_liveliness_election_t e1, e2;
+    assert(z_liveliness_declare_election(z_loan(s1), &e1, z_loan(k), z_move(closure1)) == Z_OK);
+    z_sleep_s(1);
+    assert(z_liveliness_election_is_leader(z_loan(e1)));
+    assert(context1.gained == 1);
+
+    // a new candidate does not take over an alive leader, whatever its zid
+    assert(z_liveliness_declare_election(z_loan(s2), &e2, z_loan(k), z_move(closure2)) == Z_OK);
+    z_sleep_s(1);
+    assert(z_liveliness_election_is_leader(z_loan(e1)));
+    assert(!z_liveliness_election_is_leader(z_loan(e2)));
+    assert(context2.gained == 0);
+
+    assert(z_liveliness_undeclare_election(z_move(e1)) == Z_OK);
+    z_sleep_s(1);
+    assert(z_liveliness_election_is_leader(z_loan(e2)));
+    assert(context2.gained == 1);
+    assert(context1.lost == 0);
+
+    z_drop(z_move(e2));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+
+void test_liveliness_lock() {
+    z_owned_session_t s1, s2;
+    z_owned_config_t c1, c2;
+    z_config_default(&c1);
+    z_config_default(&c2);
+    z_view_keyexpr_t k;
+    z_view_keyexpr_from_str(&k, "zenoh/liveliness/test/lock");
+
+    z_open(&s1, z_move(c1), NULL);
+    z_open(&s2, z_move(c2), NULL);
+    z_sleep_s(1);
+
+    z_owned_liveliness_lock_t l1, l2;
+    assert(z_liveliness_lock_acquire(z_loan(s1), &l1, z_loan(k), NULL) == Z_OK);
+    assert(z_liveliness_lock_is_held(z_loan(l1)));
+
+    z_liveliness_lock_options_t opts;
+    z_liveliness_lock_options_default(&opts);
+    opts.timeout_ms = 1000;
+    assert(z_liveliness_lock_acquire(z_loan(s2), &l2, z_loan(k), &opts) == Z_ETIMEDOUT);
+    assert(!z_internal_check(l2));
+    // contenders of the same session are distinct
+    assert(z_liveliness_lock_acquire(z_loan(s1), &l2, z_loan(k), &opts) == Z_ETIMEDOUT);
+    assert(!z_internal_check(l2));
+    assert(z_liveliness_lock_is_held(z_loan(l1)));
+
+    assert(z_liveliness_lock_release(z_move(l1)) == Z_OK);
+    opts.timeout_ms = 5000;
+    assert(z_liveliness_lock_acquire(z_loan(s2), &l2, z_loan(k), &opts) == Z_OK);
+    assert(z_liveliness_lock_is_held(z_loan(l2)));
+
+    z_drop(z_move(l2));
+    z_drop(z_move(s1));
+    z_drop(z_move(s2));
+}
+#endif
+
 int main(int argc, char** argv) {
     test_liveliness_sub();
     test_liveliness_get();
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_liveliness_payload();
+    test_liveliness_tracker();
+    test_liveliness_election();
+    test_liveliness_lock();
+#endif
 }
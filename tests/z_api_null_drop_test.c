@@ -64,22 +64,40 @@ int main(void) {
     TEST(z_owned_closure_sample_t)
     TEST(z_owned_closure_query_t)
     TEST(z_owned_closure_reply_t)
+#if defined(Z_FEATURE_UNSTABLE_API)
+    TEST(z_owned_closure_reply_consolidation_t)
+    TEST(z_owned_closure_leadership_status_t)
+#endif
     TEST(z_owned_closure_hello_t)
 #if defined(Z_FEATURE_UNSTABLE_API_t)
     TEST(z_owned_closure_zid_t)
 #endif
     TEST(z_owned_string_t)
     TEST(z_owned_string_array_t)
+#if defined(Z_FEATURE_UNSTABLE_API)
+    TEST(z_owned_slice_array_t)
+    TEST(z_owned_slice_map_t)
+#endif
     TEST(z_owned_sample_t)
     TEST(z_owned_query_t)
+#if defined(Z_FEATURE_UNSTABLE_API)
+    TEST(z_owned_reply_stream_t)
+    TEST(z_owned_parameters_t)
+#endif
     TEST(z_owned_slice_t)
     TEST(z_owned_bytes_t)
     TEST(z_owned_bytes_writer_t)
     TEST(z_owned_encoding_t)
     TEST(z_owned_publisher_t)
+#if defined(Z_FEATURE_UNSTABLE_API)
+    TEST(z_owned_prefix_publisher_t)
+#endif
     TEST(z_owned_subscriber_t)
     TEST(z_owned_queryable_t)
     TEST(z_owned_reply_t)
+#if defined(Z_FEATURE_UNSTABLE_API)
+    TEST(z_owned_reply_array_t)
+#endif
 
     TEST(ze_owned_serializer_t)
     // Double drop not supported for these types
@@ -0,0 +1,205 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+bool str_eq(const z_loaned_string_t *s, const char *expected) {
+    return z_string_len(s) == strlen(expected) && strncmp(z_string_data(s), expected, z_string_len(s)) == 0;
+}
+
+bool view_eq(const z_view_string_t *s, const char *expected) { return str_eq(z_loan(*s), expected); }
+
+typedef struct pairs_t {
+    size_t count;
+    char joined[64];
+} pairs_t;
+
+bool collect_pair(const z_loaned_string_t *key, const z_loaned_string_t *value, void *context) {
+    pairs_t *pairs = (pairs_t *)context;
+    size_t len = strlen(pairs->joined);
+    snprintf(pairs->joined + len, sizeof(pairs->joined) - len, "%.*s:%.*s,", (int)z_string_len(key),
+             z_string_data(key), (int)z_string_len(value), z_string_data(value));
+    pairs->count++;
+    return pairs->count == 3;
+}
+
+void test_parse(void) {
+    z_owned_parameters_t p;
+    assert(z_parameters_from_str(&p, "a=1;b=2|3;flag;;c=x=y;d=4") == Z_OK);
+    assert(!z_parameters_is_empty(z_loan(p)));
+
+    z_view_string_t value;
+    assert(z_parameters_get(z_loan(p), "a", &value));
+    assert(view_eq(&value, "1"));
+    assert(z_parameters_get(z_loan(p), "b", &value));
+    assert(view_eq(&value, "2|3"));
+    assert(z_parameters_get(z_loan(p), "flag", &value));
+    assert(view_eq(&value, ""));
+    assert(z_parameters_get(z_loan(p), "c", &value));
+    assert(view_eq(&value, "x=y"));
+    assert(!z_parameters_get(z_loan(p), "e", &value));
+
+    pairs_t pairs = {0};
+    z_parameters_iterate(z_loan(p), collect_pair, &pairs);
+    assert(pairs.count == 3);
+    assert(strcmp(pairs.joined, "a:1,b:2|3,flag:,") == 0);
+
+    z_owned_parameters_t empty;
+    z_parameters_new(&empty);
+    assert(z_parameters_is_empty(z_loan(empty)));
+    assert(!z_internal_check(empty));
+    z_drop(z_move(empty));
+
+    z_drop(z_move(p));
+    assert(!z_internal_check(p));
+}
+
+void test_build(void) {
+    z_owned_parameters_t p;
+    z_parameters_new(&p);
+    assert(z_parameters_insert(z_loan_mut(p), "a", "1") == Z_OK);
+    assert(z_parameters_insert(z_loan_mut(p), "b", "2|3") == Z_OK);
+    assert(z_parameters_insert(z_loan_mut(p), "a", "4") == Z_OK);
+    assert(z_parameters_insert(z_loan_mut(p), "c;d", "5") == Z_EINVAL);
+    assert(z_parameters_insert(z_loan_mut(p), "c=d", "5") == Z_EINVAL);
+    assert(z_parameters_insert(z_loan_mut(p), "", "5") == Z_EINVAL);
+    assert(z_parameters_insert(z_loan_mut(p), "c", "5;d=6") == Z_EINVAL);
+
+    z_view_string_t s;
+    z_parameters_as_str(z_loan(p), &s);
+    assert(view_eq(&s, "b=2|3;a=4"));
+
+    assert(z_parameters_remove(z_loan_mut(p), "b"));
+    assert(!z_parameters_remove(z_loan_mut(p), "b"));
+    z_parameters_as_str(z_loan(p), &s);
+    assert(view_eq(&s, "a=4"));
+
+    z_owned_parameters_t copy;
+    z_parameters_clone(&copy, z_loan(p));
+    z_drop(z_move(p));
+    z_parameters_as_str(z_loan(copy), &s);
+    assert(view_eq(&s, "a=4"));
+    z_drop(z_move(copy));
+}
+
+void test_time_range(void) {
+    z_owned_parameters_t p;
+    z_time_range_t range;
+    assert(z_parameters_from_str(&p, "_time=[2024-01-01T00:00:00Z..2024-01-02T00:00:00Z[") == Z_OK);
+    assert(z_parameters_time_range(z_loan(p), &range) == Z_OK);
+    assert(range.start.kind == Z_TIME_BOUND_KIND_INCLUSIVE);
+    assert(range.start.time_ms == 1704067200000ULL);
+    assert(range.end.kind == Z_TIME_BOUND_KIND_EXCLUSIVE);
+    assert(range.end.time_ms == 1704153600000ULL);
+    assert(z_time_range_contains(&range, 1704067200000ULL));
+    assert(z_time_range_contains(&range, 1704100000000ULL));
+    assert(!z_time_range_contains(&range, 1704153600000ULL));
+    z_drop(z_move(p));
+
+    assert(z_parameters_from_str(&p, "a=1;_time=[now(-1h)..]") == Z_OK);
+    assert(z_parameters_time_range(z_loan(p), &range) == Z_OK);
+    assert(range.start.kind == Z_TIME_BOUND_KIND_INCLUSIVE);
+    assert(range.end.kind == Z_TIME_BOUND_KIND_UNBOUNDED);
+    z_time_range_t fixed = {{Z_TIME_BOUND_KIND_UNBOUNDED, 0}, {Z_TIME_BOUND_KIND_INCLUSIVE, 1704067200000ULL}};
+    z_parameters_set_time_range(z_loan_mut(p), &fixed);
+    assert(z_parameters_time_range(z_loan(p), &range) == Z_OK);
+    assert(range.start.kind == Z_TIME_BOUND_KIND_UNBOUNDED);
+    assert(range.end.kind == Z_TIME_BOUND_KIND_INCLUSIVE);
+    assert(range.end.time_ms == 1704067200000ULL);
+    z_parameters_set_time_range(z_loan_mut(p), NULL);
+    assert(z_parameters_time_range(z_loan(p), &range) == Z_EUNAVAILABLE);
+    assert(z_parameters_insert(z_loan_mut(p), "_time", "yesterday") == Z_OK);
+    assert(z_parameters_time_range(z_loan(p), &range) == Z_EINVAL);
+    z_drop(z_move(p));
+}
+
+void on_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    const z_loaned_parameters_t *params = z_query_selector_parameters(query);
+    z_view_string_t value;
+    if (!z_parameters_get(params, "name", &value)) {
+        z_owned_bytes_t err;
+        z_bytes_copy_from_str(&err, "missing name");
+        z_query_reply_err(query, z_move(err), NULL);
+        return;
+    }
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, (const uint8_t *)z_string_data(z_loan(value)), z_string_len(z_loan(value)));
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+void test_query(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/parameters");
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t qable;
+    assert(z_declare_queryable(z_loan(s), &qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+
+    z_owned_parameters_t p;
+    z_parameters_new(&p);
+    assert(z_parameters_insert(z_loan_mut(p), "name", "zenoh|c") == Z_OK);
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    assert(z_get_with_parameters(z_loan(s), z_loan(ke), z_loan(p), z_move(closure), NULL) == Z_OK);
+    z_owned_reply_t reply;
+    assert(z_recv(z_loan(handler), &reply) == Z_OK);
+    assert(z_reply_is_ok(z_loan(reply)));
+    z_owned_string_t value;
+    z_bytes_to_string(z_sample_payload(z_reply_ok(z_loan(reply))), &value);
+    assert(str_eq(z_loan(value), "zenoh|c"));
+    z_drop(z_move(value));
+    z_drop(z_move(reply));
+    z_drop(z_move(handler));
+
+    z_owned_querier_t querier;
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(ke), NULL) == Z_OK);
+    assert(z_parameters_remove(z_loan_mut(p), "name"));
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    assert(z_querier_get_with_parameters(z_loan(querier), z_loan(p), z_move(closure), NULL) == Z_OK);
+    assert(z_recv(z_loan(handler), &reply) == Z_OK);
+    assert(!z_reply_is_ok(z_loan(reply)));
+    z_drop(z_move(reply));
+    z_drop(z_move(handler));
+
+    z_drop(z_move(p));
+    z_drop(z_move(querier));
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_parse();
+    test_build();
+    test_time_range();
+    test_query();
+#endif
+    return 0;
+}
@@ -260,6 +260,143 @@ void test_slices(void) {
     z_drop(z_move(payload));
 }
 
+#if defined(Z_FEATURE_UNSTABLE_API)
+void test_iovec(void) {
+    uint8_t data[] = {0, 1, 2, 3, 4, 5, 6, 7, 8, 9};
+    z_iovec_t iov[3] = {{data, 3}, {data + 3, 5}, {data + 8, 2}};
+
+    size_t cnt = 0;
+    z_owned_bytes_t payload;
+    assert(z_bytes_from_iovec(&payload, iov, 3, custom_deleter, (void *)&cnt) == Z_OK);
+    assert(z_bytes_len(z_loan(payload)) == 10);
+    assert(check_slice(z_loan(payload), data, 10));
+
+    size_t n = z_bytes_to_iovec(z_loan(payload), NULL, 0);
+    assert(n > 0 && n <= 3);
+    z_iovec_t out[3];
+    assert(z_bytes_to_iovec(z_loan(payload), out, 3) == n);
+    size_t pos = 0;
+    for (size_t i = 0; i < n; i++) {
+        assert(memcmp(data + pos, out[i].iov_base, out[i].iov_len) == 0);
+        pos += out[i].iov_len;
+    }
+    assert(pos == 10);
+
+    assert(cnt == 0);
+    z_drop(z_move(payload));
+    assert(cnt == 3);
+
+    z_iovec_t bad[2] = {{data, 3}, {NULL, 5}};
+    assert(z_bytes_from_iovec(&payload, bad, 2, custom_deleter, (void *)&cnt) == Z_EINVAL);
+    z_drop(z_move(payload));
+    assert(cnt == 3);
+}
+#endif
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+void test_reader_utilities(void) {
+    const char *fragments[] = {"ab", "\r", "\ncd\r\nef"};
+    z_owned_bytes_writer_t writer;
+    z_bytes_writer_empty(&writer);
+    for (size_t i = 0; i < 3; i++) {
+        z_owned_bytes_t b;
+        z_bytes_copy_from_str(&b, fragments[i]);
+        z_bytes_writer_append(z_loan_mut(writer), z_move(b));
+    }
+    z_owned_bytes_t payload;
+    z_bytes_writer_finish(z_move(writer), &payload);
+
+    z_bytes_reader_t reader = z_bytes_get_reader(z_loan(payload));
+    size_t offset = 0;
+    assert(z_bytes_reader_find(&reader, (const uint8_t *)"\r\n", 2, &offset));
+    assert(offset == 2);
+    assert(!z_bytes_reader_find(&reader, (const uint8_t *)"xy", 2, &offset));
+    assert(z_bytes_reader_tell(&reader) == 0);
+
+    z_owned_bytes_t line;
+    assert(z_bytes_reader_read_until(&reader, (const uint8_t *)"\r\n", 2, &line) == Z_OK);
+    assert(z_check_and_drop_payload(&line, (uint8_t *)"ab", 2));
+    assert(z_bytes_reader_read_until(&reader, (const uint8_t *)"\r\n", 2, &line) == Z_OK);
+    assert(z_check_and_drop_payload(&line, (uint8_t *)"cd", 2));
+    assert(z_bytes_reader_read_until(&reader, (const uint8_t *)"\r\n", 2, &line) != Z_OK);
+    assert(z_bytes_reader_remaining(&reader) == 2);
+
+    z_owned_bytes_t rest;
+    assert(z_bytes_reader_read_bytes(&reader, 3, &rest) != Z_OK);
+    assert(z_bytes_reader_read_bytes(&reader, 2, &rest) == Z_OK);
+    assert(z_check_and_drop_payload(&rest, (uint8_t *)"ef", 2));
+    assert(z_bytes_reader_remaining(&reader) == 0);
+
+    uint8_t out[4] = {0};
+    assert(0 == z_bytes_reader_seek(&reader, 0, SEEK_SET));
+    assert(z_bytes_reader_read_exact(&reader, out, 4) == Z_OK);
+    assert(memcmp(out, "ab\r\n", 4) == 0);
+    assert(0 == z_bytes_reader_seek(&reader, -1, SEEK_END));
+    assert(z_bytes_reader_read_exact(&reader, out, 2) != Z_OK);
+    assert(z_bytes_reader_remaining(&reader) == 1);
+
+    z_drop(z_move(payload));
+}
+#endif
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+void test_compression(void) {
+    uint8_t data[1024];
+    for (size_t i = 0; i < sizeof(data); i++) {
+        data[i] = (uint8_t)(i % 16);
+    }
+    z_compression_algorithm_t algorithms[] = {Z_COMPRESSION_ALGORITHM_LZ4, Z_COMPRESSION_ALGORITHM_ZSTD};
+    for (size_t i = 0; i < 2; i++) {
+        z_owned_bytes_t payload;
+        z_bytes_copy_from_buf(&payload, data, sizeof(data));
+        z_owned_encoding_t encoding;
+        z_encoding_from_str(&encoding, "text/plain;utf-8");
+
+        z_bytes_compress_options_t opts;
+        z_bytes_compress_options_default(&opts);
+        opts.algorithm = algorithms[i];
+        z_owned_bytes_t compressed;
+        z_owned_encoding_t compressed_encoding;
+        assert(z_bytes_compress(&compressed, &compressed_encoding, z_loan(payload), z_loan(encoding), &opts) ==
+               Z_OK);
+        assert(z_bytes_len(z_loan(compressed)) < sizeof(data));
+        z_compression_algorithm_t algorithm;
+        assert(z_encoding_is_compressed(z_loan(compressed_encoding), &algorithm));
+        assert(algorithm == algorithms[i]);
+        assert(!z_encoding_is_compressed(z_loan(encoding), NULL));
+
+        z_owned_bytes_t decompressed;
+        z_owned_encoding_t decompressed_encoding;
+        assert(z_bytes_decompress(&decompressed, &decompressed_encoding, z_loan(compressed),
+                                  z_loan(compressed_encoding), NULL) == Z_OK);
+        assert(check_slice(z_loan(decompressed), data, sizeof(data)));
+        assert(z_encoding_equals(z_loan(decompressed_encoding), z_loan(encoding)));
+        z_drop(z_move(decompressed));
+        z_drop(z_move(decompressed_encoding));
+
+        assert(z_bytes_decompress(&decompressed, &decompressed_encoding, z_loan(payload), z_loan(encoding), NULL) !=
+               Z_OK);
+        z_drop(z_move(decompressed));
+        z_drop(z_move(decompressed_encoding));
+
+        // decompressed data must not exceed the maximum size
+        z_bytes_decompress_options_t decompress_opts;
+        z_bytes_decompress_options_default(&decompress_opts);
+        decompress_opts.max_size = sizeof(data) - 1;
+        assert(z_bytes_decompress(&decompressed, &decompressed_encoding, z_loan(compressed),
+                                  z_loan(compressed_encoding), &decompress_opts) == Z_EINVAL);
+        assert(z_bytes_len(z_loan(decompressed)) == 0);
+        z_drop(z_move(decompressed));
+        z_drop(z_move(decompressed_encoding));
+
+        z_drop(z_move(compressed));
+        z_drop(z_move(compressed_encoding));
+        z_drop(z_move(encoding));
+        z_drop(z_move(payload));
+    }
+}
+#endif
+
 void test_serialize_simple(void) {
     z_owned_bytes_t b;
     ze_owned_serializer_t serializer;
@@ -315,6 +452,54 @@ void test_serialize_sequence(void) {
     z_drop(z_move(b));
 }
 
+#if defined(Z_FEATURE_UNSTABLE_API)
+void test_attachment_map(void) {
+    z_owned_slice_map_t map;
+    z_slice_map_new(&map);
+    z_view_slice_t key, value;
+    z_view_slice_from_buf(&key, (const uint8_t *)"trace_id", 8);
+    z_view_slice_from_buf(&value, (const uint8_t *)"0af7651916cd43dd", 16);
+    z_slice_map_insert_by_copy(z_loan_mut(map), z_loan(key), z_loan(value));
+    z_view_slice_from_buf(&key, (const uint8_t *)"span_id", 7);
+    z_view_slice_from_buf(&value, (const uint8_t *)"\x00\x01", 2);
+    z_slice_map_insert_by_copy(z_loan_mut(map), z_loan(key), z_loan(value));
+
+    z_owned_bytes_t attachment;
+    assert(ze_serialize_attachment_map(&attachment, z_loan(map)) == Z_OK);
+
+    z_owned_slice_map_t out;
+    assert(ze_deserialize_attachment_map(z_loan(attachment), &out) == Z_OK);
+    assert(z_slice_map_len(z_loan(out)) == 2);
+    const z_loaned_slice_t *v = z_slice_map_get(z_loan(out), z_loan(key));
+    assert(v != NULL && z_slice_len(v) == 2 && memcmp(z_slice_data(v), "\x00\x01", 2) == 0);
+    z_drop(z_move(out));
+
+    z_owned_slice_t trace_id;
+    assert(ze_attachment_get(z_loan(attachment), "trace_id", &trace_id) == Z_OK);
+    assert(z_slice_len(z_loan(trace_id)) == 16 && memcmp(z_slice_data(z_loan(trace_id)), "0af7651916cd43dd", 16) == 0);
+    z_drop(z_move(trace_id));
+    assert(ze_attachment_get(z_loan(attachment), "missing", &trace_id) == Z_EUNAVAILABLE);
+    z_drop(z_move(attachment));
+    z_drop(z_move(map));
+
+    // Same layout as `HashMap<String, Vec<u8>>` serialized by zenoh-ext.
+    uint8_t expected[] = {1, 1, 'k', 2, 'v', 'w'};
+    z_slice_map_new(&map);
+    z_view_slice_from_buf(&key, (const uint8_t *)"k", 1);
+    z_view_slice_from_buf(&value, (const uint8_t *)"vw", 2);
+    z_slice_map_insert_by_alias(z_loan_mut(map), z_loan(key), z_loan(value));
+    assert(ze_serialize_attachment_map(&attachment, z_loan(map)) == Z_OK);
+    assert(z_bytes_len(z_loan(attachment)) == sizeof(expected));
+    assert(z_check_and_drop_payload(&attachment, expected, sizeof(expected)));
+    z_drop(z_move(map));
+
+    z_bytes_copy_from_buf(&attachment, expected, 4);
+    assert(ze_deserialize_attachment_map(z_loan(attachment), &out) == Z_EDESERIALIZE);
+    z_drop(z_move(out));
+    z_drop(z_move(attachment));
+}
+#endif
+
 int main(void) {
     test_reader_seek();
     test_reader_read();
@@ -323,6 +508,14 @@ int main(void) {
     test_arithmetic();
     test_append();
     test_slices();
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_iovec();
+    test_reader_utilities();
+    test_compression();
+#endif
     test_serialize_simple();
     test_serialize_sequence();
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_attachment_map();
+#endif
 }
@@ -0,0 +1,253 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct received_keys_t {
+    size_t count;
+    char keys[8][64];
+    bool is_delete[8];
+} received_keys_t;
+
+void on_keyed_sample(z_loaned_sample_t *sample, void *context) {
+    received_keys_t *r = (received_keys_t *)context;
+    if (r->count >= 8) {
+        return;
+    }
+    z_view_string_t key;
+    z_keyexpr_as_view_string(z_sample_keyexpr(sample), &key);
+    size_t len = z_string_len(z_loan(key)) < 63 ? z_string_len(z_loan(key)) : 63;
+    memcpy(r->keys[r->count], z_string_data(z_loan(key)), len);
+    r->keys[r->count][len] = '\0';
+    r->is_delete[r->count] = z_sample_kind(sample) == Z_SAMPLE_KIND_DELETE;
+    r->count++;
+}
+
+void test_prefix_publisher(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t sub_ke, prefix, wild;
+    z_view_keyexpr_from_str(&sub_ke, "zenoh/test/publisher/prefix/**");
+    z_view_keyexpr_from_str(&prefix, "zenoh/test/publisher/prefix");
+    z_view_keyexpr_from_str(&wild, "zenoh/test/publisher/*");
+
+    received_keys_t received = {0};
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_keyed_sample, NULL, &received);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(sub_ke), z_move(callback), NULL) == Z_OK);
+
+    z_owned_prefix_publisher_t pub;
+    assert(z_declare_prefix_publisher(z_loan(s), &pub, z_loan(wild), NULL) == Z_EINVAL);
+    assert(!z_internal_check(pub));
+    z_prefix_publisher_options_t pub_opts;
+    z_prefix_publisher_options_default(&pub_opts);
+    pub_opts.is_express = true;
+    assert(z_declare_prefix_publisher(z_loan(s), &pub, z_loan(prefix), &pub_opts) == Z_OK);
+
+    z_view_string_t pub_ke;
+    z_keyexpr_as_view_string(z_prefix_publisher_keyexpr(z_loan(pub)), &pub_ke);
+    assert(strncmp(z_string_data(z_loan(pub_ke)), "zenoh/test/publisher/prefix", z_string_len(z_loan(pub_ke))) == 0);
+
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "value");
+    assert(z_prefix_publisher_put(z_loan(pub), "1/pose", z_move(payload), NULL) == Z_OK);
+    z_bytes_copy_from_str(&payload, "value");
+    assert(z_prefix_publisher_put(z_loan(pub), "2/pose", z_move(payload), NULL) == Z_OK);
+    assert(z_prefix_publisher_delete(z_loan(pub), "1/pose", NULL) == Z_OK);
+
+    z_owned_bytes_t attachment;
+    z_bytes_copy_from_str(&payload, "value");
+    z_bytes_copy_from_str(&attachment, "meta");
+    z_publisher_put_options_t options;
+    z_publisher_put_options_default(&options);
+    options.attachment = z_move(attachment);
+    assert(z_prefix_publisher_put(z_loan(pub), NULL, z_move(payload), &options) == Z_EINVAL);
+    assert(!z_internal_check(payload));
+    assert(!z_internal_check(attachment));
+
+    z_sleep_ms(500);
+    assert(received.count == 3);
+    assert(strcmp(received.keys[0], "zenoh/test/publisher/prefix/1/pose") == 0);
+    assert(strcmp(received.keys[1], "zenoh/test/publisher/prefix/2/pose") == 0);
+    assert(strcmp(received.keys[2], "zenoh/test/publisher/prefix/1/pose") == 0);
+    assert(!received.is_delete[0]);
+    assert(received.is_delete[2]);
+
+    assert(z_undeclare_prefix_publisher(z_move(pub)) == Z_OK);
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+}
+
+z_result_t put_str(const z_loaned_publisher_t *pub, const char *value) {
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    return z_publisher_put(pub, z_move(payload), NULL);
+}
+
+void test_rate_limit(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/publisher/rate_limit");
+
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+    for (size_t i = 0; i < 20; i++) {
+        assert(put_str(z_loan(pub), "value") == Z_OK);
+    }
+    assert(z_publisher_throttled_count(z_loan(pub)) == 0);
+    z_drop(z_move(pub));
+
+    // drop messages above the rate
+    z_publisher_options_t options;
+    z_publisher_options_default(&options);
+    options.rate_limit.max_messages_per_sec = 5;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), &options) == Z_OK);
+    size_t ok = 0;
+    for (size_t i = 0; i < 10; i++) {
+        z_result_t res = put_str(z_loan(pub), "value");
+        assert(res == Z_OK || res == Z_EUNAVAILABLE);
+        ok += res == Z_OK;
+    }
+    assert(ok == 5);
+    assert(z_publisher_throttled_count(z_loan(pub)) == 5);
+    assert(z_publisher_delete(z_loan(pub), NULL) == Z_EUNAVAILABLE);
+    assert(z_publisher_throttled_count(z_loan(pub)) == 6);
+    z_drop(z_move(pub));
+
+    // drop messages above the byte rate
+    z_publisher_options_default(&options);
+    options.rate_limit.max_bytes_per_sec = 10;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), &options) == Z_OK);
+    assert(put_str(z_loan(pub), "12345678") == Z_OK);
+    assert(put_str(z_loan(pub), "12345678") == Z_EUNAVAILABLE);
+    assert(put_str(z_loan(pub), "12") == Z_OK);
+    assert(z_publisher_throttled_count(z_loan(pub)) == 1);
+    z_drop(z_move(pub));
+
+    // block until messages fit into the rate
+    z_publisher_options_default(&options);
+    options.rate_limit.max_messages_per_sec = 20;
+    options.rate_limit.action = Z_RATE_LIMIT_ACTION_BLOCK;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), &options) == Z_OK);
+    z_clock_t start = z_clock_now();
+    for (size_t i = 0; i < 25; i++) {
+        assert(put_str(z_loan(pub), "value") == Z_OK);
+    }
+    assert(z_clock_elapsed_ms(&start) >= 200);
+    // delayed messages are throttled too
+    assert(z_publisher_throttled_count(z_loan(pub)) == 5);
+    z_drop(z_move(pub));
+
+    // drop messages which would block longer than the timeout
+    z_publisher_options_default(&options);
+    options.rate_limit.max_messages_per_sec = 1;
+    options.rate_limit.action = Z_RATE_LIMIT_ACTION_BLOCK;
+    options.rate_limit.block_timeout_ms = 50;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), &options) == Z_OK);
+    assert(put_str(z_loan(pub), "value") == Z_OK);
+    start = z_clock_now();
+    assert(put_str(z_loan(pub), "value") == Z_EUNAVAILABLE);
+    assert(z_clock_elapsed_ms(&start) < 500);
+    assert(z_publisher_throttled_count(z_loan(pub)) == 1);
+    z_drop(z_move(pub));
+
+    z_drop(z_move(s));
+}
+
+void on_sample(z_loaned_sample_t *sample, void *context) {
+    (void)sample;
+    (void)context;
+}
+
+void test_matching_status_channel(void) {
+    z_owned_config_t c;
+    z_config_default(&c);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(c), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "test/publisher/matching_channel");
+
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+
+    z_owned_closure_matching_status_t closure;
+    z_owned_fifo_handler_matching_status_t fifo;
+    z_fifo_channel_matching_status_new(&closure, &fifo, 16);
+    z_owned_matching_listener_t listener;
+    assert(z_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(closure)) == Z_OK);
+
+    z_matching_status_t status;
+    assert(z_try_recv(z_loan(fifo), &status) == Z_CHANNEL_NODATA);
+
+    z_owned_closure_sample_t cb;
+    z_closure(&cb, on_sample, NULL, NULL);
+    z_owned_subscriber_t sub;
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(cb), NULL) == Z_OK);
+    assert(z_recv(z_loan(fifo), &status) == Z_OK);
+    assert(status.matching);
+
+    z_drop(z_move(sub));
+    assert(z_recv(z_loan(fifo), &status) == Z_OK);
+    assert(!status.matching);
+
+    z_drop(z_move(listener));
+    assert(z_recv(z_loan(fifo), &status) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(fifo));
+
+    // a ring channel only keeps the latest statuses
+    z_owned_ring_handler_matching_status_t ring;
+    z_ring_channel_matching_status_new(&closure, &ring, 1);
+    assert(z_publisher_declare_matching_listener(z_loan(pub), &listener, z_move(closure)) == Z_OK);
+
+    z_closure(&cb, on_sample, NULL, NULL);
+    assert(z_declare_subscriber(z_loan(s), &sub, z_loan(ke), z_move(cb), NULL) == Z_OK);
+    z_sleep_s(1);
+    z_drop(z_move(sub));
+    z_sleep_s(1);
+    assert(z_try_recv(z_loan(ring), &status) == Z_OK);
+    assert(!status.matching);
+    assert(z_try_recv(z_loan(ring), &status) == Z_CHANNEL_NODATA);
+
+    z_drop(z_move(listener));
+    assert(z_try_recv(z_loan(ring), &status) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(ring));
+    z_drop(z_move(pub));
+    z_drop(z_move(s));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_rate_limit();
+    test_prefix_publisher();
+    test_matching_status_channel();
+#endif
+    return 0;
+}
@@ -0,0 +1,429 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+void on_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "reply");
+    z_query_reply(query, z_query_keyexpr(query), z_move(payload), NULL);
+}
+
+size_t get_replies(const z_loaned_session_t *s, const char *key) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    z_get_options_t options;
+    z_get_options_default(&options);
+    options.timeout_ms = 1000;
+    assert(z_get(s, z_loan(ke), "", z_move(closure), &options) == Z_OK);
+
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(handler), &reply) == Z_OK) {
+        if (z_reply_is_ok(z_loan(reply))) {
+            count++;
+        }
+        z_drop(z_move(reply));
+    }
+    z_drop(z_move(handler));
+    return count;
+}
+
+void test_pause(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    const char *key = "zenoh/test/queryable/pause";
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_query, NULL, NULL);
+    z_owned_queryable_t qable;
+    assert(z_declare_queryable(z_loan(s), &qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+    assert(!z_queryable_is_paused(z_loan(qable)));
+    assert(get_replies(z_loan(s), key) == 1);
+
+    z_queryable_pause(z_loan(qable));
+    assert(z_queryable_is_paused(z_loan(qable)));
+    assert(get_replies(z_loan(s), key) == 0);
+
+    z_queryable_resume(z_loan(qable));
+    assert(!z_queryable_is_paused(z_loan(qable)));
+    assert(get_replies(z_loan(s), key) == 1);
+
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+
+#define STREAM_LEN 10000
+#define STREAM_CHUNK_SIZE 1000
+
+void on_chunked_query(z_loaned_query_t *query, void *context) {
+    uint8_t *data = (uint8_t *)context;
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, data, STREAM_LEN);
+    z_reply_stream_options_t options;
+    z_reply_stream_options_default(&options);
+    options.chunk_size = STREAM_CHUNK_SIZE;
+    z_owned_encoding_t encoding;
+    z_encoding_from_str(&encoding, "text/plain;v1");
+    options.encoding = z_move(encoding);
+    assert(z_query_reply_chunked(query, z_query_keyexpr(query), z_move(payload), &options) == Z_OK);
+}
+
+void reply_str(const z_loaned_query_t *query, const char *value, const char *encoding_str) {
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    z_owned_encoding_t encoding;
+    z_encoding_from_str(&encoding, encoding_str);
+    z_query_reply_options_t options;
+    z_query_reply_options_default(&options);
+    options.encoding = z_move(encoding);
+    assert(z_query_reply(query, z_query_keyexpr(query), z_move(payload), &options) == Z_OK);
+}
+
+// replies like a reply stream whose second chunk was lost on the way
+void on_lossy_query(z_loaned_query_t *query, void *context) {
+    (void)context;
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "broken");
+    z_owned_encoding_t encoding;
+    z_encoding_from_str(&encoding, "zenoh/string;zenoh-c/reply-stream-broken:00000000000000ff");
+    z_query_reply_err_options_t options;
+    z_query_reply_err_options_default(&options);
+    options.encoding = z_move(encoding);
+    assert(z_query_reply_err(query, z_move(payload), &options) == Z_OK);
+    reply_str(query, "first", "zenoh/bytes;zenoh-c/reply-stream-chunk:00000000000000ff:0:0:");
+    reply_str(query, "last", "zenoh/bytes;zenoh-c/reply-stream-chunk:00000000000000ff:2:1:");
+}
+
+void on_aborted_query(z_loaned_query_t *query, void *context) {
+    uint8_t *data = (uint8_t *)context;
+    z_reply_stream_options_t options;
+    z_reply_stream_options_default(&options);
+    options.chunk_size = STREAM_CHUNK_SIZE;
+    z_owned_reply_stream_t stream;
+    assert(z_reply_stream_new(&stream, query, z_query_keyexpr(query), &options) == Z_OK);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_buf(&payload, data, STREAM_CHUNK_SIZE + 10);
+    assert(z_reply_stream_send(z_loan_mut(stream), z_move(payload)) == Z_OK);
+    z_drop(z_move(stream));
+}
+
+size_t get_chunks(const z_loaned_session_t *s, const char *key, bool reassemble, z_owned_reply_t *replies,
+                  size_t max_replies) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 32);
+    z_get_options_t options;
+    z_get_options_default(&options);
+    options.timeout_ms = 1000;
+    if (reassemble) {
+        options.reassemble_chunks = true;
+    } else {
+        options.consolidation = z_query_consolidation_none();
+    }
+    assert(z_get(s, z_loan(ke), "", z_move(closure), &options) == Z_OK);
+
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(handler), &reply) == Z_OK) {
+        assert(count < max_replies);
+        z_take(&replies[count++], z_move(reply));
+    }
+    z_drop(z_move(handler));
+    return count;
+}
+
+void test_reply_stream(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    uint8_t data[STREAM_LEN];
+    for (size_t i = 0; i < STREAM_LEN; i++) {
+        data[i] = (uint8_t)(i % 251);
+    }
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/stream/chunked");
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_chunked_query, NULL, data);
+    z_owned_queryable_t chunked_qable;
+    assert(z_declare_queryable(z_loan(s), &chunked_qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/stream/aborted");
+    z_closure(&callback, on_aborted_query, NULL, data);
+    z_owned_queryable_t aborted_qable;
+    assert(z_declare_queryable(z_loan(s), &aborted_qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/stream/lossy");
+    z_closure(&callback, on_lossy_query, NULL, NULL);
+    z_owned_queryable_t lossy_qable;
+    assert(z_declare_queryable(z_loan(s), &lossy_qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+
+    z_owned_reply_t replies[16];
+    z_reply_chunk_info_t info;
+
+    // chunks are handed over as they arrive
+    size_t count = get_chunks(z_loan(s), "zenoh/test/queryable/stream/chunked", false, replies, 16);
+    assert(count == STREAM_LEN / STREAM_CHUNK_SIZE + 1);
+    for (size_t i = 0; i < count; i++) {
+        assert(z_reply_chunk_info(z_loan(replies[i]), &info));
+        assert(info.index == i);
+        assert(info.is_last == (i == count - 1));
+        assert(!info.is_aborted);
+        const z_loaned_bytes_t *payload = z_sample_payload(z_reply_ok(z_loan(replies[i])));
+        assert(z_bytes_len(payload) == (info.is_last ? 0 : STREAM_CHUNK_SIZE));
+        z_drop(z_move(replies[i]));
+    }
+
+    // chunks are reassembled
+    count = get_chunks(z_loan(s), "zenoh/test/queryable/stream/chunked", true, replies, 16);
+    assert(count == 1);
+    assert(!z_reply_chunk_info(z_loan(replies[0]), &info));
+    const z_loaned_sample_t *sample = z_reply_ok(z_loan(replies[0]));
+    assert(z_sample_attachment(sample) == NULL);
+    z_owned_string_t encoding;
+    z_encoding_to_string(z_sample_encoding(sample), &encoding);
+    assert(z_string_len(z_loan(encoding)) == strlen("text/plain;v1"));
+    assert(strncmp(z_string_data(z_loan(encoding)), "text/plain;v1", z_string_len(z_loan(encoding))) == 0);
+    z_drop(z_move(encoding));
+    z_owned_slice_t slice;
+    z_bytes_to_slice(z_sample_payload(sample), &slice);
+    assert(z_slice_len(z_loan(slice)) == STREAM_LEN);
+    assert(memcmp(z_slice_data(z_loan(slice)), data, STREAM_LEN) == 0);
+    z_drop(z_move(slice));
+    z_drop(z_move(replies[0]));
+
+    // chunks are reassembled by queriers too
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/stream/chunked");
+    z_querier_options_t querier_options;
+    z_querier_options_default(&querier_options);
+    querier_options.consolidation = z_query_consolidation_none();
+    querier_options.timeout_ms = 1000;
+    z_owned_querier_t querier;
+    assert(z_declare_querier(z_loan(s), &querier, z_loan(ke), &querier_options) == Z_OK);
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 32);
+    z_querier_get_options_t get_options;
+    z_querier_get_options_default(&get_options);
+    get_options.reassemble_chunks = true;
+    assert(z_querier_get(z_loan(querier), "", z_move(closure), &get_options) == Z_OK);
+    count = 0;
+    while (z_recv(z_loan(handler), &replies[count]) == Z_OK) {
+        assert(count < 16);
+        count++;
+    }
+    assert(count == 1);
+    assert(!z_reply_chunk_info(z_loan(replies[0]), &info));
+    assert(z_bytes_len(z_sample_payload(z_reply_ok(z_loan(replies[0])))) == STREAM_LEN);
+    z_drop(z_move(replies[0]));
+    z_drop(z_move(handler));
+    z_drop(z_move(querier));
+
+    // dropping an unfinished stream aborts it
+    count = get_chunks(z_loan(s), "zenoh/test/queryable/stream/aborted", false, replies, 16);
+    assert(count == 2);
+    assert(z_reply_chunk_info(z_loan(replies[1]), &info));
+    assert(info.is_last && info.is_aborted);
+    z_drop(z_move(replies[0]));
+    z_drop(z_move(replies[1]));
+
+    count = get_chunks(z_loan(s), "zenoh/test/queryable/stream/aborted", true, replies, 16);
+    assert(count == 1);
+    assert(z_reply_chunk_info(z_loan(replies[0]), &info));
+    assert(info.is_aborted);
+    z_drop(z_move(replies[0]));
+
+    // a stream which lost a chunk is handed over as an error reply
+    count = get_chunks(z_loan(s), "zenoh/test/queryable/stream/lossy", true, replies, 16);
+    assert(count == 1);
+    assert(!z_reply_is_ok(z_loan(replies[0])));
+    z_owned_string_t error;
+    z_bytes_to_string(z_reply_err_payload(z_reply_err(z_loan(replies[0]))), &error);
+    char message[128];
+    snprintf(message, sizeof(message), "%.*s", (int)z_string_len(z_loan(error)), z_string_data(z_loan(error)));
+    assert(strstr(message, "lost chunk 1") != NULL);
+    z_drop(z_move(error));
+    z_drop(z_move(replies[0]));
+
+    z_drop(z_move(chunked_qable));
+    z_drop(z_move(aborted_qable));
+    z_drop(z_move(lossy_qable));
+    z_drop(z_move(s));
+}
+
+void on_deferred_query(z_loaned_query_t *query, void *context) {
+    z_owned_query_t *deferred = (z_owned_query_t *)context;
+    z_query_clone(deferred, query);
+}
+
+void test_finalize(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/finalize");
+    z_owned_query_t deferred;
+    z_internal_null(&deferred);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_deferred_query, NULL, &deferred);
+    z_owned_queryable_t qable;
+    assert(z_declare_queryable(z_loan(s), &qable, z_loan(ke), z_move(callback), NULL) == Z_OK);
+    z_sleep_ms(200);
+
+    z_owned_fifo_handler_reply_t handler;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &handler, 16);
+    z_get_options_t options;
+    z_get_options_default(&options);
+    options.timeout_ms = 5000;
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(closure), &options) == Z_OK);
+    z_sleep_ms(200);
+    assert(z_internal_check(deferred));
+
+    z_owned_query_t other;
+    z_query_clone(&other, z_loan(deferred));
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "deferred");
+    assert(z_query_reply(z_loan(deferred), z_loan(ke), z_move(payload), NULL) == Z_OK);
+
+    z_clock_t clock = z_clock_now();
+    z_query_finalize(z_loan(other));
+    z_query_finalize(z_loan(other));
+    z_bytes_copy_from_str(&payload, "too late");
+    assert(z_query_reply(z_loan(deferred), z_loan(ke), z_move(payload), NULL) != Z_OK);
+    assert(z_keyexpr_equals(z_query_keyexpr(z_loan(deferred)), z_loan(ke)));
+
+    size_t count = 0;
+    z_owned_reply_t reply;
+    while (z_recv(z_loan(handler), &reply) == Z_OK) {
+        assert(z_reply_is_ok(z_loan(reply)));
+        count++;
+        z_drop(z_move(reply));
+    }
+    assert(count == 1);
+    // the get ends on finalization, without waiting for the clones to be dropped nor for the timeout
+    assert(z_clock_elapsed_ms(&clock) < 2000);
+    z_drop(z_move(handler));
+    z_drop(z_move(other));
+    z_drop(z_move(deferred));
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+
+void recv_replies(const z_loaned_fifo_handler_reply_t *handler, size_t *ok, size_t *err) {
+    *ok = 0;
+    *err = 0;
+    z_owned_reply_t reply;
+    while (z_recv(handler, &reply) == Z_OK) {
+        if (z_reply_is_ok(z_loan(reply))) {
+            (*ok)++;
+        } else {
+            (*err)++;
+        }
+        z_drop(z_move(reply));
+    }
+}
+
+void test_limits(z_queryable_overload_policy_t policy) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/queryable/limits");
+    z_owned_query_t deferred;
+    z_internal_null(&deferred);
+    z_owned_closure_query_t callback;
+    z_closure(&callback, on_deferred_query, NULL, &deferred);
+    z_queryable_options_t qable_options;
+    z_queryable_options_default(&qable_options);
+    qable_options.max_in_flight = 1;
+    qable_options.overload_policy = policy;
+    z_owned_queryable_t qable;
+    assert(z_declare_queryable(z_loan(s), &qable, z_loan(ke), z_move(callback), &qable_options) == Z_OK);
+    z_sleep_ms(200);
+
+    z_get_options_t options;
+    z_get_options_default(&options);
+    options.timeout_ms = 1000;
+    z_owned_fifo_handler_reply_t first, second, third;
+    z_owned_closure_reply_t closure;
+    z_fifo_channel_reply_new(&closure, &first, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(closure), &options) == Z_OK);
+    z_sleep_ms(200);
+    assert(z_internal_check(deferred));
+
+    // the first query is still in flight
+    z_fifo_channel_reply_new(&closure, &second, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(closure), &options) == Z_OK);
+    size_t ok, err;
+    recv_replies(z_loan(second), &ok, &err);
+    assert(ok == 0);
+    assert(err == (policy == Z_QUERYABLE_OVERLOAD_POLICY_REPLY_ERR ? 1 : 0));
+    z_drop(z_move(second));
+
+    // finalization releases the slot of the first query
+    z_query_finalize(z_loan(deferred));
+    recv_replies(z_loan(first), &ok, &err);
+    assert(ok == 0 && err == 0);
+    z_drop(z_move(first));
+    z_fifo_channel_reply_new(&closure, &third, 16);
+    assert(z_get(z_loan(s), z_loan(ke), "", z_move(closure), &options) == Z_OK);
+    z_sleep_ms(200);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, "reply");
+    assert(z_query_reply(z_loan(deferred), z_loan(ke), z_move(payload), NULL) == Z_OK);
+    z_drop(z_move(deferred));
+    recv_replies(z_loan(third), &ok, &err);
+    assert(ok == 1 && err == 0);
+    z_drop(z_move(third));
+
+    z_drop(z_move(qable));
+    z_drop(z_move(s));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_pause();
+    test_reply_stream();
+    test_finalize();
+    test_limits(Z_QUERYABLE_OVERLOAD_POLICY_REPLY_ERR);
+    test_limits(Z_QUERYABLE_OVERLOAD_POLICY_DROP);
+#endif
+    return 0;
+}
@@ -0,0 +1,352 @@
+//
+// Copyright (c) 2025 ZettaScale Technology
+//
+// This program and the accompanying materials are made available under the
+// terms of the Eclipse Public License 2.0 which is available at
+// http://www.eclipse.org/legal/epl-2.0, or the Apache License, Version 2.0
+// which is available at https://www.apache.org/licenses/LICENSE-2.0.
+//
+// SPDX-License-Identifier: EPL-2.0 OR Apache-2.0
+//
+// Contributors:
+//   ZettaScale Zenoh Team, <zenoh@zettascale.tech>
+
+#include <stddef.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "zenoh.h"
+
+#undef NDEBUG
+#include <assert.h>
+
+#if defined(Z_FEATURE_UNSTABLE_API)
+typedef struct received_t {
+    size_t count;
+    char values[16][16];
+} received_t;
+
+void on_sample(z_loaned_sample_t *sample, void *context) {
+    received_t *r = (received_t *)context;
+    if (r->count >= 16) {
+        return;
+    }
+    z_owned_string_t s;
+    z_bytes_to_string(z_sample_payload(sample), &s);
+    size_t len = z_string_len(z_loan(s)) < 15 ? z_string_len(z_loan(s)) : 15;
+    memcpy(r->values[r->count], z_string_data(z_loan(s)), len);
+    r->values[r->count][len] = '\0';
+    r->count++;
+    z_drop(z_move(s));
+}
+
+void declare_subscriber(const z_loaned_session_t *s, z_owned_subscriber_t *sub, const z_loaned_keyexpr_t *ke,
+                        received_t *received, z_subscriber_options_t *options) {
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_sample, NULL, received);
+    assert(z_declare_subscriber(s, sub, ke, z_move(callback), options) == Z_OK);
+}
+
+void put(const z_loaned_publisher_t *pub, const char *value, const z_loaned_encoding_t *encoding,
+         const z_source_info_t *source_info) {
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    z_publisher_put_options_t options;
+    z_publisher_put_options_default(&options);
+    z_owned_encoding_t e;
+    if (encoding != NULL) {
+        z_encoding_clone(&e, encoding);
+        options.encoding = z_move(e);
+    }
+    options.source_info = source_info;
+    assert(z_publisher_put(pub, z_move(payload), &options) == Z_OK);
+}
+
+void test_filters(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/subscriber/filters");
+    z_owned_publisher_t pub;
+    assert(z_declare_publisher(z_loan(s), &pub, z_loan(ke), NULL) == Z_OK);
+
+    z_owned_config_t other_config;
+    z_config_default(&other_config);
+    z_owned_session_t other;
+    assert(z_open(&other, z_move(other_config), NULL) == Z_OK);
+
+    z_entity_global_id_t pub_id = z_publisher_id(z_loan(pub));
+    z_entity_global_id_t other_id = z_session_id(z_loan(other));
+    z_source_info_t pub_source = z_source_info_new(&pub_id, 0);
+    z_source_info_t other_source = z_source_info_new(&other_id, 0);
+
+    z_owned_encoding_t text_plain, text_utf8, json;
+    z_encoding_from_str(&text_plain, "text/plain");
+    z_encoding_from_str(&text_utf8, "text/plain;utf-8");
+    z_encoding_from_str(&json, "application/json");
+
+    // kind filter
+    received_t puts = {0};
+    z_owned_subscriber_t put_sub;
+    z_subscriber_options_t options;
+    z_subscriber_options_default(&options);
+    options.filter.kind = Z_SAMPLE_KIND_FILTER_PUT;
+    declare_subscriber(z_loan(s), &put_sub, z_loan(ke), &puts, &options);
+
+    // encoding filter
+    received_t texts = {0};
+    z_owned_subscriber_t text_sub;
+    const z_loaned_encoding_t *encodings[] = {z_loan(text_plain)};
+    z_subscriber_options_default(&options);
+    options.filter.encodings = encodings;
+    options.filter.encodings_len = 1;
+    declare_subscriber(z_loan(s), &text_sub, z_loan(ke), &texts, &options);
+
+    // source allow list
+    received_t allowed = {0};
+    z_owned_subscriber_t allow_sub;
+    z_subscriber_options_default(&options);
+    options.filter.source_mode = Z_SOURCE_FILTER_MODE_ALLOW;
+    options.filter.source_ids = &pub_id;
+    options.filter.source_ids_len = 1;
+    declare_subscriber(z_loan(s), &allow_sub, z_loan(ke), &allowed, &options);
+
+    // source deny list by zid
+    received_t denied = {0};
+    z_owned_subscriber_t deny_sub;
+    z_id_t zid = z_entity_global_id_zid(&other_id);
+    z_subscriber_options_default(&options);
+    options.filter.source_mode = Z_SOURCE_FILTER_MODE_DENY;
+    options.filter.source_zids = &zid;
+    options.filter.source_zids_len = 1;
+    declare_subscriber(z_loan(s), &deny_sub, z_loan(ke), &denied, &options);
+
+    put(z_loan(pub), "plain", z_loan(text_plain), &pub_source);
+    put(z_loan(pub), "utf8", z_loan(text_utf8), &other_source);
+    put(z_loan(pub), "json", z_loan(json), NULL);
+    assert(z_publisher_delete(z_loan(pub), NULL) == Z_OK);
+
+    z_sleep_ms(500);
+    assert(puts.count == 3);
+    assert(strcmp(puts.values[2], "json") == 0);
+
+    assert(texts.count == 2);
+    assert(strcmp(texts.values[0], "plain") == 0);
+    assert(strcmp(texts.values[1], "utf8") == 0);
+
+    assert(allowed.count == 1);
+    assert(strcmp(allowed.values[0], "plain") == 0);
+
+    assert(denied.count == 3);
+    assert(strcmp(denied.values[0], "plain") == 0);
+    assert(strcmp(denied.values[1], "json") == 0);
+
+    // custom encodings are matched by their full string
+    z_owned_encoding_t custom, custom_v2, bytes;
+    z_encoding_from_str(&custom, "my/enc");
+    z_encoding_from_str(&custom_v2, "my/enc;v2");
+    z_encoding_clone(&bytes, z_encoding_zenoh_bytes());
+    received_t customs = {0}, raw = {0};
+    z_owned_subscriber_t custom_sub, bytes_sub;
+    const z_loaned_encoding_t *custom_encodings[] = {z_loan(custom)};
+    z_subscriber_options_default(&options);
+    options.filter.encodings = custom_encodings;
+    options.filter.encodings_len = 1;
+    declare_subscriber(z_loan(s), &custom_sub, z_loan(ke), &customs, &options);
+    const z_loaned_encoding_t *bytes_encodings[] = {z_loan(bytes)};
+    options.filter.encodings = bytes_encodings;
+    declare_subscriber(z_loan(s), &bytes_sub, z_loan(ke), &raw, &options);
+
+    put(z_loan(pub), "custom", z_loan(custom), NULL);
+    put(z_loan(pub), "custom_v2", z_loan(custom_v2), NULL);
+    put(z_loan(pub), "bytes", z_loan(bytes), NULL);
+
+    z_sleep_ms(500);
+    assert(customs.count == 2);
+    assert(strcmp(customs.values[0], "custom") == 0);
+    assert(strcmp(customs.values[1], "custom_v2") == 0);
+    assert(raw.count == 1);
+    assert(strcmp(raw.values[0], "bytes") == 0);
+
+    z_drop(z_move(custom_sub));
+    z_drop(z_move(bytes_sub));
+    z_drop(z_move(custom));
+    z_drop(z_move(custom_v2));
+    z_drop(z_move(bytes));
+    z_drop(z_move(put_sub));
+    z_drop(z_move(text_sub));
+    z_drop(z_move(allow_sub));
+    z_drop(z_move(deny_sub));
+    z_drop(z_move(text_plain));
+    z_drop(z_move(text_utf8));
+    z_drop(z_move(json));
+    z_drop(z_move(pub));
+    z_drop(z_move(other));
+    z_drop(z_move(s));
+}
+
+void on_slow_sample(z_loaned_sample_t *sample, void *context) {
+    on_sample(sample, context);
+    z_sleep_ms(100);
+}
+
+void put_on(const z_loaned_session_t *s, const char *key, const char *value) {
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, key);
+    z_owned_bytes_t payload;
+    z_bytes_copy_from_str(&payload, value);
+    assert(z_put(s, z_loan(ke), z_move(payload), NULL) == Z_OK);
+}
+
+void test_coalescing(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/subscriber/coalescing/*");
+
+    received_t downsampled = {0};
+    z_owned_subscriber_t downsample_sub;
+    z_subscriber_options_t options;
+    z_subscriber_options_default(&options);
+    options.coalescing.mode = Z_SUBSCRIBER_COALESCING_MODE_DOWNSAMPLE;
+    options.coalescing.interval_ms = 300;
+    declare_subscriber(z_loan(s), &downsample_sub, z_loan(ke), &downsampled, &options);
+
+    received_t latest = {0};
+    z_owned_subscriber_t latest_sub;
+    z_owned_closure_sample_t callback;
+    z_closure(&callback, on_slow_sample, NULL, &latest);
+    z_subscriber_options_default(&options);
+    options.coalescing.mode = Z_SUBSCRIBER_COALESCING_MODE_LATEST;
+    assert(z_declare_subscriber(z_loan(s), &latest_sub, z_loan(ke), z_move(callback), &options) == Z_OK);
+
+    char value[8];
+    for (int i = 0; i < 10; i++) {
+        snprintf(value, sizeof(value), "a%d", i);
+        put_on(z_loan(s), "zenoh/test/subscriber/coalescing/a", value);
+        snprintf(value, sizeof(value), "b%d", i);
+        put_on(z_loan(s), "zenoh/test/subscriber/coalescing/b", value);
+    }
+    z_sleep_ms(200);
+    assert(downsampled.count == 0);
+    z_sleep_ms(300);
+    assert(downsampled.count == 2);
+    assert(strcmp(downsampled.values[0], "a9") == 0);
+    assert(strcmp(downsampled.values[1], "b9") == 0);
+
+    // the first samples are delivered immediately, the following ones are coalesced while the callback is busy
+    assert(latest.count >= 2 && latest.count <= 4);
+    const char *last = latest.values[latest.count - 1];
+    const char *before_last = latest.values[latest.count - 2];
+    assert((strcmp(before_last, "a9") == 0 && strcmp(last, "b9") == 0) ||
+           (strcmp(before_last, "b9") == 0 && strcmp(last, "a9") == 0));
+
+    z_drop(z_move(downsample_sub));
+    z_drop(z_move(latest_sub));
+    z_drop(z_move(s));
+}
+
+void test_pause(void) {
+    z_owned_config_t config;
+    z_config_default(&config);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(config), NULL) == Z_OK);
+
+    z_view_keyexpr_t ke;
+    z_view_keyexpr_from_str(&ke, "zenoh/test/subscriber/pause");
+    received_t received = {0};
+    z_owned_subscriber_t sub;
+    declare_subscriber(z_loan(s), &sub, z_loan(ke), &received, NULL);
+    assert(!z_subscriber_is_paused(z_loan(sub)));
+
+    put_on(z_loan(s), "zenoh/test/subscriber/pause", "before");
+    z_sleep_ms(200);
+    z_subscriber_pause(z_loan(sub));
+    z_subscriber_pause(z_loan(sub));
+    assert(z_subscriber_is_paused(z_loan(sub)));
+    put_on(z_loan(s), "zenoh/test/subscriber/pause", "paused");
+    z_sleep_ms(200);
+    z_subscriber_resume(z_loan(sub));
+    assert(!z_subscriber_is_paused(z_loan(sub)));
+    put_on(z_loan(s), "zenoh/test/subscriber/pause", "after");
+    z_sleep_ms(200);
+
+    assert(received.count == 2);
+    assert(strcmp(received.values[0], "before") == 0);
+    assert(strcmp(received.values[1], "after") == 0);
+    z_drop(z_move(sub));
+
+    // samples queued by the coalescing before the pause are still delivered
+    received_t downsampled = {0};
+    z_subscriber_options_t options;
+    z_subscriber_options_default(&options);
+    options.coalescing.mode = Z_SUBSCRIBER_COALESCING_MODE_DOWNSAMPLE;
+    options.coalescing.interval_ms = 300;
+    declare_subscriber(z_loan(s), &sub, z_loan(ke), &downsampled, &options);
+    put_on(z_loan(s), "zenoh/test/subscriber/pause", "queued");
+    z_sleep_ms(100);
+    z_subscriber_pause(z_loan(sub));
+    put_on(z_loan(s), "zenoh/test/subscriber/pause", "paused");
+    z_sleep_ms(500);
+    assert(downsampled.count == 1);
+    assert(strcmp(downsampled.values[0], "queued") == 0);
+
+    z_drop(z_move(sub));
+    z_drop(z_move(s));
+}
+
+void test_miss_channel(void) {
+    z_owned_config_t c;
+    z_config_default(&c);
+    z_owned_session_t s;
+    assert(z_open(&s, z_move(c), NULL) == Z_OK);
+
+    ze_miss_t first = {.source = z_session_id(z_loan(s)), .nb = 1};
+    ze_miss_t second = {.source = z_session_id(z_loan(s)), .nb = 2};
+    ze_miss_t miss;
+
+    ze_owned_closure_miss_t closure;
+    ze_owned_fifo_handler_miss_t fifo;
+    ze_fifo_channel_miss_new(&closure, &fifo, 16);
+    assert(z_try_recv(z_loan(fifo), &miss) == Z_CHANNEL_NODATA);
+    z_call(z_loan(closure), &first);
+    z_call(z_loan(closure), &second);
+    assert(z_recv(z_loan(fifo), &miss) == Z_OK);
+    assert(miss.nb == 1);
+    assert(z_entity_global_id_eid(&miss.source) == z_entity_global_id_eid(&first.source));
+    assert(z_recv(z_loan(fifo), &miss) == Z_OK);
+    assert(miss.nb == 2);
+    z_drop(z_move(closure));
+    assert(z_recv(z_loan(fifo), &miss) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(fifo));
+
+    // a ring channel only keeps the latest misses
+    ze_owned_ring_handler_miss_t ring;
+    ze_ring_channel_miss_new(&closure, &ring, 1);
+    z_call(z_loan(closure), &first);
+    z_call(z_loan(closure), &second);
+    assert(z_try_recv(z_loan(ring), &miss) == Z_OK);
+    assert(miss.nb == 2);
+    assert(z_try_recv(z_loan(ring), &miss) == Z_CHANNEL_NODATA);
+    z_drop(z_move(closure));
+    assert(z_try_recv(z_loan(ring), &miss) == Z_CHANNEL_DISCONNECTED);
+    z_drop(z_move(ring));
+    z_drop(z_move(s));
+}
+#endif
+
+int main(void) {
+#if defined(Z_FEATURE_UNSTABLE_API)
+    test_filters();
+    test_coalescing();
+    test_pause();
+    test_miss_channel();
+#endif
+    return 0;
+}
@@ -156,6 +156,8 @@ int run_subscriber() {
         printf("Unable to declare advanced subscriber.\n");
         exit(-1);
     }
+    assert(ze_advanced_subscriber_pause(z_loan(sub)) == Z_EUNAVAILABLE);
+    assert(ze_advanced_subscriber_resume(z_loan(sub)) == Z_EUNAVAILABLE);
 
     SEM_POST(sem_sub);
     z_sleep_s(10);